@@ -289,6 +289,10 @@ impl<T, IndexMarker> ArenaAllocator<T, IndexMarker> {
             PhantomData,
         )
     }
+    /// `None` if `id` was freed, or never allocated
+    pub fn get(&self, UUID(uuid, _): UUID<IndexMarker>) -> Option<&T> {
+        self.data.get(uuid)?.as_ref()
+    }
     pub fn reserve(&mut self) -> UUID<IndexMarker> {
         UUID(
             if let Some(empty_slot) = self.free_slots.pop() {
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::rc::Rc;
 
 use ibig::{IBig, UBig};
@@ -13,7 +13,7 @@ use crate::prelude::*;
 use crate::flattening::{BinaryOperator, Direction, Module, PartSelectDirection};
 use crate::instantiation::{
     InstantiatedModule, InstantiatedPort, IsPort, MultiplexerSource, RealWire, RealWireDataSource,
-    RealWirePathElem,
+    RealWirePathElem, SubModule,
 };
 use crate::to_string::{FmtWrapper, display_join};
 use crate::typing::concrete_type::{ConcreteGlobalReference, ConcreteTemplateArg, IntBounds};
@@ -291,9 +291,30 @@ impl Display for CommaSeparatedList {
     }
 }
 
+/// The source code that a part of the generated code was produced for
+// When --feature lsp is not used, this gives a warning
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct SourceMapping {
+    pub span: Span,
+    /// Byte range in [GeneratedCode::code]. Mappings may be nested, such as the assignments within an `always_comb` block
+    pub generated: Range<usize>,
+}
+
+#[derive(Debug)]
+pub struct GeneratedCode {
+    pub code: String,
+    /// In order of [SourceMapping::generated]'s start
+    // When --feature lsp is not used, this gives a warning
+    #[allow(dead_code)]
+    pub source_map: Vec<SourceMapping>,
+}
+
 struct CodeGenerationContext<'g> {
     /// Generate code to this variable
     program_text: String,
+    /// Offsets are into [Self::program_text]
+    source_map: Vec<SourceMapping>,
     for_vars: VariableAlloc,
     genvars: VariableAlloc,
 
@@ -475,10 +496,25 @@ impl<'g> CodeGenerationContext<'g> {
         Ok(())
     }
 
+    /// Records that the code `f` generates was produced for `span`
+    fn mapped_to(&mut self, span: Span, f: impl FnOnce(&mut Self)) {
+        let start = self.program_text.len();
+        let idx = self.source_map.len();
+        f(self);
+        let generated = start..self.program_text.len();
+        if !generated.is_empty() {
+            self.source_map
+                .insert(idx, SourceMapping { span, generated });
+        }
+    }
+
     fn comment_out(&mut self, f: impl FnOnce(&mut Self)) {
         let store_program_text_temporary = std::mem::take(&mut self.program_text);
+        let num_mappings = self.source_map.len();
         f(self);
         let added_text = std::mem::replace(&mut self.program_text, store_program_text_temporary);
+        // Commented out code doesn't correspond to anything
+        self.source_map.truncate(num_mappings);
 
         writeln!(
             self.program_text,
@@ -490,12 +526,18 @@ impl<'g> CodeGenerationContext<'g> {
 
     fn write_generative_decls_for(&mut self, f: impl FnOnce(&mut Self)) {
         let store_program_text_temporary = std::mem::take(&mut self.program_text);
+        let num_mappings = self.source_map.len();
         f(self);
         let added_text = std::mem::replace(&mut self.program_text, store_program_text_temporary);
 
         for var in &self.genvars.var_names {
             writeln!(self.program_text, "genvar {var};").unwrap()
         }
+        let offset = self.program_text.len();
+        for mapping in &mut self.source_map[num_mappings..] {
+            mapping.generated.start += offset;
+            mapping.generated.end += offset;
+        }
         self.program_text.write_str(&added_text).unwrap();
     }
     fn write_verilog_code(&mut self) {
@@ -558,7 +600,13 @@ impl<'g> CodeGenerationContext<'g> {
                 continue;
             }
             if matches!(port_wire.is_port, IsPort::Port(_, _)) {
-                self.add_latency_registers(port_wire_id, port_wire).unwrap();
+                let span = self
+                    .md
+                    .link_info
+                    .get_instruction_span(port_wire.original_instruction);
+                self.mapped_to(span, |slf| {
+                    slf.add_latency_registers(port_wire_id, port_wire).unwrap();
+                });
             }
         }
     }
@@ -734,176 +782,189 @@ impl<'g> CodeGenerationContext<'g> {
 
     fn write_wire_declarations(&mut self) {
         for (wire_id, w) in &self.instance.wires {
-            self.md
+            let span = self
+                .md
                 .link_info
                 .get_instruction_span(w.original_instruction)
                 .debug();
-            // For better readability of output Verilog
-            if self.can_inline(w) {
-                continue;
-            }
+            self.mapped_to(span, |slf| slf.write_wire_declaration(wire_id, w));
+        }
+    }
 
-            if matches!(w.is_port, IsPort::Port(_, _)) {
-                continue;
-            }
-            if should_not_codegen(w) {
-                writeln!(self.program_text, "// (zero sized) {}", w.name).unwrap();
-                continue;
-            }
-            let wire_or_reg = w.source.wire_or_reg();
-
-            let wire_name = wire_name_self_latency(w);
-            let wire_decl = typ_to_declaration(&w.typ, &wire_name);
-
-            match &w.source {
-                RealWireDataSource::Select { root, path } => {
-                    let root_wire = &self.instance.wires[*root];
-                    let root_name = self.wire_name(*root, w.absolute_latency);
-
-                    // Custom [Self::in_generate], to generate logic[31:0] my_val = 5 + other_val
-                    self.genvars.reuse();
-                    let content = self.foreach_for_real_path(
-                        &root_wire.typ,
-                        path,
-                        w.absolute_latency,
-                        false,
-                        |slf, source_path, target_path, result_typ| {
-                            let source = format!("{root_name}{source_path}");
-                            let target = format!("{wire_name}{target_path}");
-                            slf.foreach_for_copy_unpacked(result_typ, false, |path, _| {
-                                format!("assign {target}{path} = {source}{path};\n")
-                            })
-                        },
-                    );
-
-                    if self.genvars.currently_used != 0 {
-                        write!(
-                            self.program_text,
-                            "{wire_or_reg}{wire_decl};\ngenerate\n{content}endgenerate\n"
-                        )
-                        .unwrap();
-                    } else {
-                        // We're basically trimming "<assert wire_name>[...] = ..." off the string, so we can stitch it to the declaration
-                        let content = content.strip_prefix("assign ").unwrap();
-                        let content = content.strip_prefix(wire_name.as_ref()).unwrap();
-                        write!(self.program_text, "{wire_or_reg}{wire_decl}{content}").unwrap();
-                    }
-                }
-                RealWireDataSource::UnaryOp { op, right, .. } => {
-                    writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+    fn write_wire_declaration(&mut self, wire_id: WireID, w: &'g RealWire) {
+        // For better readability of output Verilog
+        if self.can_inline(w) {
+            return;
+        }
 
-                    let right_name = self.wire_name(*right, w.absolute_latency);
-                    self.in_generate(|slf| {
-                        slf.foreach_for_copy_unpacked(&w.typ, false, |path, _| {
-                            format!("assign {wire_name}{path} = {op}{right_name}{path};\n")
+        if matches!(w.is_port, IsPort::Port(_, _)) {
+            return;
+        }
+        if should_not_codegen(w) {
+            writeln!(self.program_text, "// (zero sized) {}", w.name).unwrap();
+            return;
+        }
+        let wire_or_reg = w.source.wire_or_reg();
+
+        let wire_name = wire_name_self_latency(w);
+        let wire_decl = typ_to_declaration(&w.typ, &wire_name);
+
+        match &w.source {
+            RealWireDataSource::Select { root, path } => {
+                let root_wire = &self.instance.wires[*root];
+                let root_name = self.wire_name(*root, w.absolute_latency);
+
+                // Custom [Self::in_generate], to generate logic[31:0] my_val = 5 + other_val
+                self.genvars.reuse();
+                let content = self.foreach_for_real_path(
+                    &root_wire.typ,
+                    path,
+                    w.absolute_latency,
+                    false,
+                    |slf, source_path, target_path, result_typ| {
+                        let source = format!("{root_name}{source_path}");
+                        let target = format!("{wire_name}{target_path}");
+                        slf.foreach_for_copy_unpacked(result_typ, false, |path, _| {
+                            format!("assign {target}{path} = {source}{path};\n")
                         })
-                    })
-                }
-                RealWireDataSource::BinaryOp {
-                    op, left, right, ..
-                } => {
-                    writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+                    },
+                );
 
-                    let left_wire = &self.instance.wires[*left];
-                    let right_wire = &self.instance.wires[*right];
-                    let left_name = self.wire_name(*left, w.absolute_latency);
-                    let right_name = self.wire_name(*right, w.absolute_latency);
-                    self.in_generate(|slf| {
-                        slf.foreach_for_copy_unpacked(&w.typ, false, |path, _| {
-                            if *op == BinaryOperator::Modulo {
-                                let left_int_range = path.walk_type(&left_wire.typ).unwrap_int_bounds();
-                                let right_int_range = path.walk_type(&right_wire.typ).unwrap_int_bounds();
-
-                                let content = codegen_optimized_modulo(left_int_range, right_int_range, &format!("{left_name}{path}"), &format!("{right_name}{path}"));
-                                format!(
-                                    "assign {wire_name}{path} = {content}\n"
-                                )
-                            } else {
-                                format!(
-                                    "assign {wire_name}{path} = {left_name}{path} {op} {right_name}{path};\n"
-                                )
-                            }
-                        })
-                    });
-                }
-                RealWireDataSource::Constant { value } => {
-                    let const_str = Self::display_constant(&w.typ, value);
-                    writeln!(self.program_text, "{wire_or_reg}{wire_decl} = {const_str};").unwrap();
-                }
-                RealWireDataSource::ReadOnly => {
-                    writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+                if self.genvars.currently_used != 0 {
+                    write!(
+                        self.program_text,
+                        "{wire_or_reg}{wire_decl};\ngenerate\n{content}endgenerate\n"
+                    )
+                    .unwrap();
+                } else {
+                    // We're basically trimming "<assert wire_name>[...] = ..." off the string, so we can stitch it to the declaration
+                    let content = content.strip_prefix("assign ").unwrap();
+                    let content = content.strip_prefix(wire_name.as_ref()).unwrap();
+                    write!(self.program_text, "{wire_or_reg}{wire_decl}{content}").unwrap();
                 }
-                RealWireDataSource::ConstructArray { array_wires } => {
-                    writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+            }
+            RealWireDataSource::UnaryOp { op, right, .. } => {
+                writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
 
-                    for (arr_idx, elem_id) in array_wires.iter().enumerate() {
-                        let elem_wire = &self.instance.wires[*elem_id];
-                        let element_wire_name = self.wire_name(*elem_id, w.absolute_latency);
+                let right_name = self.wire_name(*right, w.absolute_latency);
+                self.in_generate(|slf| {
+                    slf.foreach_for_copy_unpacked(&w.typ, false, |path, _| {
+                        format!("assign {wire_name}{path} = {op}{right_name}{path};\n")
+                    })
+                })
+            }
+            RealWireDataSource::BinaryOp {
+                op, left, right, ..
+            } => {
+                writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+
+                let left_wire = &self.instance.wires[*left];
+                let right_wire = &self.instance.wires[*right];
+                let left_name = self.wire_name(*left, w.absolute_latency);
+                let right_name = self.wire_name(*right, w.absolute_latency);
+                self.in_generate(|slf| {
+                    slf.foreach_for_copy_unpacked(&w.typ, false, |path, _| {
+                        if *op == BinaryOperator::Modulo {
+                            let left_int_range = path.walk_type(&left_wire.typ).unwrap_int_bounds();
+                            let right_int_range = path.walk_type(&right_wire.typ).unwrap_int_bounds();
+
+                            let content = codegen_optimized_modulo(left_int_range, right_int_range, &format!("{left_name}{path}"), &format!("{right_name}{path}"));
+                            format!(
+                                "assign {wire_name}{path} = {content}\n"
+                            )
+                        } else {
+                            format!(
+                                "assign {wire_name}{path} = {left_name}{path} {op} {right_name}{path};\n"
+                            )
+                        }
+                    })
+                });
+            }
+            RealWireDataSource::Constant { value } => {
+                let const_str = Self::display_constant(&w.typ, value);
+                writeln!(self.program_text, "{wire_or_reg}{wire_decl} = {const_str};").unwrap();
+            }
+            RealWireDataSource::ReadOnly => {
+                writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
+            }
+            RealWireDataSource::ConstructArray { array_wires } => {
+                writeln!(self.program_text, "{wire_or_reg}{wire_decl};").unwrap();
 
-                        self.in_generate(|slf| {
-                            slf.foreach_for_copy_unpacked(&elem_wire.typ, false, |path, _| {
-                                format!(
+                for (arr_idx, elem_id) in array_wires.iter().enumerate() {
+                    let elem_wire = &self.instance.wires[*elem_id];
+                    let element_wire_name = self.wire_name(*elem_id, w.absolute_latency);
+
+                    self.in_generate(|slf| {
+                        slf.foreach_for_copy_unpacked(&elem_wire.typ, false, |path, _| {
+                            format!(
                                 "assign {wire_name}[{arr_idx}]{path} = {element_wire_name}{path};\n"
                             )
-                            })
-                        });
-                    }
+                        })
+                    });
                 }
-                RealWireDataSource::Multiplexer {
-                    is_state,
-                    sources: _,
-                } => {
-                    write!(self.program_text, "{wire_or_reg}{wire_decl}").unwrap();
-                    match is_state {
-                        Some(initial_val) if !initial_val.is_unset() => {
-                            let cst_str = Self::display_constant(&w.typ, initial_val);
-                            write!(self.program_text, " = {cst_str}",).unwrap();
-                        }
-                        _ => {}
+            }
+            RealWireDataSource::Multiplexer {
+                is_state,
+                sources: _,
+            } => {
+                write!(self.program_text, "{wire_or_reg}{wire_decl}").unwrap();
+                match is_state {
+                    Some(initial_val) if !initial_val.is_unset() => {
+                        let cst_str = Self::display_constant(&w.typ, initial_val);
+                        write!(self.program_text, " = {cst_str}",).unwrap();
                     }
-                    self.program_text.write_str(";\n").unwrap();
+                    _ => {}
                 }
+                self.program_text.write_str(";\n").unwrap();
             }
-            self.add_latency_registers(wire_id, w).unwrap();
         }
+        self.add_latency_registers(wire_id, w).unwrap();
     }
 
     fn write_submodules(&mut self) {
         let parent_clk_name = self.md.get_clock_name();
         for (_id, sm) in &self.instance.submodules {
-            let sm_md = &self.linker.modules[sm.refers_to.id];
+            let span = self
+                .md
+                .link_info
+                .get_instruction_span(sm.original_instruction);
+            self.mapped_to(span, |slf| slf.write_submodule(sm, parent_clk_name));
+        }
+    }
 
-            // Invalid submodules are impossible to remain by the time codegen happens
-            let sm_inst: &InstantiatedModule = sm.instance.get().unwrap();
-            if sm_md.link_info.is_extern == IsExtern::Extern {
-                self.write_template_args(&sm_md.link_info, &sm_inst.global_ref.template_args);
-            } else {
-                self.program_text.write_str(&sm_inst.mangled_name).unwrap();
-            };
-            let sm_name = &sm.name;
+    fn write_submodule(&mut self, sm: &'g SubModule, parent_clk_name: &str) {
+        let sm_md = &self.linker.modules[sm.refers_to.id];
 
-            let mut port_list = CommaSeparatedList::new("// (zero sized port) ");
-            let submod_clk = sm_md.get_clock_name();
-            port_list.line(format!(".{submod_clk}({parent_clk_name})"));
+        // Invalid submodules are impossible to remain by the time codegen happens
+        let sm_inst: &InstantiatedModule = sm.instance.get().unwrap();
+        if sm_md.link_info.is_extern == IsExtern::Extern {
+            self.write_template_args(&sm_md.link_info, &sm_inst.global_ref.template_args);
+        } else {
+            self.program_text.write_str(&sm_inst.mangled_name).unwrap();
+        };
+        let sm_name = &sm.name;
 
-            for (port_id, iport) in sm_inst.interface_ports.iter_valids() {
-                let sm_port = &sm_inst.wires[iport.wire];
-                let port_name = &sm_port.name;
-                let wire_name = if let Some(port_wire) = &sm.port_map[port_id] {
-                    &self.instance.wires[port_wire.maps_to_wire].name
-                } else {
-                    // Ports that are defined on the submodule, but not used by impl
-                    ""
-                };
-                let line = format!(".{port_name}({wire_name})");
-                if should_not_codegen(sm_port) {
-                    port_list.commented(line);
-                } else {
-                    port_list.line(line);
-                }
+        let mut port_list = CommaSeparatedList::new("// (zero sized port) ");
+        let submod_clk = sm_md.get_clock_name();
+        port_list.line(format!(".{submod_clk}({parent_clk_name})"));
+
+        for (port_id, iport) in sm_inst.interface_ports.iter_valids() {
+            let sm_port = &sm_inst.wires[iport.wire];
+            let port_name = &sm_port.name;
+            let wire_name = if let Some(port_wire) = &sm.port_map[port_id] {
+                &self.instance.wires[port_wire.maps_to_wire].name
+            } else {
+                // Ports that are defined on the submodule, but not used by impl
+                ""
+            };
+            let line = format!(".{port_name}({wire_name})");
+            if should_not_codegen(sm_port) {
+                port_list.commented(line);
+            } else {
+                port_list.line(line);
             }
-            writeln!(self.program_text, " {sm_name}({port_list});").unwrap();
         }
+        writeln!(self.program_text, " {sm_name}({port_list});").unwrap();
     }
 
     fn write_template_args(
@@ -966,56 +1027,62 @@ impl<'g> CodeGenerationContext<'g> {
 
     fn write_multiplexers(&mut self) {
         for (_id, w) in &self.instance.wires {
-            self.md
+            let span = self
+                .md
                 .link_info
                 .get_instruction_span(w.original_instruction)
                 .debug();
             if should_not_codegen(w) {
                 continue;
             }
-            match &w.source {
-                RealWireDataSource::Multiplexer { is_state, sources } => {
-                    let output_name = wire_name_self_latency(w);
-                    let arrow_str = if is_state.is_some() {
-                        let clk_name = self.md.get_clock_name();
-                        writeln!(self.program_text, "always_ff @(posedge {clk_name}) begin")
-                            .unwrap();
-                        "<="
-                    } else {
-                        writeln!(self.program_text, "always_comb begin\n\t// Combinatorial wires are not defined when not valid. This is just so that the synthesis tool doesn't generate latches").unwrap();
-                        let unset_str = Self::display_constant(&w.typ, &Value::Unset);
-                        writeln!(self.program_text, "\t{output_name} = {unset_str};").unwrap();
-                        "="
-                    };
+            self.mapped_to(span, |slf| slf.write_multiplexer(w));
+        }
+    }
 
-                    for s in sources {
-                        if should_not_codegen_assign(s) {
-                            // Eliminate zero-size sub-slice assignments
-                            continue;
-                        }
-                        self.write_assign(&output_name, arrow_str, s, w);
+    fn write_multiplexer(&mut self, w: &'g RealWire) {
+        match &w.source {
+            RealWireDataSource::Multiplexer { is_state, sources } => {
+                let output_name = wire_name_self_latency(w);
+                let arrow_str = if is_state.is_some() {
+                    let clk_name = self.md.get_clock_name();
+                    writeln!(self.program_text, "always_ff @(posedge {clk_name}) begin").unwrap();
+                    "<="
+                } else {
+                    writeln!(self.program_text, "always_comb begin\n\t// Combinatorial wires are not defined when not valid. This is just so that the synthesis tool doesn't generate latches").unwrap();
+                    let unset_str = Self::display_constant(&w.typ, &Value::Unset);
+                    writeln!(self.program_text, "\t{output_name} = {unset_str};").unwrap();
+                    "="
+                };
+
+                for s in sources {
+                    if should_not_codegen_assign(s) {
+                        // Eliminate zero-size sub-slice assignments
+                        continue;
                     }
+                    self.mapped_to(s.write_span, |slf| {
+                        slf.write_assign(&output_name, arrow_str, s, w)
+                    });
+                }
 
-                    write!(
-                        self.program_text,
-                        "{}",
-                        super::patches::patch_combinatorial_write_one_bit_dont_care(
-                            is_state,
-                            &output_name,
-                            &w.typ
-                        )
+                write!(
+                    self.program_text,
+                    "{}",
+                    super::patches::patch_combinatorial_write_one_bit_dont_care(
+                        is_state,
+                        &output_name,
+                        &w.typ
                     )
-                    .unwrap();
+                )
+                .unwrap();
 
-                    writeln!(self.program_text, "end").unwrap();
-                }
-                RealWireDataSource::ReadOnly
-                | RealWireDataSource::Select { .. }
-                | RealWireDataSource::UnaryOp { .. }
-                | RealWireDataSource::BinaryOp { .. }
-                | RealWireDataSource::Constant { .. }
-                | RealWireDataSource::ConstructArray { .. } => {}
+                writeln!(self.program_text, "end").unwrap();
             }
+            RealWireDataSource::ReadOnly
+            | RealWireDataSource::Select { .. }
+            | RealWireDataSource::UnaryOp { .. }
+            | RealWireDataSource::BinaryOp { .. }
+            | RealWireDataSource::Constant { .. }
+            | RealWireDataSource::ConstructArray { .. } => {}
         }
     }
 
@@ -1171,11 +1238,20 @@ impl RealWireDataSource {
 }
 
 pub fn gen_verilog_code(instance: &InstantiatedModule, linker: &Linker) -> String {
+    gen_verilog_code_with_source_map(instance, linker).code
+}
+
+/// Like [gen_verilog_code], but also records which source code each part of the output was generated for
+pub fn gen_verilog_code_with_source_map(
+    instance: &InstantiatedModule,
+    linker: &Linker,
+) -> GeneratedCode {
     let mut ctx = CodeGenerationContext {
         md: &linker.modules[instance.global_ref.id],
         instance,
         linker,
         program_text: String::new(),
+        source_map: Vec::new(),
         genvars: VariableAlloc::new("_g"),
         for_vars: VariableAlloc::new("_v"),
         needed_untils: instance.compute_needed_untils(),
@@ -1190,5 +1266,63 @@ pub fn gen_verilog_code(instance: &InstantiatedModule, linker: &Linker) -> Strin
         },
     );
 
-    ctx.program_text
+    GeneratedCode {
+        code: ctx.program_text,
+        source_map: ctx.source_map,
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text, instances_of};
+
+    /// The innermost mapping that satisfies `contains`
+    fn innermost(
+        source_map: &[SourceMapping],
+        contains: impl Fn(&SourceMapping) -> bool,
+    ) -> &SourceMapping {
+        source_map
+            .iter()
+            .filter(|mapping| contains(mapping))
+            .min_by_key(|mapping| mapping.generated.len())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_source_map_round_trip() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let text = &linker.files[file].file_text;
+        let top = &instances_of(&linker, "top")[0];
+        let generated = gen_verilog_code_with_source_map(top, &linker);
+
+        // Source to Verilog
+        let write_pos = TWO_DELAYS.find("d1.a = x").unwrap();
+        let write = innermost(&generated.source_map, |m| m.span.contains_pos(write_pos));
+        assert_eq!(&generated.code[write.generated.clone()], "\t_d1_a = x;\n");
+
+        // Verilog to source
+        let instance_pos = generated.code.find("delay_T_type_bool_2 d2(").unwrap();
+        let submodule = innermost(&generated.source_map, |m| {
+            m.generated.contains(&instance_pos)
+        });
+        assert_eq!(&text[submodule.span], "delay #(T: type bool[2])");
+    }
+
+    #[test]
+    fn test_source_map_is_ordered_and_nested() {
+        let (linker, _file) = compile_text(TWO_DELAYS);
+        let top = &instances_of(&linker, "top")[0];
+        let generated = gen_verilog_code_with_source_map(top, &linker);
+        let map = &generated.source_map;
+        assert!(!map.is_empty());
+        assert!(map.is_sorted_by_key(|m| m.generated.start));
+        for (idx, a) in map.iter().enumerate() {
+            assert!(a.generated.end <= generated.code.len());
+            for b in &map[idx + 1..] {
+                let disjoint = a.generated.end <= b.generated.start;
+                let nested = b.generated.end <= a.generated.end;
+                assert!(disjoint || nested, "{a:?} and {b:?} overlap");
+            }
+        }
+    }
 }
@@ -1,22 +1,26 @@
 use crate::prelude::*;
 
+use std::collections::HashSet;
 use std::ffi::OsStr;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 use crate::config::EarlyExitUpTo;
+use crate::file_position::LineCol;
 use crate::flattening::typecheck::{perform_lints, typecheck};
-use crate::linker::GlobalObj;
 use crate::linker::checkpoint::{
     AFTER_FLATTEN_CP, AFTER_INITIAL_PARSE_CP, AFTER_LINTS_CP, AFTER_TYPE_CHECK_CP,
 };
+use crate::linker::incremental::FileGlobalsBeforeChange;
+use crate::linker::{GlobalObj, GlobalUUID};
 use crate::typing::concrete_type::ConcreteGlobalReference;
 
 use sus_proc_macro::{get_builtin_const, get_builtin_type};
-use tree_sitter::Parser;
+use tree_sitter::{InputEdit, Parser, Point, Tree};
 
 use crate::{config::config, errors::ErrorStore, file_position::FileText, linker::FileData};
 
-use crate::flattening::{flatten_all_globals, gather_initial_file_data};
+use crate::flattening::{flatten_globals, gather_initial_file_data};
 
 pub fn get_std_dir() -> PathBuf {
     config().sus_home.join("std")
@@ -169,6 +173,7 @@ impl Linker {
         file_id
     }
 
+    /// Returns the globals that must be recompiled, see [Linker::recompile_incremental]
     // When --feature lsp is not used, this gives a warning
     #[allow(dead_code)]
     pub fn add_or_update_file<ExtraInfoManager: LinkerExtraFileInfoManager>(
@@ -176,35 +181,102 @@ impl Linker {
         file_identifier: &str,
         text: String,
         info_mngr: &mut ExtraInfoManager,
-    ) {
+    ) -> HashSet<GlobalUUID> {
         if let Some(file_id) = self.find_file(file_identifier) {
-            let file_data = self.remove_everything_in_file(file_id);
-
             let mut parser = Parser::new();
             parser.set_language(&tree_sitter_sus::language()).unwrap();
             let tree = parser.parse(&text, None).unwrap();
 
-            file_data.parsing_errors = ErrorStore::new();
-            file_data.file_text = FileText::new(text);
-            file_data.tree = tree;
-
-            self.with_file_builder(file_id, |builder| {
-                crate::debug::debug_context(
-                    "gather_initial_file_data in update_file",
-                    builder.file_data.file_identifier.clone(),
-                    builder.file_data,
-                    || gather_initial_file_data(builder),
-                );
-            });
-            let assoc_vals = self.files[file_id].associated_values.clone();
-            self.checkpoint(&assoc_vals, AFTER_INITIAL_PARSE_CP);
-
-            info_mngr.on_file_updated(file_id, self);
+            self.replace_file_contents(file_id, FileText::new(text), tree, info_mngr)
         } else {
-            self.add_file_text(file_identifier.to_owned(), text, info_mngr);
+            let file_id = self.add_file_text(file_identifier.to_owned(), text, info_mngr);
+            self.globals_to_recompile(file_id, &FileGlobalsBeforeChange::default())
         }
     }
 
+    /// Applies `edits` one after the other, as editors send them. Each replaces a range of the text as it is after the previous edits.
+    /// Columns count UTF-16 code units, like LSP positions do.
+    ///
+    /// The file is re-parsed reusing the unchanged parts of its syntax tree. Returns the globals that must be recompiled, see [Linker::recompile_incremental]
+    // When --feature lsp is not used, this gives a warning
+    #[allow(dead_code)]
+    pub fn edit_file<ExtraInfoManager: LinkerExtraFileInfoManager>(
+        &mut self,
+        file_id: FileUUID,
+        edits: impl IntoIterator<Item = (Range<LineCol>, String)>,
+        info_mngr: &mut ExtraInfoManager,
+    ) -> HashSet<GlobalUUID> {
+        let file_data = &self.files[file_id];
+        let mut file_text = FileText::new(file_data.file_text.file_text.clone());
+        let mut tree = file_data.tree.clone();
+
+        // tree-sitter wants positions as rows and byte columns
+        let to_point = |file_text: &FileText, byte: usize| {
+            let line = file_text.byte_to_linecol(byte).line;
+            let line_start = file_text.linecol_to_byte_clamp(LineCol { line, col: 0 });
+            Point::new(line, byte - line_start)
+        };
+        for (range, new_text) in edits {
+            let start_byte = file_text.utf16_linecol_to_byte_clamp(range.start);
+            let old_end_byte = file_text
+                .utf16_linecol_to_byte_clamp(range.end)
+                .max(start_byte);
+            let start_position = to_point(&file_text, start_byte);
+            let old_end_position = to_point(&file_text, old_end_byte);
+
+            let mut text = file_text.file_text;
+            text.replace_range(start_byte..old_end_byte, &new_text);
+            file_text = FileText::new(text);
+
+            let new_end_byte = start_byte + new_text.len();
+            tree.edit(&InputEdit {
+                start_byte,
+                old_end_byte,
+                new_end_byte,
+                start_position,
+                old_end_position,
+                new_end_position: to_point(&file_text, new_end_byte),
+            });
+        }
+
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_sus::language()).unwrap();
+        let tree = parser.parse(&file_text.file_text, Some(&tree)).unwrap();
+
+        self.replace_file_contents(file_id, file_text, tree, info_mngr)
+    }
+
+    /// Replaces all globals in the file by those in the new text. Returns the globals that must be recompiled
+    fn replace_file_contents<ExtraInfoManager: LinkerExtraFileInfoManager>(
+        &mut self,
+        file_id: FileUUID,
+        file_text: FileText,
+        tree: Tree,
+        info_mngr: &mut ExtraInfoManager,
+    ) -> HashSet<GlobalUUID> {
+        let before = self.file_globals_before_change(file_id);
+        let file_data = self.remove_everything_in_file(file_id);
+
+        file_data.parsing_errors = ErrorStore::new();
+        file_data.file_text = file_text;
+        file_data.tree = tree;
+
+        self.with_file_builder(file_id, |builder| {
+            crate::debug::debug_context(
+                "gather_initial_file_data in update_file",
+                builder.file_data.file_identifier.clone(),
+                builder.file_data,
+                || gather_initial_file_data(builder),
+            );
+        });
+        let assoc_vals = self.files[file_id].associated_values.clone();
+        self.checkpoint(&assoc_vals, AFTER_INITIAL_PARSE_CP);
+
+        info_mngr.on_file_updated(file_id, self);
+
+        self.globals_to_recompile(file_id, &before)
+    }
+
     pub fn find_file(&self, file_identifier: &str) -> Option<FileUUID> {
         self.files
             .find(|_id, f| f.file_identifier == file_identifier)
@@ -215,13 +287,45 @@ impl Linker {
     }
 
     pub fn recompile_all(&mut self) {
-        let config = config();
-
         self.instantiator.borrow_mut().clear_instances();
 
         let global_ids = self.get_all_global_ids();
+        self.recompile_globals(&global_ids);
+    }
+
+    // When --feature lsp is not used, this gives a warning
+    #[allow(dead_code)]
+    pub fn recompile_incremental_report_panics(&mut self, changed: &HashSet<GlobalUUID>) {
+        crate::debug::create_dump_on_panic(self, |slf| slf.recompile_incremental(changed))
+    }
+
+    /// Recompiles only the `changed` globals, as found by [Linker::globals_to_recompile], and re-instantiates what depends on them.
+    /// Everything else keeps the results of the previous compilation
+    pub fn recompile_incremental(&mut self, changed: &HashSet<GlobalUUID>) {
+        self.instantiator
+            .borrow_mut()
+            .remove_instances_depending_on(changed);
+
+        // `changed` may still contain the ids of removed globals
+        let all_global_ids = self.get_all_global_ids();
+        let num_globals = all_global_ids.len();
+        let global_ids: Vec<GlobalUUID> = all_global_ids
+            .into_iter()
+            .filter(|id| changed.contains(id))
+            .collect();
+        info!(
+            "Incremental recompile of {} of {num_globals} globals",
+            global_ids.len()
+        );
+        self.recompile_globals(&global_ids);
+    }
+
+    /// Runs all passes on `global_ids`, and instantiates the top modules. Instances still in the cache are reused
+    fn recompile_globals(&mut self, global_ids: &[GlobalUUID]) {
+        let config = config();
+
         // First reset all modules back to post-gather_initial_file_data
-        for id in &global_ids {
+        for id in global_ids {
             let link_info = &mut self.globals[*id];
 
             link_info.reset_to(AFTER_INITIAL_PARSE_CP);
@@ -231,14 +335,14 @@ impl Linker {
             return;
         }
 
-        flatten_all_globals(self);
+        flatten_globals(self, global_ids);
 
-        self.checkpoint(&global_ids, AFTER_FLATTEN_CP);
+        self.checkpoint(global_ids, AFTER_FLATTEN_CP);
         if config.early_exit == EarlyExitUpTo::Flatten {
             return;
         }
 
-        for global_id in &global_ids {
+        for global_id in global_ids {
             self.pass("Typechecking", *global_id, |pass, errors, files| {
                 typecheck(pass, errors);
 
@@ -250,22 +354,24 @@ impl Linker {
                 }
             });
         }
-        self.checkpoint(&global_ids, AFTER_TYPE_CHECK_CP);
+        self.checkpoint(global_ids, AFTER_TYPE_CHECK_CP);
 
-        for (_, md) in &self.modules {
-            md.assert_valid();
+        for id in global_ids {
+            if let GlobalUUID::Module(md_id) = id {
+                self.modules[*md_id].assert_valid();
+            }
         }
 
         if config.early_exit == EarlyExitUpTo::AbstractTypecheck {
             return;
         }
 
-        for global_id in &global_ids {
+        for global_id in global_ids {
             self.pass("Lints", *global_id, |pass, errors, files| {
                 perform_lints(pass, errors, files);
             });
         }
-        self.checkpoint(&global_ids, AFTER_LINTS_CP);
+        self.checkpoint(global_ids, AFTER_LINTS_CP);
 
         if config.early_exit == EarlyExitUpTo::Lint {
             return;
@@ -1,8 +1,10 @@
 use crate::prelude::*;
 
+use crate::lint_levels::{LintLevel, all_lint_names, lints_named, load_project_lint_levels};
+
 use clap::{Arg, ArgGroup, Command, ValueEnum};
 use log::info;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 use std::time::Duration;
 use std::{env, ffi::OsStr, path::PathBuf};
@@ -51,6 +53,54 @@ pub enum ConnectionMethod {
     Tcp { port: u16, should_listen: bool },
 }
 
+/// How errors and warnings are printed, see [crate::dev_aid::message_format]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    Human,
+    Json,
+    Sarif,
+}
+
+impl ValueEnum for MessageFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            MessageFormat::Human,
+            MessageFormat::Json,
+            MessageFormat::Sarif,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(match self {
+            MessageFormat::Human => "human".into(),
+            MessageFormat::Json => "json".into(),
+            MessageFormat::Sarif => "sarif".into(),
+        })
+    }
+}
+
+/// See [crate::dev_aid::fix]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixMode {
+    /// Rewrite the source files
+    Apply,
+    /// Only print the fixes as a unified diff
+    DryRun,
+}
+
+/// Modes of the compiler that don't compile .sus files
+#[derive(Debug)]
+pub enum SubCommand {
+    /// Loads and solves latency problems dumped with `--debug dump-latency-problem-json`
+    LatencySolve { problem_files: Vec<PathBuf> },
+    /// Prints the explanation of a diagnostic code, see [crate::error_codes]
+    Explain { code: ErrorCode },
+    /// Formats .sus files, see [crate::dev_aid::format]
+    Format { files: Vec<PathBuf>, check: bool },
+    /// Compiles the files and writes html documentation to `out`, see [crate::dev_aid::doc]
+    Doc { files: Vec<PathBuf>, out: PathBuf },
+}
+
 /// All command-line flags are converted to this struct, of which the singleton instance can be acquired using [crate::config::config]
 #[derive(Debug)]
 pub struct ConfigStruct {
@@ -59,9 +109,17 @@ pub struct ConfigStruct {
     pub sus_home: PathBuf,
     pub codegen_file: Option<PathBuf>,
     pub codegen_separate_folder: Option<PathBuf>,
+    /// Writes the source files annotated with their solved latencies as html to this folder
+    pub latency_html_folder: Option<PathBuf>,
+    /// Apply the compiler's suggested fixes instead of generating code
+    pub fix_mode: Option<FixMode>,
     /// When no top modules specified, then codegen all
     pub top_modules: Vec<String>,
     pub use_color: bool,
+    pub message_format: MessageFormat,
+    /// From the project file and `-W`, `-A` and `-D`, see [crate::lint_levels]
+    pub lint_levels: HashMap<ErrorCode, LintLevel>,
+    pub deny_warnings: bool,
     pub ci: bool,
     pub target_language: TargetLanguage,
     pub files: Vec<PathBuf>,
@@ -78,6 +136,7 @@ pub struct ConfigStruct {
     pub enabled_debug_paths: HashSet<String>,
     pub early_exit: EarlyExitUpTo,
     pub no_redump: bool,
+    pub subcommand: Option<SubCommand>,
 }
 
 pub const VERSION_INFO: &str = concat!(
@@ -90,6 +149,32 @@ pub const VERSION_INFO: &str = concat!(
     env!("BUILD_FEATURES")
 );
 
+fn parse_lint_name(name: &str) -> Result<Vec<ErrorCode>, String> {
+    lints_named(name).ok_or_else(|| format!("The lints are: {}", all_lint_names()))
+}
+
+fn parse_sus_file(file_path_str: &str) -> Result<PathBuf, &'static str> {
+    let file_path = PathBuf::from(file_path_str);
+    if !file_path.exists() {
+        Err("File does not exist")
+    } else if !file_path.is_file() {
+        Err("Is a directory")
+    } else if file_path.extension() != Some(OsStr::new("sus")) {
+        Err("Source files must end in .sus")
+    } else {
+        Ok(file_path)
+    }
+}
+
+/// When no files are passed, all .sus files in the working directory are used
+fn sus_files_in_working_directory() -> Vec<PathBuf> {
+    std::fs::read_dir(".")
+        .unwrap()
+        .map(|file| file.unwrap().path())
+        .filter(|file_path| file_path.is_file() && file_path.extension() == Some("sus".as_ref()))
+        .collect()
+}
+
 fn command_builder() -> Command {
     Command::new("SUS Compiler")
         .version(VERSION_INFO)
@@ -136,6 +221,23 @@ fn command_builder() -> Command {
                 let file_path = PathBuf::from(file_path_str);
                 Result::<PathBuf, &'static str>::Ok(file_path)
             }))
+        .arg(Arg::new("latency-html")
+            .long("latency-html")
+            .help("Writes every source file as html to the chosen folder, with all wires annotated by their absolute latency, domain and type")
+            .value_parser(|file_path_str : &str| {
+                let file_path = PathBuf::from(file_path_str);
+                Result::<PathBuf, &'static str>::Ok(file_path)
+            }))
+        .arg(Arg::new("fix")
+            .long("fix")
+            .help("Applies the fixes the compiler suggests for its errors and warnings to the source files in place. Overlapping fixes are skipped. Fails if errors without a fix remain")
+            .conflicts_with_all(["fix-dry-run", "codegen-enabled"])
+            .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("fix-dry-run")
+            .long("fix-dry-run")
+            .help("Prints the fixes --fix would apply as a unified diff, without changing any files")
+            .conflicts_with("codegen-enabled")
+            .action(clap::ArgAction::SetTrue))
         .group(ArgGroup::new("codegen-enabled").args(["o", "codegen-separate"]))
         .arg(Arg::new("codegen-language")
             .long("codegen-language")
@@ -156,26 +258,46 @@ fn command_builder() -> Command {
             .long("nocolor")
             .help("Disables color printing in the errors of the sus_compiler output")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("message-format")
+            .long("message-format")
+            .help("How errors and warnings are printed. 'json' prints one JSON object per diagnostic to stdout, 'sarif' a single SARIF 2.1.0 log for code scanning tools")
+            .value_parser(clap::builder::EnumValueParser::<MessageFormat>::new())
+            .default_value("human"))
+        .arg(Arg::new("warn")
+            .short('W')
+            .long("warn")
+            .help(format!("Reports this lint as a warning. The lints are: {}", all_lint_names()))
+            .action(clap::ArgAction::Append)
+            .value_parser(parse_lint_name))
+        .arg(Arg::new("allow")
+            .short('A')
+            .long("allow")
+            .help("Silences this lint")
+            .action(clap::ArgAction::Append)
+            .value_parser(parse_lint_name))
+        .arg(Arg::new("deny")
+            .short('D')
+            .long("deny")
+            .help("Reports this lint as an error, which fails the build")
+            .action(clap::ArgAction::Append)
+            .value_parser(parse_lint_name))
+        .arg(Arg::new("deny-warnings")
+            .long("deny-warnings")
+            .help("Reports all warnings as errors, which fails the build")
+            .action(clap::ArgAction::SetTrue))
         .arg(Arg::new("ci")
             .long("ci")
             .hide(true)
             .help("Makes the compiler output as environment agnostic as possible")
             .action(clap::ArgAction::SetTrue))
+        .arg(Arg::new("explain")
+            .long("explain")
+            .help("Explains a diagnostic code like SUS0301 with an example, and exits")
+            .value_parser(|code: &str| code.parse::<ErrorCode>()))
         .arg(Arg::new("files")
             .action(clap::ArgAction::Append)
             .help(".sus Files")
-            .value_parser(|file_path_str : &str| {
-                let file_path = PathBuf::from(file_path_str);
-                if !file_path.exists() {
-                    Err("File does not exist")
-                } else if !file_path.is_file() {
-                    Err("Is a directory")
-                } else if file_path.extension() != Some(OsStr::new("sus")) {
-                    Err("Source files must end in .sus")
-                } else {
-                    Ok(file_path)
-                }
-            }))
+            .value_parser(parse_sus_file))
         .arg(Arg::new("sus-home")
             .long("sus-home")
             .help("Override the SUS_HOME directory (for std/core.sus, crash_dumps, etc)")
@@ -215,12 +337,74 @@ fn command_builder() -> Command {
             .hide(true)
             .help("Disable creation of new crash dump on panic")
             .action(clap::ArgAction::SetTrue))
+        .subcommand(Command::new("latency-solve")
+            .about("Loads latency problems dumped with --debug dump-latency-problem-json, solves them standalone and reports the time taken. Fails when an outcome differs from the one the compiler got")
+            .arg(Arg::new("problem-files")
+                .action(clap::ArgAction::Append)
+                .required(true)
+                .help(".json latency problem files")
+                .value_parser(|file_path_str : &str| {
+                    let file_path = PathBuf::from(file_path_str);
+                    if !file_path.is_file() {
+                        Err("File does not exist")
+                    } else {
+                        Ok(file_path)
+                    }
+                })))
+        .subcommand(Command::new("fmt")
+            .about("Formats .sus files in place: indentation, spacing around operators, and alignment of consecutive declarations. Comments are kept")
+            .arg(Arg::new("check")
+                .long("check")
+                .help("Doesn't change any files, but prints the changes as a unified diff, and fails if there are any")
+                .action(clap::ArgAction::SetTrue))
+            .arg(Arg::new("files")
+                .action(clap::ArgAction::Append)
+                .help(".sus Files. Defaults to all .sus files in the working directory")
+                .value_parser(parse_sus_file)))
+        .subcommand(Command::new("doc")
+            .about("Generates html documentation for all modules, structs and constants, including the standard library. Shows signatures, template parameters, interfaces with their latencies, and /// comments")
+            .arg(Arg::new("out")
+                .long("out")
+                .required(true)
+                .help("The folder to write the html pages to")
+                .value_parser(|file_path_str : &str| {
+                    let file_path = PathBuf::from(file_path_str);
+                    Result::<PathBuf, &'static str>::Ok(file_path)
+                }))
+            .arg(Arg::new("files")
+                .action(clap::ArgAction::Append)
+                .help(".sus Files. Defaults to all .sus files in the working directory")
+                .value_parser(parse_sus_file)))
 }
 
 pub fn parse_args() {
     assert!(CONFIG.get().is_none(), "parse_args() used twice!");
 
-    let matches = match command_builder().try_get_matches_from(std::env::args_os()) {
+    CONFIG.set(parse_config(std::env::args_os())).unwrap();
+}
+
+/// Test setup: a config as if `sus_compiler --ci --nocolor -W int-bounds` was run from the crate root, so the opt-in lints can be tested too
+#[cfg(test)]
+pub fn initialize_test_config() {
+    CONFIG.get_or_init(|| {
+        parse_config([
+            "sus_compiler",
+            "--ci",
+            "--nocolor",
+            "-W",
+            "int-bounds",
+            "--sus-home",
+            env!("CARGO_MANIFEST_DIR"),
+        ])
+    });
+}
+
+fn parse_config<I, T>(args: I) -> ConfigStruct
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    let matches = match command_builder().try_get_matches_from(args) {
         Ok(matches) => matches,
         Err(e) => e.exit(),
     };
@@ -239,17 +423,19 @@ pub fn parse_args() {
     let use_color = !matches.get_flag("nocolor") && !matches.get_flag("lsp");
     let files: Vec<PathBuf> = match matches.get_many("files") {
         Some(files) => files.cloned().collect(),
-        None => std::fs::read_dir(".")
-            .unwrap()
-            .map(|file| file.unwrap().path())
-            .filter(|file_path| {
-                file_path.is_file() && file_path.extension() == Some("sus".as_ref())
-            })
-            .collect(),
+        None => sus_files_in_working_directory(),
     };
 
     let codegen_file: Option<PathBuf> = matches.get_one("o").cloned();
     let codegen_separate_folder: Option<PathBuf> = matches.get_one("codegen-separate").cloned();
+    let latency_html_folder: Option<PathBuf> = matches.get_one("latency-html").cloned();
+    let fix_mode = if matches.get_flag("fix") {
+        Some(FixMode::Apply)
+    } else if matches.get_flag("fix-dry-run") {
+        Some(FixMode::DryRun)
+    } else {
+        None
+    };
 
     let top_modules = matches
         .get_many("top")
@@ -298,11 +484,60 @@ pub fn parse_args() {
 
     let ci = matches.get_flag("ci");
 
+    let subcommand = match matches.subcommand() {
+        Some(("latency-solve", sub_matches)) => Some(SubCommand::LatencySolve {
+            problem_files: sub_matches
+                .get_many("problem-files")
+                .unwrap_or_default()
+                .cloned()
+                .collect(),
+        }),
+        Some(("fmt", sub_matches)) => Some(SubCommand::Format {
+            files: match sub_matches.get_many("files") {
+                Some(files) => files.cloned().collect(),
+                None => sus_files_in_working_directory(),
+            },
+            check: sub_matches.get_flag("check"),
+        }),
+        Some(("doc", sub_matches)) => Some(SubCommand::Doc {
+            files: match sub_matches.get_many("files") {
+                Some(files) => files.cloned().collect(),
+                None => sus_files_in_working_directory(),
+            },
+            out: sub_matches.get_one::<PathBuf>("out").unwrap().clone(),
+        }),
+        _ => matches
+            .get_one::<ErrorCode>("explain")
+            .map(|code| SubCommand::Explain { code: *code }),
+    };
+
     if !ci {
         // Otherwise this might vary on build server, and spuriously change the output
         info!("SUS_HOME is {}", sus_home.to_string_lossy());
     }
 
+    let mut lint_levels = load_project_lint_levels();
+    // Later flags override earlier ones
+    let mut lint_flags: Vec<(usize, &Vec<ErrorCode>, LintLevel)> = Vec::new();
+    for (arg, level) in [
+        ("warn", LintLevel::Warn),
+        ("allow", LintLevel::Allow),
+        ("deny", LintLevel::Deny),
+    ] {
+        if let (Some(indices), Some(lints)) = (
+            matches.indices_of(arg),
+            matches.get_many::<Vec<ErrorCode>>(arg),
+        ) {
+            lint_flags.extend(indices.zip(lints).map(|(idx, lints)| (idx, lints, level)));
+        }
+    }
+    lint_flags.sort_by_key(|(idx, _, _)| *idx);
+    for (_, lints, level) in lint_flags {
+        for code in lints {
+            lint_levels.insert(*code, level);
+        }
+    }
+
     let target_language = matches
         .get_one("codegen-language")
         .copied()
@@ -328,23 +563,28 @@ pub fn parse_args() {
         );
     }
 
-    let cfg = ConfigStruct {
+    ConfigStruct {
         lsp_settings,
         sus_home,
         files,
         codegen_file,
         codegen_separate_folder,
+        latency_html_folder,
+        fix_mode,
         top_modules,
         target_language,
         use_color,
+        message_format: *matches.get_one("message-format").unwrap(),
+        lint_levels,
+        deny_warnings: matches.get_flag("deny-warnings"),
         ci,
         debug_whitelist,
         enabled_debug_paths,
         kill_timeout: *matches.get_one::<Duration>("kill-timeout").unwrap(),
         early_exit: *matches.get_one("upto").unwrap(),
         no_redump: matches.get_flag("no-redump"),
-    };
-    CONFIG.set(cfg).unwrap();
+        subcommand,
+    }
 }
 
 static CONFIG: OnceLock<ConfigStruct> = OnceLock::new();
@@ -133,11 +133,14 @@ pub fn pretty_print_error<AriadneCache: Cache<FileUUID>>(
     let config = ariadne_config();
     let mut report: ReportBuilder<'_, (FileUUID, Range<usize>)> =
         Report::build(report_kind, (file, error_span.clone())).with_config(config);
-    report = report.with_message(&error.reason).with_label(
-        Label::new((file, error_span))
-            .with_message(error.reason)
-            .with_color(err_color),
-    );
+    report = report
+        .with_code(error.code)
+        .with_message(&error.reason)
+        .with_label(
+            Label::new((file, error_span))
+                .with_message(error.reason)
+                .with_color(err_color),
+        );
 
     for info in error.infos {
         let info_span = info.position.as_range();
@@ -0,0 +1,536 @@
+//! Generates static HTML documentation for all modules, structs and constants, including the standard library.
+//!
+//! Every global gets a page with its signature, template parameters, domains, interfaces with port latencies and `///` comments.
+//! Names of other globals in the shown code link to their pages, as do `` `Name` `` code spans in doc comments.
+
+use crate::prelude::*;
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::config::config;
+use crate::flattening::{
+    DeclarationKind, ExpressionSource, GlobalReference, Instruction, InterfaceDeclKind, Module,
+    WireReferenceRoot, WrittenType,
+};
+use crate::linker::{Documentation, FileData, GlobalUUID, IsExtern, LinkInfo};
+use crate::typing::template::{GenerativeParameterKind, TemplateKind};
+
+use super::latency_html::escape_html;
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; padding: 1em; }
+pre, code { font-family: monospace; background: #f5f5f5; border-radius: 3px; }
+pre { padding: 0.5em; }
+pre.sig { font-size: 110%; }
+a { color: #0b57d0; text-decoration: none; }
+a:hover { text-decoration: underline; }
+.interface { border-left: 3px solid #ccc; padding-left: 1em; margin-bottom: 1em; }
+.kind { color: #777; }
+";
+
+fn kind_name(id: GlobalUUID) -> &'static str {
+    match id {
+        GlobalUUID::Module(_) => "module",
+        GlobalUUID::Type(_) => "struct",
+        GlobalUUID::Constant(_) => "const",
+    }
+}
+
+fn page_name(linker: &Linker, id: GlobalUUID) -> String {
+    format!("{}.{}.html", kind_name(id), linker.globals[id].name)
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    let title = escape_html(title);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n"
+    )
+}
+
+fn add_type_refs(refs: &mut Vec<(Span, GlobalUUID)>, typ: &WrittenType) {
+    match typ {
+        WrittenType::Error(_) | WrittenType::TemplateVariable(..) => {}
+        WrittenType::Named(global_ref) => add_global_ref(refs, global_ref),
+        WrittenType::Array(_, arr_box) => add_type_refs(refs, &arr_box.0),
+    }
+}
+
+fn add_global_ref<ID: Copy>(refs: &mut Vec<(Span, GlobalUUID)>, global_ref: &GlobalReference<ID>)
+where
+    GlobalUUID: From<ID>,
+{
+    refs.push((global_ref.name_span, GlobalUUID::from(global_ref.id)));
+    for arg in &global_ref.template_args {
+        if let Some(TemplateKind::Type(typ)) = &arg.kind {
+            add_type_refs(refs, typ);
+        }
+    }
+}
+
+/// The spans of all names in `link_info` that refer to a global, sorted by position
+fn global_references(link_info: &LinkInfo) -> Vec<(Span, GlobalUUID)> {
+    let mut refs = Vec::new();
+    for (_, instr) in &link_info.instructions {
+        match instr {
+            Instruction::SubModule(sm) => add_global_ref(&mut refs, &sm.module_ref),
+            Instruction::Declaration(decl) => add_type_refs(&mut refs, &decl.typ_expr),
+            Instruction::Expression(expr) => {
+                if let ExpressionSource::WireRef(wire_ref) = &expr.source {
+                    match &wire_ref.root {
+                        WireReferenceRoot::NamedConstant(cst) => add_global_ref(&mut refs, cst),
+                        WireReferenceRoot::NamedModule(md) => add_global_ref(&mut refs, md),
+                        _ => {}
+                    }
+                }
+            }
+            Instruction::Interface(_)
+            | Instruction::IfStatement(_)
+            | Instruction::ForStatement(_) => {}
+        }
+    }
+    refs.sort_by_key(|(span, _)| span.as_range().start);
+    refs.dedup_by_key(|(span, _)| span.as_range().start);
+    refs
+}
+
+/// Renders the documentation page of one global
+struct GlobalPage<'l> {
+    linker: &'l Linker,
+    link_info: &'l LinkInfo,
+    file: &'l FileData,
+    refs: Vec<(Span, GlobalUUID)>,
+}
+
+impl GlobalPage<'_> {
+    fn link(&self, id: GlobalUUID, text: &str) -> String {
+        format!(
+            "<a href=\"{}\">{}</a>",
+            escape_html(&page_name(self.linker, id)),
+            escape_html(text)
+        )
+    }
+
+    /// The source text of `span`, with the names of globals linked to their pages
+    fn code(&self, span: Span) -> String {
+        let range = span.as_range();
+        let text = &self.file.file_text.file_text;
+        let mut result = String::new();
+        let mut cursor = range.start;
+        for (ref_span, id) in &self.refs {
+            let ref_range = ref_span.as_range();
+            if ref_range.start < cursor || ref_range.end > range.end {
+                continue;
+            }
+            result.push_str(&escape_html(&text[cursor..ref_range.start]));
+            result.push_str(&self.link(*id, &text[ref_range.clone()]));
+            cursor = ref_range.end;
+        }
+        result.push_str(&escape_html(&text[cursor..range.end]));
+        result
+    }
+
+    /// Escapes `text`, and turns `` `code` `` spans into `<code>`, linking them if they name a global
+    fn inline_doc(&self, text: &str) -> String {
+        let mut result = String::new();
+        for (idx, part) in text.split('`').enumerate() {
+            if idx % 2 == 0 {
+                result.push_str(&escape_html(part));
+            } else if let Ok(id) = self.linker.get_by_name(part.trim()) {
+                write!(result, "<code>{}</code>", self.link(id, part)).unwrap();
+            } else {
+                write!(result, "<code>{}</code>", escape_html(part)).unwrap();
+            }
+        }
+        result
+    }
+
+    /// Doc comments are a small subset of Markdown: paragraphs separated by blank lines, `` `code` `` and ```` ``` ```` blocks
+    fn doc(&self, documentation: &Documentation) -> String {
+        let mut result = String::new();
+        let mut paragraph: Vec<&str> = Vec::new();
+        let mut code_block: Option<String> = None;
+        let flush_paragraph = |result: &mut String, paragraph: &mut Vec<&str>| {
+            if !paragraph.is_empty() {
+                let text = paragraph.join("\n");
+                writeln!(result, "<p>{}</p>", self.inline_doc(&text)).unwrap();
+                paragraph.clear();
+            }
+        };
+        for line_span in &documentation.gathered {
+            let line = &self.file.file_text[*line_span];
+            let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+            if line.trim_start().starts_with("```") {
+                if let Some(code) = code_block.take() {
+                    writeln!(result, "<pre>{}</pre>", escape_html(&code)).unwrap();
+                } else {
+                    flush_paragraph(&mut result, &mut paragraph);
+                    code_block = Some(String::new());
+                }
+            } else if let Some(code) = &mut code_block {
+                code.push_str(line);
+                code.push('\n');
+            } else if line.trim().is_empty() {
+                flush_paragraph(&mut result, &mut paragraph);
+            } else {
+                paragraph.push(line.trim());
+            }
+        }
+        if let Some(code) = code_block {
+            writeln!(result, "<pre>{}</pre>", escape_html(&code)).unwrap();
+        }
+        flush_paragraph(&mut result, &mut paragraph);
+        result
+    }
+
+    fn latency(&self, lat_spec: Option<FlatID>) -> String {
+        match lat_spec {
+            Some(lat_spec) => {
+                let span = self.link_info.instructions[lat_spec]
+                    .unwrap_expression()
+                    .span;
+                format!("'{}", self.code(span))
+            }
+            None => String::new(),
+        }
+    }
+
+    /// `input state int #(FROM: 0, TO: 8) count'2`
+    fn port(&self, decl_id: FlatID) -> String {
+        let decl = self.link_info.instructions[decl_id].unwrap_declaration();
+        let DeclarationKind::Port {
+            direction,
+            is_state,
+            ..
+        } = decl.decl_kind
+        else {
+            unreachable!("Interface inputs and outputs are always ports")
+        };
+        let state_kw = if is_state { "state " } else { "" };
+        format!(
+            "{direction} {state_kw}{} {}{}",
+            self.code(decl.typ_expr.get_span()),
+            escape_html(&decl.name),
+            self.latency(decl.latency_specifier)
+        )
+    }
+
+    fn port_list(&self, decl_ids: &[FlatID]) -> String {
+        let mut result = String::new();
+        for decl_id in decl_ids {
+            let decl = self.link_info.instructions[*decl_id].unwrap_declaration();
+            writeln!(
+                result,
+                "<li><code>{}</code>{}</li>",
+                self.port(*decl_id),
+                self.doc(&decl.documentation)
+            )
+            .unwrap();
+        }
+        result
+    }
+
+    fn signature(&self, id: GlobalUUID) -> String {
+        let mut result = String::new();
+        match self.link_info.is_extern {
+            IsExtern::Normal => {}
+            IsExtern::Extern => result.push_str("extern "),
+            IsExtern::Builtin => result.push_str("__builtin__ "),
+        }
+        result.push_str(kind_name(id));
+        if let GlobalUUID::Constant(cst_id) = id {
+            let output_decl = self.linker.constants[cst_id].output_decl;
+            let typ_span = self.link_info.instructions[output_decl]
+                .unwrap_declaration()
+                .typ_expr
+                .get_span();
+            write!(result, " {}", self.code(typ_span)).unwrap();
+        }
+        write!(result, " {}", escape_html(&self.link_info.name)).unwrap();
+        if !self.link_info.parameters.is_empty() {
+            let params: Vec<String> = self
+                .link_info
+                .parameters
+                .iter()
+                .map(|(_, param)| match &param.kind {
+                    TemplateKind::Type(_) => escape_html(&param.name),
+                    TemplateKind::Value(GenerativeParameterKind { decl_span, .. }) => {
+                        self.code(*decl_span)
+                    }
+                })
+                .collect();
+            write!(result, " #({})", params.join(", ")).unwrap();
+        }
+        result
+    }
+
+    fn parameters(&self) -> String {
+        let mut result = String::new();
+        for (_, param) in &self.link_info.parameters {
+            let (text, doc) = match &param.kind {
+                TemplateKind::Type(_) => {
+                    (format!("type {}", escape_html(&param.name)), String::new())
+                }
+                TemplateKind::Value(GenerativeParameterKind {
+                    decl_span,
+                    declaration_instruction,
+                }) => {
+                    let decl =
+                        self.link_info.instructions[*declaration_instruction].unwrap_declaration();
+                    (self.code(*decl_span), self.doc(&decl.documentation))
+                }
+            };
+            writeln!(result, "<li><code>{text}</code>{doc}</li>").unwrap();
+        }
+        result
+    }
+
+    /// The domains of the module, with the interfaces and ports in each
+    fn interfaces(&self, md: &Module) -> String {
+        let mut result = String::new();
+        for (domain_id, domain) in &md.domains {
+            let ratio = match &domain.clock_ratio {
+                Some(ratio) => format!(" = {}", self.code(ratio.span)),
+                None => String::new(),
+            };
+            writeln!(
+                result,
+                "<h3><span class=\"kind\">domain</span> {}{ratio}</h3>",
+                escape_html(&domain.name)
+            )
+            .unwrap();
+            for (_, interface) in &md.interfaces {
+                if interface.domain != Some(domain_id) {
+                    continue;
+                }
+                match interface.declaration_instruction {
+                    Some(InterfaceDeclKind::Interface(decl_id)) => {
+                        let interface = self.link_info.instructions[decl_id].unwrap_interface();
+                        if interface.is_local {
+                            continue;
+                        }
+                        write!(
+                            result,
+                            "<div class=\"interface\">\n<code>{} {}{}</code>\n{}",
+                            interface.interface_kind,
+                            escape_html(&interface.name),
+                            self.latency(interface.latency_specifier),
+                            self.doc(&interface.documentation)
+                        )
+                        .unwrap();
+                        if !interface.inputs.is_empty() {
+                            write!(result, "<ul>\n{}</ul>\n", self.port_list(&interface.inputs))
+                                .unwrap();
+                        }
+                        if !interface.outputs.is_empty() {
+                            write!(
+                                result,
+                                "<p>-&gt;</p>\n<ul>\n{}</ul>\n",
+                                self.port_list(&interface.outputs)
+                            )
+                            .unwrap();
+                        }
+                        result.push_str("</div>\n");
+                    }
+                    Some(InterfaceDeclKind::SinglePort(decl_id)) => {
+                        write!(
+                            result,
+                            "<div class=\"interface\">\n<ul>\n{}</ul>\n</div>\n",
+                            self.port_list(&[decl_id])
+                        )
+                        .unwrap();
+                    }
+                    None => {}
+                }
+            }
+        }
+        result
+    }
+
+    fn struct_fields(&self) -> String {
+        let mut result = String::new();
+        for (_, instr) in &self.link_info.instructions {
+            if let Instruction::Declaration(decl) = instr
+                && let DeclarationKind::StructField(_) = decl.decl_kind
+            {
+                writeln!(
+                    result,
+                    "<li><code>{} {}</code>{}</li>",
+                    self.code(decl.typ_expr.get_span()),
+                    escape_html(&decl.name),
+                    self.doc(&decl.documentation)
+                )
+                .unwrap();
+            }
+        }
+        result
+    }
+
+    /// Links to all globals this one uses
+    fn references(&self, id: GlobalUUID) -> String {
+        let mut referenced: Vec<GlobalUUID> = Vec::new();
+        for global in &self.link_info.resolved_globals.referenced_globals {
+            if *global != id && !referenced.contains(global) {
+                referenced.push(*global);
+            }
+        }
+        referenced.sort_by(|a, b| {
+            self.linker.globals[*a]
+                .name
+                .cmp(&self.linker.globals[*b].name)
+        });
+        let links: Vec<String> = referenced
+            .into_iter()
+            .map(|global| {
+                let name = &self.linker.globals[global].name;
+                format!("<li>{}</li>", self.link(global, name))
+            })
+            .collect();
+        links.join("\n")
+    }
+
+    fn render(&self, id: GlobalUUID) -> String {
+        let name = escape_html(&self.link_info.name);
+        let kind = kind_name(id);
+        let position = self
+            .file
+            .file_text
+            .byte_to_linecol(self.link_info.name_span.as_range().start);
+        let mut body = format!(
+            "<p><a href=\"index.html\">Index</a></p>\n<h1><span class=\"kind\">{kind}</span> {name}</h1>\n<p>Defined in {}:{}</p>\n<pre class=\"sig\">{}</pre>\n{}",
+            escape_html(&self.file.file_identifier),
+            position.line + 1,
+            self.signature(id),
+            self.doc(&self.link_info.documentation)
+        );
+        if !self.link_info.parameters.is_empty() {
+            write!(
+                body,
+                "<h2>Template parameters</h2>\n<ul>\n{}</ul>\n",
+                self.parameters()
+            )
+            .unwrap();
+        }
+        match id {
+            GlobalUUID::Module(md_id) => {
+                write!(
+                    body,
+                    "<h2>Interfaces</h2>\n{}",
+                    self.interfaces(&self.linker.modules[md_id])
+                )
+                .unwrap();
+            }
+            GlobalUUID::Type(_) => {
+                let fields = self.struct_fields();
+                if !fields.is_empty() {
+                    write!(body, "<h2>Fields</h2>\n<ul>\n{fields}</ul>\n").unwrap();
+                }
+            }
+            GlobalUUID::Constant(_) => {}
+        }
+        let references = self.references(id);
+        if !references.is_empty() {
+            write!(body, "<h2>Uses</h2>\n<ul>\n{references}\n</ul>\n").unwrap();
+        }
+        html_page(&format!("{kind} {}", self.link_info.name), &body)
+    }
+}
+
+/// The first sentence of the first paragraph of a [Documentation::to_string], for the index
+fn first_sentence(doc: &str) -> &str {
+    let first_paragraph = doc.split("  \n").next().unwrap_or("");
+    let sentence = match first_paragraph.find(". ") {
+        Some(end) => &first_paragraph[..=end],
+        None => first_paragraph,
+    };
+    sentence.trim()
+}
+
+/// Writes one html page per module, struct and constant into `out_dir`, and an `index.html` listing them per file
+pub fn write_docs(linker: &Linker, out_dir: &Path) {
+    if let Err(e) = fs::create_dir_all(out_dir) {
+        fatal_exit!(
+            "Could not create the output directory {}: {e}",
+            out_dir.to_string_lossy()
+        );
+    }
+
+    let mut files: Vec<&FileData> = linker.files.iter().map(|(_, file)| file).collect();
+    // User files first, the standard library after
+    files.sort_by_key(|file| file.is_std);
+
+    let mut index = String::from("<h1>Documentation</h1>\n");
+    for file in files {
+        if file.associated_values.is_empty() {
+            continue;
+        }
+        let std_note = if file.is_std {
+            " <span class=\"kind\">(standard library)</span>"
+        } else {
+            ""
+        };
+        writeln!(
+            index,
+            "<h2>{}{std_note}</h2>\n<ul>",
+            escape_html(&file.file_identifier)
+        )
+        .unwrap();
+        for global in &file.associated_values {
+            let link_info = &linker.globals[*global];
+            let page = GlobalPage {
+                linker,
+                link_info,
+                file,
+                refs: global_references(link_info),
+            };
+            let file_name = page_name(linker, *global);
+            let path = out_dir.join(&file_name);
+            if let Err(e) = fs::write(&path, page.render(*global)) {
+                fatal_exit!("Error while writing to {}: {e}", path.to_string_lossy());
+            }
+            writeln!(
+                index,
+                "<li><span class=\"kind\">{}</span> <a href=\"{}\">{}</a> {}</li>",
+                kind_name(*global),
+                escape_html(&file_name),
+                escape_html(&link_info.name),
+                escape_html(first_sentence(
+                    &link_info.documentation.to_string(&file.file_text)
+                ))
+            )
+            .unwrap();
+        }
+        index.push_str("</ul>\n");
+    }
+
+    let path = out_dir.join("index.html");
+    if let Err(e) = fs::write(&path, html_page("Documentation", &index)) {
+        fatal_exit!("Error while writing to {}: {e}", path.to_string_lossy());
+    }
+    if !config().ci {
+        info!(
+            "Documentation written to {}",
+            out_dir.canonicalize().unwrap().to_string_lossy()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::first_sentence;
+
+    #[test]
+    fn test_first_sentence() {
+        assert_eq!(first_sentence(" Adds two numbers"), "Adds two numbers");
+        assert_eq!(
+            first_sentence(" A FIFO. Data is pushed when ready. More info"),
+            "A FIFO."
+        );
+        assert_eq!(
+            first_sentence(" Concatenates arrays  \n Also see `Repeat`. It is long"),
+            "Concatenates arrays"
+        );
+        assert_eq!(first_sentence(" Uses clog2(N) bits"), "Uses clog2(N) bits");
+        assert_eq!(first_sentence(""), "");
+    }
+}
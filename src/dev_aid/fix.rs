@@ -0,0 +1,278 @@
+//! `--fix` and `--fix-dry-run`: apply the [Suggestion]s the compiler attached to its errors to the source files.
+//!
+//! Only suggestions that don't overlap are applied, see [crate::errors::ErrorStore::compatible_suggestions].
+//! Fixes can enable new fixes (removing an unused wire can make its inputs unused), so it can pay off to run `--fix` more than once.
+
+use crate::prelude::*;
+
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::config::FixMode;
+use crate::errors::{ErrorLevel, ErrorStore, Suggestion};
+
+/// Lines of unchanged context around each change in the diff
+const DIFF_CONTEXT: usize = 3;
+
+/// `suggestions` must be sorted and non-overlapping. Their spans are offset by `offset` relative to `text`
+pub fn apply_suggestions(text: &str, offset: usize, suggestions: &[&Suggestion]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for s in suggestions {
+        let range = s.position.as_range();
+        result.push_str(&text[cursor..range.start - offset]);
+        result.push_str(&s.replace_with);
+        cursor = range.end - offset;
+    }
+    result.push_str(&text[cursor..]);
+    result
+}
+
+/// A run of lines touched by one or more suggestions
+struct Change<'s> {
+    lines: Range<usize>,
+    suggestions: Vec<&'s Suggestion>,
+}
+
+/// Unified diff of applying `suggestions` to `text`. Built straight from the suggestions, they already say what changed
+pub fn unified_diff(path: &str, text: &str, suggestions: &[Suggestion]) -> String {
+    let mut line_starts: Vec<usize> = vec![0];
+    line_starts.extend(text.match_indices('\n').map(|(idx, _)| idx + 1));
+    if line_starts.last() == Some(&text.len()) {
+        line_starts.pop();
+    }
+    let num_lines = line_starts.len();
+    let line_of = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+    let line_start_byte = |line: usize| line_starts.get(line).copied().unwrap_or(text.len());
+
+    // Merge suggestions on the same or adjacent lines
+    let mut changes: Vec<Change> = Vec::new();
+    for s in suggestions {
+        let range = s.position.as_range();
+        let first = line_of(range.start);
+        let last = if range.end > range.start {
+            line_of(range.end - 1)
+        } else {
+            first
+        };
+        if let Some(prev) = changes.last_mut()
+            && first <= prev.lines.end
+        {
+            prev.lines.end = prev.lines.end.max(last + 1);
+            prev.suggestions.push(s);
+        } else {
+            changes.push(Change {
+                lines: first..last + 1,
+                suggestions: vec![s],
+            });
+        }
+    }
+
+    let push_lines = |out: &mut String, prefix: char, lines: &str| {
+        for line in lines.split_inclusive('\n') {
+            out.push(prefix);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    };
+    let count_lines = |s: &str| s.split_inclusive('\n').count();
+
+    let mut result = format!("--- a/{path}\n+++ b/{path}\n");
+    // Running difference between new and old line numbers
+    let mut line_delta: isize = 0;
+    let mut hunk_start = 0;
+    while hunk_start < changes.len() {
+        let mut hunk_end = hunk_start + 1;
+        while hunk_end < changes.len()
+            && changes[hunk_end].lines.start - changes[hunk_end - 1].lines.end <= 2 * DIFF_CONTEXT
+        {
+            hunk_end += 1;
+        }
+        let hunk = &changes[hunk_start..hunk_end];
+        let from_line = hunk[0].lines.start.saturating_sub(DIFF_CONTEXT);
+        let to_line = (hunk.last().unwrap().lines.end + DIFF_CONTEXT).min(num_lines);
+
+        let mut body = String::new();
+        let mut new_count = 0;
+        let mut cursor = from_line;
+        for change in hunk {
+            let context = &text[line_start_byte(cursor)..line_start_byte(change.lines.start)];
+            push_lines(&mut body, ' ', context);
+            new_count += count_lines(context);
+
+            let old_start = line_start_byte(change.lines.start);
+            let old = &text[old_start..line_start_byte(change.lines.end)];
+            let new = apply_suggestions(old, old_start, &change.suggestions);
+            push_lines(&mut body, '-', old);
+            push_lines(&mut body, '+', &new);
+            new_count += count_lines(&new);
+            cursor = change.lines.end;
+        }
+        let context = &text[line_start_byte(cursor)..line_start_byte(to_line)];
+        push_lines(&mut body, ' ', context);
+        new_count += count_lines(context);
+
+        let old_count = to_line - from_line;
+        // By convention, empty ranges point at the line before them
+        let header_line = |start: usize, count: usize| if count == 0 { start } else { start + 1 };
+        let new_from_line = (from_line as isize + line_delta) as usize;
+        writeln!(
+            result,
+            "@@ -{},{old_count} +{},{new_count} @@",
+            header_line(from_line, old_count),
+            header_line(new_from_line, new_count)
+        )
+        .unwrap();
+        result.push_str(&body);
+
+        line_delta += new_count as isize - old_count as isize;
+        hunk_start = hunk_end;
+    }
+    result
+}
+
+/// Shorter paths for the diff headers, relative to the working directory where possible
+pub fn display_path(file_identifier: &str) -> String {
+    let path = Path::new(file_identifier);
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Errors (not warnings) that applying [ErrorStore::compatible_suggestions] leaves in place
+pub fn count_unfixed_errors(errs: &ErrorStore) -> usize {
+    let applied = errs.compatible_suggestions();
+    errs.into_iter()
+        .filter(|err| {
+            err.level == ErrorLevel::Error
+                && (err.suggestions.is_empty()
+                    || err.suggestions.iter().any(|s| !applied.contains(s)))
+        })
+        .count()
+}
+
+/// Applies all compatible suggestions to the user's source files, or prints them as a unified diff for [FixMode::DryRun]
+///
+/// Fails if errors remain that couldn't be fixed
+pub fn fix_all_files(linker: &Linker, mode: FixMode) -> ExitCode {
+    let errors = linker.collect_all_errors();
+    let mut total_fixes = 0;
+    let mut total_unfixed = 0;
+    for (_file_id, file_data, errs_for_file) in crate::alloc::zip_eq(linker.files.iter(), errors) {
+        total_unfixed += count_unfixed_errors(&errs_for_file);
+        if file_data.is_std {
+            continue;
+        }
+        let suggestions = errs_for_file.compatible_suggestions();
+        if suggestions.is_empty() {
+            continue;
+        }
+        total_fixes += suggestions.len();
+        let text = &file_data.file_text.file_text;
+        match mode {
+            FixMode::DryRun => {
+                let path = display_path(&file_data.file_identifier);
+                print!("{}", unified_diff(&path, text, &suggestions));
+            }
+            FixMode::Apply => {
+                let suggestion_refs: Vec<&Suggestion> = suggestions.iter().collect();
+                let fixed = apply_suggestions(text, 0, &suggestion_refs);
+                if let Err(e) = std::fs::write(&file_data.file_identifier, fixed) {
+                    fatal_exit!("Error while writing to {}: {e}", file_data.file_identifier);
+                }
+                info!(
+                    "Applied {} fixes to {}",
+                    suggestions.len(),
+                    display_path(&file_data.file_identifier)
+                );
+            }
+        }
+    }
+    if total_fixes == 0 {
+        info!("No fixes to apply");
+    }
+    if total_unfixed > 0 {
+        error!("{total_unfixed} errors can't be fixed automatically");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::compile_text;
+
+    fn mk_suggestion(range: Range<usize>, replace_with: &str) -> Suggestion {
+        Suggestion {
+            position: Span::from(range),
+            replace_with: replace_with.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let text = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        let at = |line: &str| text.find(line).unwrap();
+        let suggestions = [
+            // Remove line "b"
+            mk_suggestion(at("b")..at("c"), ""),
+            // Replace "k" with two lines
+            mk_suggestion(at("k")..at("k") + 1, "k1\nk2"),
+        ];
+
+        let fixed = apply_suggestions(text, 0, &suggestions.iter().collect::<Vec<_>>());
+        assert_eq!(fixed, "a\nc\nd\ne\nf\ng\nh\ni\nj\nk1\nk2\nl\n");
+
+        let diff = unified_diff("f.sus", text, &suggestions);
+        assert_eq!(
+            diff,
+            "--- a/f.sus\n+++ b/f.sus\n\
+             @@ -1,5 +1,4 @@\n a\n-b\n c\n d\n e\n\
+             @@ -8,5 +7,6 @@\n h\n i\n j\n-k\n+k1\n+k2\n l\n"
+        );
+    }
+
+    fn unfixed_errors(text: &str) -> usize {
+        let (linker, file) = compile_text(text);
+        count_unfixed_errors(&linker.collect_all_errors()[file])
+    }
+
+    #[test]
+    fn test_unfixed_errors() {
+        // The conflicting latency comes with a suggested replacement
+        assert_eq!(
+            unfixed_errors(
+                "
+module m {
+    input bool a'0
+    output bool b'0
+    reg b = a
+}
+"
+            ),
+            0
+        );
+        assert_eq!(
+            unfixed_errors(
+                "
+module m {
+    input bool a'0
+    output bool b'0
+    reg b = a
+    bool c = does_not_exist
+}
+"
+            ),
+            1
+        );
+    }
+}
@@ -0,0 +1,540 @@
+//! `sus_compiler fmt`: formats .sus files from their tree-sitter syntax tree.
+//!
+//! The formatter only ever changes the whitespace between tokens, so comments and documentation are kept as they are.
+//! It produces [Suggestion]s, which `--check` prints with the same diff as `--fix-dry-run`, and the LSP sends as text edits.
+//! - Indentation follows the nesting of blocks. Lines that continue a statement, or the inside of a bracket that was left open on an earlier line, are indented once more
+//! - Operators, commas, brackets and the like get their spacing normalized, and blank lines are collapsed to at most one
+//! - Consecutive single line declarations like `reg int i4 = i2 * i2` are aligned into columns
+
+use crate::prelude::*;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use sus_proc_macro::{kind, kw};
+use tree_sitter::{Node, Parser, Tree};
+
+use crate::errors::Suggestion;
+use crate::file_position::FileText;
+
+use super::fix::{apply_suggestions, display_path, unified_diff};
+
+/// The indentation of the CLI. The LSP uses the editor's settings
+pub const DEFAULT_INDENT: &str = "    ";
+
+/// At most this many newlines are kept in a row, so at most one blank line
+const MAX_NEWLINES: usize = 2;
+
+/// A token of the source, with the whitespace the formatter puts before it
+struct Token<'t> {
+    node: Node<'t>,
+    /// Comments may end in whitespace, which is not part of this range
+    range: std::ops::Range<usize>,
+    /// Line in the original text
+    line: usize,
+    newlines_before: usize,
+    indent: usize,
+    spaces_before: usize,
+}
+
+impl Token<'_> {
+    fn kind(&self) -> u16 {
+        self.node.kind_id()
+    }
+    fn parent_kind(&self) -> u16 {
+        self.node.parent().map_or(0, |p| p.kind_id())
+    }
+    fn is_open_bracket(&self) -> bool {
+        [kw!("("), kw!("["), kw!("#(")].contains(&self.kind())
+    }
+    fn is_close_bracket(&self) -> bool {
+        [kw!(")"), kw!("]")].contains(&self.kind())
+    }
+}
+
+fn is_comment(kind: u16) -> bool {
+    [
+        kind!("doc_comment"),
+        kind!("single_line_comment"),
+        kind!("multi_line_comment"),
+    ]
+    .contains(&kind)
+}
+
+/// The span of the first `ERROR` or missing node, if any
+fn first_syntax_error(mut node: Node) -> Option<Span> {
+    if !node.has_error() {
+        return None;
+    }
+    'descend: loop {
+        if node.is_error() || node.is_missing() {
+            return Some(Span::from(node.byte_range()));
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.has_error() {
+                node = child;
+                continue 'descend;
+            }
+        }
+        return Some(Span::from(node.byte_range()));
+    }
+}
+
+fn collect_tokens<'t>(tree: &'t Tree, text: &str) -> Vec<Token<'t>> {
+    let mut line_starts: Vec<usize> = vec![0];
+    line_starts.extend(text.match_indices('\n').map(|(idx, _)| idx + 1));
+    let line_of = |byte: usize| line_starts.partition_point(|start| *start <= byte) - 1;
+
+    let mut tokens = Vec::new();
+    let mut cursor = tree.walk();
+    if !cursor.goto_first_child() {
+        return tokens;
+    }
+    loop {
+        let node = cursor.node();
+        if node.child_count() != 0 {
+            cursor.goto_first_child();
+            continue;
+        }
+        let mut range = node.byte_range();
+        if is_comment(node.kind_id()) {
+            range.end = range.start + text[range.clone()].trim_end().len();
+        }
+        // Newlines are kept from the whitespace between the other tokens
+        if node.kind_id() != kw!("\n") && !range.is_empty() {
+            let newlines_before = tokens.last().map_or(0, |prev: &Token| {
+                text[prev.range.end..range.start].matches('\n').count()
+            });
+            tokens.push(Token {
+                node,
+                line: line_of(range.start),
+                range,
+                newlines_before,
+                indent: 0,
+                spaces_before: 0,
+            });
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return tokens;
+            }
+        }
+    }
+}
+
+/// Is there a space between two tokens on the same line?
+fn space_between(a: &Token, b: &Token) -> bool {
+    let (a_kind, b_kind) = (a.kind(), b.kind());
+    if is_comment(a_kind) || is_comment(b_kind) {
+        return true;
+    }
+    if a.is_open_bracket() || b.is_close_bracket() || b_kind == kw!(",") {
+        return false;
+    }
+    if a_kind == kw!("{") && b_kind == kw!("}") {
+        return false;
+    }
+    // Field access, namespaces, latency specifiers and ranges
+    let tight = [kw!("."), kw!("::"), kw!("'"), kw!("..")];
+    if tight.contains(&a_kind) || tight.contains(&b_kind) {
+        return false;
+    }
+    // Function calls and indexing
+    if b_kind == kw!("(") && b.parent_kind() == kind!("parenthesis_expression_list") {
+        return false;
+    }
+    if b_kind == kw!("[")
+        && [
+            kind!("array_access_bracket_expression"),
+            kind!("array_type_bracket"),
+        ]
+        .contains(&b.parent_kind())
+    {
+        return false;
+    }
+    if a.parent_kind() == kind!("unary_op")
+        && a.node
+            .parent()
+            .is_some_and(|p| p.start_byte() == a.range.start)
+    {
+        return false;
+    }
+    // `#(FROM: 0)`, `a[3:5]` and `a[3+:2]`, but `interface x : int a`
+    if a.parent_kind() == kind!("slice") || b.parent_kind() == kind!("slice") {
+        return false;
+    }
+    if b_kind == kw!(":") && b.parent_kind() == kind!("template_arg") {
+        return false;
+    }
+    true
+}
+
+struct Formatter<'t, 's> {
+    text: &'s str,
+    tokens: Vec<Token<'t>>,
+}
+
+impl<'t> Formatter<'t, '_> {
+    /// Index of the first token that starts at or after `byte`
+    fn token_at(&self, byte: usize) -> usize {
+        self.tokens.partition_point(|t| t.range.start < byte)
+    }
+
+    fn starts_line(&self, idx: usize) -> bool {
+        idx == 0 || self.tokens[idx].newlines_before > 0
+    }
+
+    fn indent_level(&self, idx: usize) -> usize {
+        let token = &self.tokens[idx];
+        let start = token.range.start;
+        let mut depth = 0;
+        // The ancestor of the token that is an item of the innermost block around it
+        let mut statement = token.node;
+        let mut found_statement = false;
+        let mut child = token.node;
+        while let Some(parent) = child.parent() {
+            let is_block = parent.kind_id() == kind!("block")
+                && parent.start_byte() < start
+                && start < parent.end_byte() - 1;
+            if is_block || parent.kind_id() == kind!("source_file") {
+                if !found_statement {
+                    statement = child;
+                    found_statement = true;
+                }
+                depth += usize::from(is_block);
+            }
+            child = parent;
+        }
+
+        let statement_tokens =
+            self.token_at(statement.start_byte())..self.token_at(statement.end_byte());
+        let mut code_tokens = statement_tokens
+            .clone()
+            .filter(|i| !self.tokens[*i].node.is_extra());
+        let Some(first) = code_tokens.next() else {
+            return depth;
+        };
+        let last = code_tokens.next_back().unwrap_or(first);
+        // The `}` of a block is at the indentation of the statement it belongs to
+        let is_continuation = self.tokens[first].range.start < start
+            && start <= self.tokens[last].range.start
+            && token.kind() != kw!("}");
+        if !is_continuation {
+            return depth;
+        }
+
+        // Lines of the brackets that are still open at this token
+        let mut open_brackets = Vec::new();
+        for t in &self.tokens[statement_tokens.start..idx] {
+            if t.is_open_bracket() {
+                open_brackets.push(t.line);
+            } else if t.is_close_bracket() {
+                open_brackets.pop();
+            }
+        }
+        // The statement itself continues on this line, not just a bracket of its first line
+        let continues_statement = open_brackets.first() != Some(&self.tokens[first].line);
+        if token.is_close_bracket() {
+            open_brackets.pop();
+        }
+        open_brackets.dedup();
+        depth + usize::from(continues_statement) + open_brackets.len()
+    }
+
+    fn layout(&mut self) {
+        for idx in 0..self.tokens.len() {
+            if self.starts_line(idx) {
+                let t = &self.tokens[idx];
+                let keep_blank_line =
+                    idx > 0 && self.tokens[idx - 1].kind() != kw!("{") && t.kind() != kw!("}");
+                let max_newlines = if keep_blank_line { MAX_NEWLINES } else { 1 };
+                let newlines = t.newlines_before.min(max_newlines);
+                let indent = self.indent_level(idx);
+                let t = &mut self.tokens[idx];
+                t.newlines_before = newlines;
+                t.indent = indent;
+            } else {
+                let spaces = usize::from(space_between(&self.tokens[idx - 1], &self.tokens[idx]));
+                self.tokens[idx].spaces_before = spaces;
+            }
+        }
+    }
+
+    /// Formatted width of the tokens in `range`, which are on a single line
+    fn width(&self, range: std::ops::Range<usize>) -> usize {
+        let mut width = 0;
+        for idx in range.clone() {
+            let t = &self.tokens[idx];
+            if idx != range.start {
+                width += t.spaces_before;
+            }
+            width += self.text[t.range.clone()].chars().count();
+        }
+        width
+    }
+
+    /// The columns of an aligned declaration: write modifiers, declaration modifiers, type, name and `=`
+    fn alignment_columns(&self, statement: Node) -> Option<[Option<std::ops::Range<usize>>; 5]> {
+        if statement.kind_id() != kind!("decl_assign_statement") {
+            return None;
+        }
+        let first = self.token_at(statement.start_byte());
+        let end = self.token_at(statement.end_byte());
+        if self.tokens[first].line != self.tokens[end - 1].line {
+            return None;
+        }
+        let left = statement.child_by_field_name("assign_left")?;
+        if left.named_child_count() != 1 {
+            return None;
+        }
+        let assign_to = left.named_child(0)?;
+        let eq = self.token_at(left.end_byte());
+        if self.tokens[eq].kind() != kw!("=") {
+            return None;
+        }
+        let tokens_of =
+            |node: Node| self.token_at(node.start_byte())..self.token_at(node.end_byte());
+        let write_modifiers = assign_to
+            .child_by_field_name("write_modifiers")
+            .map(tokens_of);
+        let target = assign_to.child_by_field_name("expr_or_decl")?;
+        if target.kind_id() != kind!("declaration") {
+            return None;
+        }
+        Some([
+            write_modifiers,
+            target
+                .child_by_field_name("declaration_modifiers")
+                .map(tokens_of),
+            Some(tokens_of(target.child_by_field_name("type")?)),
+            Some(self.token_at(target.child_by_field_name("name")?.start_byte())..eq),
+            Some(eq..eq + 1),
+        ])
+    }
+
+    /// Aligns consecutive lines of declarations in each block
+    fn align_declarations(&mut self, block: Node) {
+        let mut cursor = block.walk();
+        let mut runs: Vec<Vec<[Option<std::ops::Range<usize>>; 5]>> = vec![Vec::new()];
+        let mut prev_line = None;
+        for item in block.children_by_field_name("item", &mut cursor) {
+            let line = self.tokens[self.token_at(item.start_byte())].line;
+            let columns = self.alignment_columns(item);
+            let continues_run = columns.is_some() && prev_line.map(|l| l + 1) == Some(line);
+            if !continues_run && !runs.last().unwrap().is_empty() {
+                runs.push(Vec::new());
+            }
+            if let Some(columns) = columns {
+                runs.last_mut().unwrap().push(columns);
+            }
+            prev_line = Some(line);
+        }
+
+        for run in runs {
+            if run.len() < 2 {
+                continue;
+            }
+            let mut column_starts = [0; 5];
+            let mut pos = 0;
+            for col in 0..5 {
+                let width = run
+                    .iter()
+                    .filter_map(|columns| Some(self.width(columns[col].clone()?)))
+                    .max()
+                    .unwrap_or(0);
+                column_starts[col] = pos;
+                if width > 0 {
+                    pos += width + 1;
+                }
+            }
+            for columns in &run {
+                let mut line_pos = 0;
+                for (col, range) in columns.iter().enumerate() {
+                    let Some(range) = range else { continue };
+                    self.tokens[range.start].spaces_before = column_starts[col] - line_pos;
+                    line_pos = column_starts[col] + self.width(range.clone());
+                }
+            }
+        }
+    }
+
+    fn align_all_blocks(&mut self, node: Node) {
+        if node.kind_id() == kind!("block") {
+            self.align_declarations(node);
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.child_count() != 0 {
+                self.align_all_blocks(child);
+            }
+        }
+    }
+
+    fn edits(&self, indent: &str) -> Vec<Suggestion> {
+        let mut edits = Vec::new();
+        let mut prev_end = 0;
+        // Only the part of the whitespace that changed, to keep diffs and editor changes small
+        let mut add_edit = |range: std::ops::Range<usize>, new: String| {
+            let old = &self.text[range.clone()];
+            let prefix = old
+                .bytes()
+                .zip(new.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let suffix = old[prefix..]
+                .bytes()
+                .rev()
+                .zip(new[prefix..].bytes().rev())
+                .take_while(|(a, b)| a == b)
+                .count();
+            if old != new {
+                edits.push(Suggestion {
+                    position: Span::from(range.start + prefix..range.end - suffix),
+                    replace_with: new[prefix..new.len() - suffix].to_string(),
+                });
+            }
+        };
+        for (idx, t) in self.tokens.iter().enumerate() {
+            let mut gap = "\n".repeat(t.newlines_before);
+            if self.starts_line(idx) && idx != 0 {
+                gap.push_str(&indent.repeat(t.indent));
+            }
+            gap.push_str(&" ".repeat(t.spaces_before));
+            add_edit(prev_end..t.range.start, gap);
+            prev_end = t.range.end;
+        }
+        let file_end = if self.tokens.is_empty() { "" } else { "\n" };
+        add_edit(prev_end..self.text.len(), file_end.to_string());
+        edits
+    }
+}
+
+/// The whitespace edits that format `text`. Files with syntax errors aren't formatted, the span of the first one is returned instead
+pub fn format_edits(text: &str, tree: &Tree, indent: &str) -> Result<Vec<Suggestion>, Span> {
+    if let Some(error_span) = first_syntax_error(tree.root_node()) {
+        return Err(error_span);
+    }
+    let mut formatter = Formatter {
+        text,
+        tokens: collect_tokens(tree, text),
+    };
+    formatter.layout();
+    formatter.align_all_blocks(tree.root_node());
+    Ok(formatter.edits(indent))
+}
+
+fn parse(text: &str) -> Tree {
+    let mut parser = Parser::new();
+    parser.set_language(&tree_sitter_sus::language()).unwrap();
+    parser.parse(text, None).unwrap()
+}
+
+/// Formats the given files in place, or with `check` prints the changes as a unified diff and fails if there are any
+pub fn format_files(files: &[PathBuf], check: bool) -> ExitCode {
+    let mut success = true;
+    let mut num_unformatted = 0;
+    for file in files {
+        let path = display_path(&file.to_string_lossy());
+        let text = match std::fs::read_to_string(file) {
+            Ok(text) => text,
+            Err(e) => fatal_exit!("Could not read {path}: {e}"),
+        };
+        let edits = match format_edits(&text, &parse(&text), DEFAULT_INDENT) {
+            Ok(edits) => edits,
+            Err(error_span) => {
+                let pos = FileText::new(text).byte_to_linecol(error_span.as_range().start);
+                error!(
+                    "{path}:{}:{}: Syntax error, the file is not formatted",
+                    pos.line + 1,
+                    pos.col + 1
+                );
+                success = false;
+                continue;
+            }
+        };
+        if edits.is_empty() {
+            continue;
+        }
+        num_unformatted += 1;
+        if check {
+            print!("{}", unified_diff(&path, &text, &edits));
+            success = false;
+        } else {
+            let edit_refs: Vec<&Suggestion> = edits.iter().collect();
+            let formatted = apply_suggestions(&text, 0, &edit_refs);
+            if let Err(e) = std::fs::write(file, formatted) {
+                fatal_exit!("Error while writing to {path}: {e}");
+            }
+            info!("Formatted {path}");
+        }
+    }
+    if check && num_unformatted > 0 {
+        info!("{num_unformatted} files are not formatted");
+    }
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Formats `text` with the default indentation
+#[cfg(test)]
+fn format_text(text: &str) -> String {
+    let edits = format_edits(text, &parse(text), DEFAULT_INDENT).unwrap();
+    let edit_refs: Vec<&Suggestion> = edits.iter().collect();
+    apply_suggestions(text, 0, &edit_refs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The formatter must only change whitespace
+    fn token_texts(text: &str) -> Vec<String> {
+        let tree = parse(text);
+        collect_tokens(&tree, text)
+            .iter()
+            .map(|t| text[t.range.clone()].to_string())
+            .collect()
+    }
+
+    fn assert_formats_to(input: &str, expected: &str) {
+        let formatted = format_text(input);
+        assert_eq!(formatted, expected);
+        assert_eq!(token_texts(input), token_texts(&formatted));
+        // Formatting is idempotent
+        assert_eq!(format_text(&formatted), formatted);
+    }
+
+    #[test]
+    fn test_indentation_and_spacing() {
+        assert_formats_to(
+            "module m  #( int N ){\n\n// A comment   \ninput int#(FROM:0,TO:N) a'0\n  output bool[ 3 ] b\n\tb[ 0 ]=a==-N&!(a<3)\nwhen a>2{b[1 : 2]=foo.bar(a,3)}else{\n\n\n  b[1]=false\n\n}\n}\n\n\n\nmodule n {}",
+            "module m #(int N) {\n    // A comment\n    input int #(FROM: 0, TO: N) a'0\n    output bool[3] b\n    b[0] = a == -N & !(a < 3)\n    when a > 2 { b[1:2] = foo.bar(a, 3) } else {\n        b[1] = false\n    }\n}\n\nmodule n {}\n",
+        );
+    }
+
+    #[test]
+    fn test_continuation_lines() {
+        assert_formats_to(
+            "module m {\ninterface m :\nint a,\nint b ->\nint c\nc = f(a,\nb)\nd = g #(\nX: 1,\nY: 2\n)(c)\nfor int i in 0 .. 3 {\n}\n}\n",
+            "module m {\n    interface m :\n        int a,\n        int b ->\n        int c\n    c = f(a,\n        b)\n    d = g #(\n        X: 1,\n        Y: 2\n    )(c)\n    for int i in 0..3 {\n    }\n}\n",
+        );
+    }
+
+    #[test]
+    fn test_align_declarations() {
+        assert_formats_to(
+            "module pow17 {\n    interface pow17 : int i -> int o\n    int i2 = i * i\n    reg int i4 = i2 * i2\n    int i8 = i4 * i4\n    reg int i16 = i8 * i8\n    o = i16 * i\n\n    a.b = 3\n    x = 5\n}\n",
+            "module pow17 {\n    interface pow17 : int i -> int o\n        int i2  = i * i\n    reg int i4  = i2 * i2\n        int i8  = i4 * i4\n    reg int i16 = i8 * i8\n    o = i16 * i\n\n    a.b = 3\n    x = 5\n}\n",
+        );
+    }
+
+    #[test]
+    fn test_syntax_error_is_not_formatted() {
+        let text = "module m {\n    int x = \n}\n";
+        assert!(format_edits(text, &parse(text), DEFAULT_INDENT).is_err());
+    }
+}
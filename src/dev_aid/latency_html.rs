@@ -0,0 +1,293 @@
+//! Renders the source files as HTML, with every wire annotated by its solved absolute latency.
+//!
+//! Meant for reading pipelines in code review, where the LSP isn't available.
+//! Wires are coloured by pipeline stage, hovering them shows the domain and concrete type of every instantiation.
+
+use crate::prelude::*;
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Component, Path};
+
+use crate::config::config;
+use crate::instantiation::{InstantiatedModule, IsPort, RealWire};
+use crate::linker::{FileData, GlobalUUID};
+use crate::to_string::join_shorten_filename;
+
+/// Pipeline stages cycle through this many colours
+const NUM_STAGE_COLOURS: i64 = 8;
+
+const STYLE: &str = "body { font-family: sans-serif; }
+pre { font-family: monospace; line-height: 1.4; }
+span.w { border-radius: 3px; }
+span.w:hover { outline: 1px solid #333; }
+span.w::after { content: attr(data-lat); font-size: 65%; vertical-align: super; color: #555; }
+.s0 { background: #e3f2fd; } .s1 { background: #e8f5e9; } .s2 { background: #fff8e1; } .s3 { background: #fce4ec; }
+.s4 { background: #ede7f6; } .s5 { background: #e0f7fa; } .s6 { background: #f1f8e9; } .s7 { background: #fbe9e7; }
+";
+
+/// One wire of one instantiation, that was generated from some span in the source
+struct WireAnnotation {
+    latency: Option<i64>,
+    info: String,
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '&' => result.push_str("&amp;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn wire_info(inst: &InstantiatedModule, wire: &RealWire, linker: &Linker) -> String {
+    let md = &linker.modules[inst.global_ref.id];
+    let domain = md
+        .domains
+        .get(wire.domain)
+        .map(|d| d.name.as_str())
+        .unwrap_or("?");
+    format!(
+        "{}: {} {}'{} in domain {domain}",
+        inst.name,
+        wire.typ.display(linker),
+        wire.name,
+        wire.absolute_latency
+    )
+}
+
+/// Collects the annotations of all instantiations of all modules in this file, keyed by the span they were generated from
+fn gather_annotations(
+    file: &FileData,
+    linker: &Linker,
+) -> BTreeMap<(usize, usize), Vec<WireAnnotation>> {
+    let mut annotations: BTreeMap<(usize, usize), Vec<WireAnnotation>> = BTreeMap::new();
+    let instantiator = linker.instantiator.borrow();
+    for global in &file.associated_values {
+        let GlobalUUID::Module(md_id) = *global else {
+            continue;
+        };
+        let md = &linker.modules[md_id];
+        for (_, inst) in instantiator.iter_for_module(md_id) {
+            for (_, wire) in &inst.wires {
+                // Submodule ports all map back to the submodule's name, they're shown where they're used instead
+                if matches!(wire.is_port, IsPort::SubmodulePort(..)) {
+                    continue;
+                }
+                let span = md.link_info.instructions[wire.original_instruction]
+                    .get_span()
+                    .as_range();
+                annotations
+                    .entry((span.start, span.end))
+                    .or_default()
+                    .push(WireAnnotation {
+                        latency: wire.absolute_latency.get(),
+                        info: wire_info(inst, wire, linker),
+                    });
+            }
+        }
+    }
+    annotations
+}
+
+/// Spans from the syntax tree are either nested or disjoint. Spans that partially overlap an enclosing span are dropped
+fn render_file(file: &FileData, linker: &Linker) -> String {
+    let text = &file.file_text.file_text;
+    let annotations = gather_annotations(file, linker);
+
+    // Outer spans first: by start, then longest first
+    let mut spans: Vec<_> = annotations.iter().collect();
+    spans.sort_by_key(|((start, end), _)| (*start, std::cmp::Reverse(*end)));
+
+    let mut body = String::new();
+    let mut open_ends: Vec<usize> = Vec::new();
+    let mut cursor = 0;
+    let close_until = |body: &mut String, open_ends: &mut Vec<usize>, cursor: &mut usize, pos| {
+        while let Some(&end) = open_ends.last()
+            && end <= pos
+        {
+            body.push_str(&escape_html(&text[*cursor..end]));
+            body.push_str("</span>");
+            *cursor = end;
+            open_ends.pop();
+        }
+    };
+    for (&(start, end), wires) in spans {
+        close_until(&mut body, &mut open_ends, &mut cursor, start);
+        if open_ends.last().is_some_and(|&outer_end| end > outer_end) {
+            continue;
+        }
+        body.push_str(&escape_html(&text[cursor..start]));
+        cursor = start;
+
+        let stage = wires.iter().filter_map(|w| w.latency).min();
+        let class = match stage {
+            Some(stage) => format!("w s{}", stage.rem_euclid(NUM_STAGE_COLOURS)),
+            None => "w".to_string(),
+        };
+        let label = match stage {
+            Some(stage) => format!("'{stage}"),
+            None => "'?".to_string(),
+        };
+        let title = wires
+            .iter()
+            .map(|w| w.info.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(
+            body,
+            "<span class=\"{class}\" data-lat=\"{}\" title=\"{}\">",
+            escape_html(&label),
+            escape_html(&title)
+        )
+        .unwrap();
+        open_ends.push(end);
+    }
+    close_until(&mut body, &mut open_ends, &mut cursor, text.len());
+    body.push_str(&escape_html(&text[cursor..]));
+
+    let title = escape_html(&file.file_identifier);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n<pre>{body}</pre>\n</body>\n</html>\n"
+    )
+}
+
+/// The name of the html file for a source file: its path relative to the working directory, flattened into one name.
+/// Paths that flatten to a name that's already `taken` get a numbered prefix
+fn html_file_name(file_identifier: &str, taken: &mut HashSet<String>) -> String {
+    let path = Path::new(file_identifier);
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    let flat = relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+        .replace(|c: char| !(c.is_alphanumeric() || "._-".contains(c)), "_");
+
+    let mut file_name = join_shorten_filename(&flat, ".html");
+    let mut counter = 1;
+    while !taken.insert(file_name.clone()) {
+        counter += 1;
+        file_name = join_shorten_filename(&format!("{counter}_{flat}"), ".html");
+    }
+    file_name
+}
+
+/// Writes one html file per user source file into `out_dir`, and an `index.html` linking them
+pub fn write_latency_html(linker: &Linker, out_dir: &Path) {
+    if let Err(e) = fs::create_dir_all(out_dir) {
+        fatal_exit!(
+            "Could not create the output directory {}: {e}",
+            out_dir.to_string_lossy()
+        );
+    }
+
+    let mut index = String::new();
+    let mut taken = HashSet::from(["index.html".to_string()]);
+    for (_, file) in &linker.files {
+        if file.is_std {
+            continue;
+        }
+        let file_name = html_file_name(&file.file_identifier, &mut taken);
+        let path = out_dir.join(&file_name);
+        if let Err(e) = fs::write(&path, render_file(file, linker)) {
+            fatal_exit!("Error while writing to {}: {e}", path.to_string_lossy());
+        }
+        writeln!(
+            index,
+            "<li><a href=\"{}\">{}</a></li>",
+            escape_html(&file_name),
+            escape_html(&file.file_identifier)
+        )
+        .unwrap();
+    }
+
+    let path = out_dir.join("index.html");
+    let index = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Latencies</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n<ul>\n{index}</ul>\n</body>\n</html>\n"
+    );
+    if let Err(e) = fs::write(&path, index) {
+        fatal_exit!("Error while writing to {}: {e}", path.to_string_lossy());
+    }
+    if !config().ci {
+        info!(
+            "Latency annotated source written to {}",
+            out_dir.canonicalize().unwrap().to_string_lossy()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_files, compile_text};
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("a <b> & 'c' \"d\""),
+            "a &lt;b&gt; &amp; &#39;c&#39; &quot;d&quot;"
+        );
+    }
+
+    #[test]
+    fn test_render_file() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let html = render_file(&linker.files[file], &linker);
+        // Both instantiations of `delay` annotate the declaration of `b`
+        assert!(html.contains(
+            "<span class=\"w s1\" data-lat=\"&#39;1\" title=\"delay #(T: type bool #()): bool #() b&#39;1 in domain clk\ndelay #(T: type bool #()[2]): bool #()[2] b&#39;1 in domain clk\">b</span>"
+        ));
+        assert!(html.contains("<title>test.sus</title>"));
+        assert!(html.contains("reg b = <span"));
+    }
+
+    #[test]
+    fn test_html_file_names_are_unique() {
+        let mut taken = HashSet::from(["index.html".to_string()]);
+        assert_eq!(html_file_name("/x/a/m.sus", &mut taken), "x_a_m.sus.html");
+        assert_eq!(html_file_name("/x/b/m.sus", &mut taken), "x_b_m.sus.html");
+        // Flattens to the same name as the first
+        assert_eq!(html_file_name("/x/a_m.sus", &mut taken), "2_x_a_m.sus.html");
+        assert_eq!(html_file_name("/index", &mut taken), "2_index.html");
+    }
+
+    #[test]
+    fn test_write_latency_html() {
+        let (linker, _) = compile_files(&[
+            (
+                "/project/a/m.sus",
+                "module m {\n    input bool x'0\n    output bool y\n    reg y = x\n}\n",
+            ),
+            (
+                "/project/b/m.sus",
+                "module n {\n    input bool x'0\n    output bool y\n    y = x\n}\n",
+            ),
+        ]);
+        let out_dir = std::env::temp_dir().join(format!("sus_latency_html_{}", std::process::id()));
+        write_latency_html(&linker, &out_dir);
+
+        let first = fs::read_to_string(out_dir.join("project_a_m.sus.html")).unwrap();
+        let second = fs::read_to_string(out_dir.join("project_b_m.sus.html")).unwrap();
+        assert!(first.contains("module m"));
+        assert!(second.contains("module n"));
+        let index = fs::read_to_string(out_dir.join("index.html")).unwrap();
+        assert!(index.contains("<a href=\"project_a_m.sus.html\">/project/a/m.sus</a>"));
+        assert!(index.contains("<a href=\"project_b_m.sus.html\">/project/b/m.sus</a>"));
+        fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
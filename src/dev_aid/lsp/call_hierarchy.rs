@@ -0,0 +1,290 @@
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Range, SymbolKind, Url,
+};
+
+use crate::flattening::{
+    Expression, ExpressionSource, GlobalReference, Instruction, Module, WireReference,
+    WireReferenceRoot,
+};
+use crate::linker::GlobalUUID;
+use crate::prelude::*;
+
+use super::span_to_lsp_range;
+use super::tree_walk::{InGlobal, LocationInfo, get_selected_object};
+
+/// The instantiations of other modules in `md`: explicit submodules, and modules called inline as in `Adder(a, b)`
+fn instantiations(md: &Module) -> impl Iterator<Item = (FlatID, &GlobalReference<ModuleUUID>)> {
+    md.link_info
+        .instructions
+        .iter()
+        .filter_map(|(id, instr)| match instr {
+            Instruction::SubModule(sm) => Some((id, &sm.module_ref)),
+            Instruction::Expression(Expression {
+                source:
+                    ExpressionSource::WireRef(WireReference {
+                        root: WireReferenceRoot::NamedModule(md_ref),
+                        ..
+                    }),
+                ..
+            }) => Some((id, md_ref)),
+            _ => None,
+        })
+}
+
+/// How often the instructions `original_instructions` of `md_id` were instantiated, summed over all its instances.
+/// Instructions in generative `for` loops and modules that are instantiated several times add up
+fn concrete_instantiation_count(
+    linker: &Linker,
+    md_id: ModuleUUID,
+    original_instructions: &[FlatID],
+) -> usize {
+    linker
+        .instantiator
+        .borrow()
+        .iter_for_module(md_id)
+        .map(|(_, inst)| {
+            inst.submodules
+                .iter()
+                .filter(|(_, sm)| original_instructions.contains(&sm.original_instruction))
+                .count()
+        })
+        .sum()
+}
+
+fn make_item(linker: &Linker, md_id: ModuleUUID, detail: Option<String>) -> CallHierarchyItem {
+    let link_info = &linker.modules[md_id].link_info;
+    let file_data = &linker.files[link_info.file];
+    CallHierarchyItem {
+        name: link_info.name.clone(),
+        kind: SymbolKind::MODULE,
+        tags: None,
+        detail,
+        uri: Url::parse(&file_data.file_identifier).unwrap(),
+        range: span_to_lsp_range(&file_data.file_text, link_info.span),
+        selection_range: span_to_lsp_range(&file_data.file_text, link_info.name_span),
+        // Names can be ambiguous, the follow-up requests find the module by its id
+        data: Some(md_id.get_hidden_value().into()),
+    }
+}
+
+fn instances_detail(count: usize) -> Option<String> {
+    match count {
+        0 => None,
+        1 => Some("1 instance".to_string()),
+        n => Some(format!("{n} instances")),
+    }
+}
+
+/// Calls from `parent` to `child`, grouped per child module in order of first appearance
+struct Call {
+    parent: ModuleUUID,
+    child: ModuleUUID,
+    instructions: Vec<FlatID>,
+    ranges: Vec<Range>,
+}
+
+fn calls_from(linker: &Linker, parent: ModuleUUID) -> Vec<Call> {
+    let md = &linker.modules[parent];
+    let file_text = &linker.files[md.link_info.file].file_text;
+    let mut calls: Vec<Call> = Vec::new();
+    for (id, md_ref) in instantiations(md) {
+        let range = span_to_lsp_range(file_text, md_ref.name_span);
+        if let Some(call) = calls.iter_mut().find(|c| c.child == md_ref.id) {
+            call.instructions.push(id);
+            call.ranges.push(range);
+        } else {
+            calls.push(Call {
+                parent,
+                child: md_ref.id,
+                instructions: vec![id],
+                ranges: vec![range],
+            });
+        }
+    }
+    calls
+}
+
+/// The module named at the cursor, or else the module the cursor is in
+pub fn prepare_call_hierarchy(
+    linker: &Linker,
+    file_id: FileUUID,
+    position: usize,
+) -> Option<Vec<CallHierarchyItem>> {
+    let md_id = match get_selected_object(linker, file_id, position) {
+        Some((_, LocationInfo::Global(GlobalUUID::Module(md_id)))) => md_id,
+        Some((_, LocationInfo::InGlobal(_, _, _, InGlobal::NamedSubmodule(sm)))) => {
+            sm.module_ref.id
+        }
+        _ => linker.files[file_id]
+            .associated_values
+            .iter()
+            .find_map(|global| match *global {
+                GlobalUUID::Module(md_id)
+                    if linker.modules[md_id].link_info.span.contains_pos(position) =>
+                {
+                    Some(md_id)
+                }
+                _ => None,
+            })?,
+    };
+    let instance_count = linker.instantiator.borrow().iter_for_module(md_id).count();
+    Some(vec![make_item(
+        linker,
+        md_id,
+        instances_detail(instance_count),
+    )])
+}
+
+/// The module `item` was made for. Edits since may have removed it, or reused its id for another module, then it's looked up by name
+fn module_of_item(linker: &Linker, item: &CallHierarchyItem) -> Option<ModuleUUID> {
+    let from_data = item
+        .data
+        .as_ref()
+        .and_then(|data| data.as_u64())
+        .map(|id| ModuleUUID::from_hidden_value(id as usize))
+        .filter(|md_id| {
+            linker
+                .modules
+                .get(*md_id)
+                .is_some_and(|md| md.link_info.name == item.name)
+        });
+    from_data.or_else(|| match linker.get_by_name(&item.name) {
+        Ok(GlobalUUID::Module(md_id)) => Some(md_id),
+        _ => None,
+    })
+}
+
+/// The submodules `item` instantiates. The detail shows how many concrete submodules each one became
+pub fn outgoing_calls(linker: &Linker, item: &CallHierarchyItem) -> Vec<CallHierarchyOutgoingCall> {
+    let Some(md_id) = module_of_item(linker, item) else {
+        return Vec::new();
+    };
+    calls_from(linker, md_id)
+        .into_iter()
+        .map(|call| {
+            let count = concrete_instantiation_count(linker, call.parent, &call.instructions);
+            CallHierarchyOutgoingCall {
+                to: make_item(linker, call.child, instances_detail(count)),
+                from_ranges: call.ranges,
+            }
+        })
+        .collect()
+}
+
+/// Every module that instantiates `item`, with how many concrete submodules of `item` it has
+pub fn incoming_calls(linker: &Linker, item: &CallHierarchyItem) -> Vec<CallHierarchyIncomingCall> {
+    let Some(md_id) = module_of_item(linker, item) else {
+        return Vec::new();
+    };
+    linker
+        .modules
+        .iter()
+        .flat_map(|(parent, _)| calls_from(linker, parent))
+        .filter(|call| call.child == md_id)
+        .map(|call| {
+            let count = concrete_instantiation_count(linker, call.parent, &call.instructions);
+            CallHierarchyIncomingCall {
+                from: make_item(
+                    linker,
+                    call.parent,
+                    instances_detail(count).map(|detail| format!("{detail} of {}", item.name)),
+                ),
+                from_ranges: call.ranges,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::compile_text_lsp;
+
+    const CALLS: &str = "
+module leaf {
+    interface leaf : bool a -> bool b
+    b = a
+}
+module mid {
+    interface mid : bool a -> bool b
+    leaf l
+    b = leaf(l(a))
+}
+module top {
+    mid m1
+    mid m2
+    bool x = m1(m2(true))
+}
+module dup {
+    leaf l
+}
+module dup {
+    mid m
+}
+";
+
+    fn item_at_position(linker: &Linker, file: FileUUID, position: usize) -> CallHierarchyItem {
+        let mut items = prepare_call_hierarchy(linker, file, position).unwrap();
+        assert_eq!(items.len(), 1);
+        items.pop().unwrap()
+    }
+
+    fn item_at(linker: &Linker, file: FileUUID, text: &str) -> CallHierarchyItem {
+        item_at_position(linker, file, CALLS.find(text).unwrap() + text.len())
+    }
+
+    fn outgoing_names(linker: &Linker, item: &CallHierarchyItem) -> Vec<(String, usize)> {
+        outgoing_calls(linker, item)
+            .into_iter()
+            .map(|call| (call.to.name, call.from_ranges.len()))
+            .collect()
+    }
+
+    #[test]
+    fn test_outgoing_calls() {
+        let (linker, file) = compile_text_lsp(CALLS);
+        let top = item_at(&linker, file, "module to");
+        assert_eq!(top.name, "top");
+        assert_eq!(outgoing_names(&linker, &top), [("mid".to_string(), 2)]);
+        // The inline call and the submodule are grouped
+        let mid = item_at(&linker, file, "mid m1");
+        assert_eq!(mid.name, "mid");
+        assert_eq!(outgoing_names(&linker, &mid), [("leaf".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_incoming_calls() {
+        let (linker, file) = compile_text_lsp(CALLS);
+        let leaf = item_at(&linker, file, "module le");
+        let mut callers: Vec<_> = incoming_calls(&linker, &leaf)
+            .into_iter()
+            .map(|call| (call.from.name, call.from_ranges.len()))
+            .collect();
+        callers.sort();
+        assert_eq!(callers, [("dup".to_string(), 1), ("mid".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_ambiguous_names_use_item_data() {
+        let (linker, file) = compile_text_lsp(CALLS);
+        let first_dup = item_at_position(&linker, file, CALLS.find("module du").unwrap() + 9);
+        let second_dup = item_at_position(&linker, file, CALLS.rfind("module du").unwrap() + 9);
+        assert_eq!(
+            (first_dup.name.as_str(), second_dup.name.as_str()),
+            ("dup", "dup")
+        );
+        assert_eq!(
+            outgoing_names(&linker, &first_dup),
+            [("leaf".to_string(), 1)]
+        );
+        assert_eq!(
+            outgoing_names(&linker, &second_dup),
+            [("mid".to_string(), 1)]
+        );
+
+        // Stale data, pointing at a module of another name, falls back to the name
+        let mut top = item_at(&linker, file, "module to");
+        top.data = second_dup.data.clone();
+        assert_eq!(outgoing_names(&linker, &top), [("mid".to_string(), 2)]);
+    }
+}
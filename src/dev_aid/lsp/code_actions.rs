@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, TextEdit, Url, WorkspaceEdit};
+
+use crate::errors::Suggestion;
+use crate::linker::FileData;
+use crate::prelude::*;
+
+use super::{convert_diagnostic, span_to_lsp_range};
+
+fn make_edit(file_data: &FileData, suggestions: &[Suggestion]) -> WorkspaceEdit {
+    let edits = suggestions
+        .iter()
+        .map(|s| TextEdit {
+            range: span_to_lsp_range(&file_data.file_text, s.position),
+            new_text: s.replace_with.clone(),
+        })
+        .collect();
+    let uri = Url::parse(&file_data.file_identifier).unwrap();
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri, edits)])),
+        document_changes: None,
+        change_annotations: None,
+    }
+}
+
+fn suggestion_title(file_data: &FileData, s: &Suggestion) -> String {
+    let replaced = file_data.file_text[s.position].trim();
+    if s.replace_with.is_empty() {
+        format!("Remove `{replaced}`")
+    } else {
+        format!("Replace `{replaced}` with `{}`", s.replace_with)
+    }
+}
+
+fn overlaps(span: Span, range: &std::ops::Range<usize>) -> bool {
+    let span = span.as_range();
+    span.start <= range.end && span.end >= range.start
+}
+
+/// One quick fix per suggestion of the errors in `range`, and a "fix all" for all suggestions in the file
+pub fn make_code_actions(
+    linker: &Linker,
+    file_id: FileUUID,
+    range: std::ops::Range<usize>,
+) -> Vec<CodeActionOrCommand> {
+    let file_data = &linker.files[file_id];
+    let mut errors = linker.collect_all_errors();
+    let errs_for_file = std::mem::take(&mut errors[file_id]);
+
+    let mut actions = Vec::new();
+    for err in &errs_for_file {
+        let in_range = overlaps(err.position, &range)
+            || err.suggestions.iter().any(|s| overlaps(s.position, &range));
+        if !in_range {
+            continue;
+        }
+        let diagnostic = convert_diagnostic(err.clone(), &file_data.file_text, linker);
+        for s in &err.suggestions {
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: suggestion_title(file_data, s),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(make_edit(file_data, std::slice::from_ref(s))),
+                command: None,
+                is_preferred: Some(err.suggestions.len() == 1),
+                disabled: None,
+                data: None,
+            }));
+        }
+    }
+
+    let all_suggestions = errs_for_file.compatible_suggestions();
+    if !all_suggestions.is_empty() {
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title: format!(
+                "Apply all {} suggested fixes in file",
+                all_suggestions.len()
+            ),
+            kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+            diagnostics: None,
+            edit: Some(make_edit(file_data, &all_suggestions)),
+            command: None,
+            is_preferred: None,
+            disabled: None,
+            data: None,
+        }));
+    }
+    actions
+}
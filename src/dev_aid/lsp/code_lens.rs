@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use ibig::UBig;
+use lsp_types::{CodeLens, Command};
+
+use crate::codegen::system_verilog::gen_verilog_code;
+use crate::flattening::Direction;
+use crate::instantiation::{InstantiatedModule, RealWireDataSource};
+use crate::linker::GlobalUUID;
+use crate::prelude::*;
+use crate::to_string::join_shorten_filename;
+
+use super::instance_selection::InstanceSelection;
+use super::span_to_lsp_range;
+
+/// Command that writes the SystemVerilog of an instance to a temporary file and opens it in the editor.
+///
+/// Arguments: `[module_name, instance_name]`
+pub const OPEN_GENERATED_CODE_COMMAND: &str = "sus.openGeneratedCode";
+
+/// State registers and latency registers of `inst` and all of its submodules, in bits.
+/// The latency registers are the ones [gen_verilog_code] inserts
+fn register_bits(
+    inst: &InstantiatedModule,
+    memo: &mut HashMap<*const InstantiatedModule, UBig>,
+) -> UBig {
+    if let Some(found) = memo.get(&(inst as *const InstantiatedModule)) {
+        return found.clone();
+    }
+    let needed_untils = inst.compute_needed_untils();
+    let mut total = UBig::from(0u8);
+    for (id, w) in &inst.wires {
+        let mut registers = needed_untils[id] - w.absolute_latency.unwrap();
+        if let RealWireDataSource::Multiplexer {
+            is_state: Some(_), ..
+        } = &w.source
+        {
+            registers += 1;
+        }
+        total += w.typ.sizeof() * UBig::from(registers as u64);
+    }
+    for (_, sm) in &inst.submodules {
+        if let Some(sm_inst) = sm.instance.get() {
+            total += register_bits(sm_inst, memo);
+        }
+    }
+    memo.insert(inst, total.clone());
+    total
+}
+
+/// Latency from the earliest input port to the latest output port
+fn pipeline_depth(inst: &InstantiatedModule) -> Option<i64> {
+    let latencies_of = |direction: Direction| {
+        inst.interface_ports
+            .iter()
+            .filter_map(move |(_, port)| port.as_ref())
+            .filter(move |port| port.direction == direction)
+            .filter_map(|port| port.absolute_latency.get())
+    };
+    let first_input = latencies_of(Direction::Input).min()?;
+    let last_output = latencies_of(Direction::Output).max()?;
+    Some(last_output - first_input)
+}
+
+fn instance_count_text(count: usize) -> String {
+    if count == 1 {
+        "1 instance".to_string()
+    } else {
+        format!("{count} instances")
+    }
+}
+
+fn summarize(inst: &InstantiatedModule, num_instances: usize) -> String {
+    let mut title = instance_count_text(num_instances);
+    if num_instances > 1 {
+        title.push_str(&format!(" · {}", inst.name));
+    }
+    if inst.errors.did_error {
+        title.push_str(" · has errors");
+        return title;
+    }
+    let bits = register_bits(inst, &mut HashMap::new());
+    title.push_str(&format!(" · {bits} register bits"));
+    if let Some(depth) = pipeline_depth(inst) {
+        title.push_str(&format!(" · pipeline depth {depth}"));
+    }
+    title
+}
+
+/// One lens above each module, summarizing the instance picked with [InstanceSelection]. Clicking it opens the generated code
+pub fn make_code_lenses(
+    linker: &Linker,
+    file_id: FileUUID,
+    selection: &InstanceSelection,
+) -> Vec<CodeLens> {
+    let file_data = &linker.files[file_id];
+    let mut lenses = Vec::new();
+    for global in &file_data.associated_values {
+        let GlobalUUID::Module(md_id) = *global else {
+            continue;
+        };
+        let md = &linker.modules[md_id];
+        let num_instances = linker.instantiator.borrow().iter_for_module(md_id).count();
+        let command = match selection.get(linker, md_id) {
+            Some(inst) => Command {
+                title: summarize(&inst, num_instances),
+                command: OPEN_GENERATED_CODE_COMMAND.to_string(),
+                arguments: Some(vec![
+                    md.link_info.name.clone().into(),
+                    inst.name.clone().into(),
+                ]),
+            },
+            None => Command {
+                title: "Not instantiated".to_string(),
+                command: String::new(),
+                arguments: None,
+            },
+        };
+        lenses.push(CodeLens {
+            range: span_to_lsp_range(&file_data.file_text, md.link_info.name_span),
+            command: Some(command),
+            data: None,
+        });
+    }
+    lenses
+}
+
+fn find_instance(
+    linker: &Linker,
+    md_name: &str,
+    instance_name: &str,
+) -> Option<Rc<InstantiatedModule>> {
+    let md_id = linker.modules.find(|_, md| md.link_info.name == md_name)?;
+    linker
+        .instantiator
+        .borrow()
+        .iter_for_module(md_id)
+        .find(|(_, inst)| inst.name == instance_name)
+        .map(|(_, inst)| inst.clone())
+}
+
+/// Writes the SystemVerilog of the instance to a temporary file, for [OPEN_GENERATED_CODE_COMMAND]
+pub fn write_generated_code(
+    linker: &Linker,
+    md_name: &str,
+    instance_name: &str,
+) -> Result<PathBuf, String> {
+    let inst = find_instance(linker, md_name, instance_name)
+        .ok_or_else(|| format!("No instance {instance_name} of '{md_name}'"))?;
+    if inst.errors.did_error {
+        return Err(format!("Cannot codegen {instance_name} due to errors"));
+    }
+    let code = gen_verilog_code(&inst, linker);
+
+    let dir = std::env::temp_dir().join("sus_generated_code");
+    let path = dir.join(join_shorten_filename(&inst.mangled_name, ".sv"));
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, code))
+        .map_err(|e| format!("Could not write {}: {e}", path.to_string_lossy()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text};
+
+    fn titles(linker: &Linker, file: FileUUID, selection: &InstanceSelection) -> Vec<String> {
+        make_code_lenses(linker, file, selection)
+            .into_iter()
+            .map(|lens| lens.command.unwrap().title)
+            .collect()
+    }
+
+    #[test]
+    fn test_lenses_summarize_the_selected_instance() {
+        let text = format!("{TWO_DELAYS}module never_used #(T) {{}}\n");
+        let (linker, file) = compile_text(&text);
+        let mut selection = InstanceSelection::default();
+        assert_eq!(
+            titles(&linker, file, &selection),
+            [
+                "2 instances · delay #(T: type bool #()) · 1 register bits · pipeline depth 1",
+                "1 instance · 3 register bits · pipeline depth 1",
+                "Not instantiated",
+            ]
+        );
+
+        selection
+            .select(&linker, "delay", &serde_json::json!(1))
+            .unwrap();
+        assert_eq!(
+            titles(&linker, file, &selection)[0],
+            "2 instances · delay #(T: type bool #()[2]) · 2 register bits · pipeline depth 1"
+        );
+
+        let lenses = make_code_lenses(&linker, file, &selection);
+        let command = lenses[0].command.as_ref().unwrap();
+        assert_eq!(command.command, OPEN_GENERATED_CODE_COMMAND);
+        assert_eq!(
+            command.arguments,
+            Some(vec!["delay".into(), "delay #(T: type bool #()[2])".into()])
+        );
+        // The lens sits on the module name
+        assert_eq!(lenses[0].range.start.line, 1);
+    }
+
+    #[test]
+    fn test_write_generated_code() {
+        let (linker, _file) = compile_text(TWO_DELAYS);
+        let path = write_generated_code(&linker, "delay", "delay #(T: type bool #())").unwrap();
+        let code = std::fs::read_to_string(path).unwrap();
+        assert!(code.contains("module delay_T_type_bool("));
+        assert!(write_generated_code(&linker, "delay", "delay #(T: type int)").is_err());
+    }
+}
@@ -0,0 +1,485 @@
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Documentation as LspDocumentation, InsertTextFormat,
+};
+
+use crate::flattening::{Instruction, InterfaceDeclKind, Module};
+use crate::linker::{Documentation, GlobalUUID, LinkInfo};
+use crate::prelude::*;
+use crate::typing::template::TemplateKind;
+
+use sus_proc_macro::{kind, kw};
+use tree_sitter::{Node, Tree};
+
+/// What the syntax in front of the cursor says about what can be written here
+#[derive(Debug, PartialEq, Eq)]
+enum CompletionContext<'t> {
+    /// `submodule.`
+    Member {
+        of: &'t str,
+    },
+    /// An argument name in `Global #(`
+    TemplateArg {
+        of: &'t str,
+    },
+    /// After `input`, `state`, `gen`, `type` etc, or in the port list of an interface
+    Type,
+    Anywhere,
+}
+
+const TYPE_PRECEDING_KEYWORDS: [u16; 6] = [
+    kw!("input"),
+    kw!("output"),
+    kw!("state"),
+    kw!("gen"),
+    kw!("type"),
+    kw!("const"),
+];
+const INTERFACE_KEYWORDS: [u16; 3] = [kw!("interface"), kw!("action"), kw!("trigger")];
+const PORT_SEPARATORS: [u16; 3] = [kw!(":"), kw!(","), kw!("->")];
+
+/// The tokens of the syntax tree that end before `byte`. Comments, newlines and the tokens the parser inserted to recover from syntax errors are left out
+pub(super) fn tokens_before(tree: &Tree, byte: usize) -> Vec<Node<'_>> {
+    let mut tokens = Vec::new();
+    let mut cursor = tree.walk();
+    if !cursor.goto_first_child() {
+        return tokens;
+    }
+    loop {
+        let node = cursor.node();
+        if node.start_byte() >= byte {
+            return tokens;
+        }
+        if node.child_count() != 0 {
+            cursor.goto_first_child();
+            continue;
+        }
+        if node.end_byte() <= byte
+            && !node.byte_range().is_empty()
+            && !node.is_extra()
+            && node.kind_id() != kw!("\n")
+        {
+            tokens.push(node);
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return tokens;
+            }
+        }
+    }
+}
+
+/// Drops the identifier the cursor is in the middle of typing, if any
+pub(super) fn without_partial_word(mut tokens: Vec<Node<'_>>, position: usize) -> Vec<Node<'_>> {
+    if tokens
+        .last()
+        .is_some_and(|t| t.kind_id() == kind!("identifier") && t.end_byte() == position)
+    {
+        tokens.pop();
+    }
+    tokens
+}
+
+/// Index of the innermost bracket token that is still open at the end of `tokens`. The `{` of blocks counts too, so a statement in a block is not inside the brackets of an earlier line
+pub(super) fn innermost_open_bracket(tokens: &[Node]) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, token) in tokens.iter().enumerate().rev() {
+        let kind = token.kind_id();
+        if [kw!(")"), kw!("]"), kw!("}")].contains(&kind) {
+            depth += 1;
+        } else if [kw!("("), kw!("["), kw!("#("), kw!("{")].contains(&kind) {
+            if depth == 0 {
+                return Some(idx);
+            }
+            depth -= 1;
+        }
+    }
+    None
+}
+
+/// The name of the global whose template arguments are opened by `open`, a `#(` token
+pub(super) fn template_args_owner<'t>(
+    open: Node,
+    tokens: &[Node],
+    text: &'t str,
+) -> Option<&'t str> {
+    let name = match open.parent() {
+        Some(args) if args.kind_id() == kind!("template_args") => {
+            let namespace_list = args.parent()?.child_by_field_name("namespace_list")?;
+            namespace_list.named_child(namespace_list.named_child_count().checked_sub(1)?)?
+        }
+        // Error recovery may not have built the template_global, take the identifier in front of the `#(`
+        _ => {
+            let idx = tokens.iter().position(|t| *t == open)?;
+            *tokens[..idx].last()?
+        }
+    };
+    (name.kind_id() == kind!("identifier")).then(|| &text[name.byte_range()])
+}
+
+fn has_ancestor(node: Node, kind: u16) -> bool {
+    std::iter::successors(node.parent(), |n| n.parent()).any(|n| n.kind_id() == kind)
+}
+
+fn completion_context<'t>(tree: &Tree, text: &'t str, position: usize) -> CompletionContext<'t> {
+    let tokens = without_partial_word(tokens_before(tree, position), position);
+    let Some(prev) = tokens.last() else {
+        return CompletionContext::Anywhere;
+    };
+    let prev_kind = prev.kind_id();
+
+    if prev_kind == kw!(".")
+        && let [.., of, _] = tokens.as_slice()
+        && of.kind_id() == kind!("identifier")
+    {
+        return CompletionContext::Member {
+            of: &text[of.byte_range()],
+        };
+    }
+
+    if TYPE_PRECEDING_KEYWORDS.contains(&prev_kind) {
+        return CompletionContext::Type;
+    }
+
+    let open_bracket = innermost_open_bracket(&tokens).map(|idx| tokens[idx]);
+    if let Some(open) = open_bracket
+        && open.kind_id() == kw!("#(")
+        && (prev_kind == kw!("#(") || prev_kind == kw!(","))
+        && let Some(of) = template_args_owner(open, &tokens, text)
+    {
+        return CompletionContext::TemplateArg { of };
+    }
+
+    // Port lists of interfaces: `interface x : int a, bool b -> int c`. While typing the ports don't always parse yet, then the line must start with the interface keyword
+    if PORT_SEPARATORS.contains(&prev_kind)
+        && open_bracket.is_none_or(|open| open.kind_id() == kw!("{"))
+    {
+        let row = prev.start_position().row;
+        let line_start = tokens
+            .iter()
+            .rposition(|t| t.start_position().row != row)
+            .map_or(0, |idx| idx + 1);
+        let mut line_kinds = tokens[line_start..]
+            .iter()
+            .map(|t| t.kind_id())
+            .skip_while(|kind| *kind == kw!("local"));
+        if has_ancestor(*prev, kind!("interface_ports"))
+            || line_kinds
+                .next()
+                .is_some_and(|kind| INTERFACE_KEYWORDS.contains(&kind))
+        {
+            return CompletionContext::Type;
+        }
+    }
+
+    CompletionContext::Anywhere
+}
+
+pub(super) fn documentation(
+    doc: &Documentation,
+    file: FileUUID,
+    linker: &Linker,
+) -> Option<LspDocumentation> {
+    let text = doc.to_string(&linker.files[file].file_text);
+    (!text.trim().is_empty()).then_some(LspDocumentation::String(text))
+}
+
+fn global_item(linker: &Linker, global: GlobalUUID) -> CompletionItem {
+    let link_info = &linker.globals[global];
+    let kind = match global {
+        GlobalUUID::Module(_) => CompletionItemKind::FUNCTION,
+        GlobalUUID::Type(_) => CompletionItemKind::STRUCT,
+        GlobalUUID::Constant(_) => CompletionItemKind::CONSTANT,
+    };
+    let file_text = &linker.files[link_info.file].file_text;
+    CompletionItem {
+        label: link_info.name.clone(),
+        kind: Some(kind),
+        detail: Some(link_info.display_full_name_and_args(file_text).to_string()),
+        documentation: documentation(&link_info.documentation, link_info.file, linker),
+        ..Default::default()
+    }
+}
+
+/// Ports and interfaces of the module instantiated as `submodule_name`
+fn member_items(linker: &Linker, md: &Module, submodule_name: &str) -> Vec<CompletionItem> {
+    let Some(sm) = md
+        .link_info
+        .instructions
+        .iter()
+        .find_map(|(_, instr)| match instr {
+            Instruction::SubModule(sm) if sm.name == submodule_name => Some(sm),
+            _ => None,
+        })
+    else {
+        return Vec::new();
+    };
+    let target = &linker.modules[sm.module_ref.id];
+    let target_link_info = &target.link_info;
+    let file_text = &linker.files[target_link_info.file].file_text;
+
+    let mut result = Vec::new();
+    for (_, interface) in &target.interfaces {
+        if let Some(InterfaceDeclKind::Interface(decl_id)) = interface.declaration_instruction {
+            let decl = target_link_info.instructions[decl_id].unwrap_interface();
+            // Show the whole signature, up to the last port
+            let signature_span = decl
+                .inputs
+                .iter()
+                .chain(&decl.outputs)
+                .map(|port| {
+                    target_link_info.instructions[*port]
+                        .unwrap_declaration()
+                        .decl_span
+                })
+                .max_by_key(|span| span.as_range().end)
+                .map_or(decl.decl_span, |last_port| {
+                    Span::new_overarching(decl.decl_span, last_port)
+                });
+            result.push(CompletionItem {
+                label: interface.name.clone(),
+                kind: Some(CompletionItemKind::METHOD),
+                detail: Some(file_text[signature_span].to_string()),
+                documentation: documentation(&decl.documentation, target_link_info.file, linker),
+                ..Default::default()
+            });
+        }
+    }
+    for (_, port) in &target.ports {
+        // The trigger ports of actions and triggers are offered as their interface
+        let Instruction::Declaration(decl) =
+            &target_link_info.instructions[port.declaration_instruction]
+        else {
+            continue;
+        };
+        result.push(CompletionItem {
+            label: port.name.clone(),
+            kind: Some(CompletionItemKind::FIELD),
+            detail: Some(format!(
+                "{} {}",
+                port.direction,
+                &file_text[decl.typ_expr.get_span()]
+            )),
+            documentation: documentation(&decl.documentation, target_link_info.file, linker),
+            ..Default::default()
+        });
+    }
+    result
+}
+
+/// Named arguments for the parameters of `global_name`, as `NAME: ` snippets
+fn template_arg_items(linker: &Linker, global_name: &str) -> Vec<CompletionItem> {
+    let Ok(global) = linker.get_by_name(global_name) else {
+        return Vec::new();
+    };
+    let link_info = &linker.globals[global];
+    let file_text = &linker.files[link_info.file].file_text;
+    link_info
+        .parameters
+        .iter()
+        .map(|(_, param)| {
+            let (insert_text, detail) = match &param.kind {
+                TemplateKind::Type(_) => (format!("{}: type $0", param.name), "type".to_string()),
+                TemplateKind::Value(v) => (
+                    format!("{}: $0", param.name),
+                    file_text[v.decl_span].to_string(),
+                ),
+            };
+            CompletionItem {
+                label: param.name.clone(),
+                kind: Some(CompletionItemKind::TYPE_PARAMETER),
+                detail: Some(detail),
+                insert_text: Some(insert_text),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn type_items(linker: &Linker, current: Option<&LinkInfo>) -> Vec<CompletionItem> {
+    let mut result: Vec<CompletionItem> = linker
+        .types
+        .iter()
+        .map(|(id, _)| global_item(linker, GlobalUUID::Type(id)))
+        .collect();
+    if let Some(link_info) = current {
+        for (_, param) in &link_info.parameters {
+            if let TemplateKind::Type(_) = param.kind {
+                result.push(CompletionItem {
+                    label: param.name.clone(),
+                    kind: Some(CompletionItemKind::TYPE_PARAMETER),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    result
+}
+
+fn all_items(linker: &Linker, current: Option<&LinkInfo>) -> Vec<CompletionItem> {
+    let mut result: Vec<CompletionItem> = linker
+        .files
+        .iter()
+        .flat_map(|(_, file_data)| file_data.associated_values.iter())
+        .map(|global| global_item(linker, *global))
+        .collect();
+
+    if let Some(link_info) = current {
+        for (_id, v) in &link_info.instructions {
+            let (name, kind, doc) = match v {
+                Instruction::Declaration(d) => {
+                    (&d.name, CompletionItemKind::VARIABLE, &d.documentation)
+                }
+                Instruction::SubModule(sm) => {
+                    (&sm.name, CompletionItemKind::MODULE, &sm.documentation)
+                }
+                _ => continue,
+            };
+            result.push(CompletionItem {
+                label: name.to_string(),
+                kind: Some(kind),
+                documentation: documentation(doc, link_info.file, linker),
+                ..Default::default()
+            });
+        }
+    }
+    result
+}
+
+pub fn gather_completions(
+    linker: &Linker,
+    file_id: FileUUID,
+    position: usize,
+) -> Vec<CompletionItem> {
+    let file_data = &linker.files[file_id];
+    let current = file_data.associated_values.iter().find_map(|global| {
+        let link_info = &linker.globals[*global];
+        link_info
+            .span
+            .contains_pos(position)
+            .then_some((*global, link_info))
+    });
+    let current_module = current.and_then(|(global, _)| match global {
+        GlobalUUID::Module(md_id) => Some(&linker.modules[md_id]),
+        _ => None,
+    });
+    let current_link_info = current.map(|(_, link_info)| link_info);
+
+    match completion_context(&file_data.tree, &file_data.file_text.file_text, position) {
+        CompletionContext::Member { of } => current_module
+            .map(|md| member_items(linker, md, of))
+            .unwrap_or_default(),
+        CompletionContext::TemplateArg { of } => template_arg_items(linker, of),
+        CompletionContext::Type => type_items(linker, current_link_info),
+        CompletionContext::Anywhere => all_items(linker, current_link_info),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::compile_text;
+
+    /// The context at the `|` in `text`
+    fn context_at(text: &str) -> CompletionContext<'_> {
+        let position = text.find('|').unwrap();
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_sus::language()).unwrap();
+        // Same length, so spans into the parsed text are valid in `text`
+        let tree = parser.parse(text.replace('|', " "), None).unwrap();
+        completion_context(&tree, text, position)
+    }
+
+    fn in_module(body: &str) -> String {
+        format!("module m {{\n    FIFO f\n{body}\n    bool done\n}}\n")
+    }
+
+    #[test]
+    fn test_completion_context() {
+        use CompletionContext::*;
+        let cases = [
+            ("    f.|", Member { of: "f" }),
+            ("    x = f.pu|", Member { of: "f" }),
+            ("    FIFO #(|) g", TemplateArg { of: "FIFO" }),
+            ("    FIFO #(DEPTH: 3, T|) g", TemplateArg { of: "FIFO" }),
+            ("    FIFO #(DEPTH: f(3, |)) g", Anywhere),
+            ("    FIFO #(T: type |) g", Type),
+            ("    input bo|", Type),
+            ("    state |", Type),
+            ("    interface x : bool a, |", Type),
+            ("    action x : bool a -> |", Type),
+            ("    local trigger x : |", Type),
+            ("    interface x :\n        bool a,\n        |", Type),
+            ("    x = a + |", Anywhere),
+            ("    x = f(a, |)", Anywhere),
+            // Brackets and keywords in comments don't count
+            ("    // FIFO #(\n    x = |", Anywhere),
+            ("    x = a // input |", Anywhere),
+        ];
+        for (body, expected) in cases {
+            let text = in_module(body);
+            assert_eq!(context_at(&text), expected, "{body}");
+        }
+    }
+
+    const MODULES: &str = "
+module inv {
+    interface inv : bool a -> bool b
+    output bool flipped
+    b = !a
+    flipped = b
+}
+module outer #(T, int N) {
+    input T x
+    inv i
+    bool y = i.inv(true)
+}
+module user {
+    outer #(T: type bool, N: 3) o
+}
+";
+
+    fn labels_at(after: &str) -> Vec<String> {
+        let (linker, file) = compile_text(MODULES);
+        let position = MODULES.find(after).unwrap() + after.len();
+        gather_completions(&linker, file, position)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn test_member_completions() {
+        assert_eq!(labels_at("i."), ["inv", "a", "b", "flipped"]);
+    }
+
+    #[test]
+    fn test_template_arg_completions() {
+        let (linker, file) = compile_text(MODULES);
+        let position = MODULES.find("outer #(").unwrap() + "outer #(".len();
+        let items = gather_completions(&linker, file, position);
+        let snippets: Vec<_> = items
+            .iter()
+            .map(|item| (item.label.as_str(), item.insert_text.as_deref().unwrap()))
+            .collect();
+        assert_eq!(snippets, [("T", "T: type $0"), ("N", "N: $0")]);
+    }
+
+    #[test]
+    fn test_type_completions() {
+        let labels = labels_at("input ");
+        assert!(labels.contains(&"bool".to_string()));
+        // Type parameters of the current module
+        assert!(labels.contains(&"T".to_string()));
+        assert!(!labels.contains(&"inv".to_string()));
+    }
+
+    #[test]
+    fn test_anywhere_completions() {
+        let labels = labels_at("bool y = ");
+        // Globals, and the declarations and submodules of the current module
+        for expected in ["inv", "outer", "CrossDomain", "x", "i", "y"] {
+            assert!(labels.contains(&expected.to_string()), "{expected}");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use lsp_types::{FormattingOptions, TextEdit};
+
+use crate::dev_aid::format::format_edits;
+use crate::prelude::*;
+
+use super::span_to_lsp_range;
+
+/// Formats the whole file, or only the whitespace that touches `range`. Files with syntax errors are left alone
+pub fn make_formatting_edits(
+    linker: &Linker,
+    file_id: FileUUID,
+    range: Option<std::ops::Range<usize>>,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let file_data = &linker.files[file_id];
+    let indent = if options.insert_spaces {
+        " ".repeat(options.tab_size as usize)
+    } else {
+        "\t".to_string()
+    };
+    let edits = format_edits(&file_data.file_text.file_text, &file_data.tree, &indent).ok()?;
+    Some(
+        edits
+            .into_iter()
+            .filter(|edit| {
+                range.as_ref().is_none_or(|range| {
+                    let span = edit.position.as_range();
+                    span.start <= range.end && span.end >= range.start
+                })
+            })
+            .map(|edit| TextEdit {
+                range: span_to_lsp_range(&file_data.file_text, edit.position),
+                new_text: edit.replace_with,
+            })
+            .collect(),
+    )
+}
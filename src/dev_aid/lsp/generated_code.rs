@@ -0,0 +1,177 @@
+use lsp_types::{Range, TextDocumentPositionParams, request::Request};
+use serde::{Deserialize, Serialize};
+
+use crate::codegen::system_verilog::gen_verilog_code_with_source_map;
+use crate::file_position::FileText;
+use crate::linker::GlobalUUID;
+use crate::prelude::*;
+
+use super::instance_selection::{InstanceSelection, find_instance_name, instance_names};
+use super::span_to_lsp_range;
+
+/// Custom request for the SystemVerilog generated for the module the cursor is in
+pub enum GeneratedCodeRequest {}
+
+impl Request for GeneratedCodeRequest {
+    type Params = GeneratedCodeParams;
+    type Result = Option<GeneratedCodeResult>;
+    const METHOD: &'static str = "sus/generatedCode";
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedCodeParams {
+    #[serde(flatten)]
+    pub text_document_position_params: TextDocumentPositionParams,
+    /// Name or index of one of [GeneratedCodeResult::instances].
+    /// Defaults to the instance picked with [super::instance_selection::SELECT_INSTANCE_COMMAND]
+    #[serde(default)]
+    pub instance: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedCodeResult {
+    pub module: String,
+    pub instance: String,
+    /// All instances of the module, for the client to pick from
+    pub instances: Vec<String>,
+    /// Empty if the instance has errors
+    pub code: String,
+    pub source_map: Vec<CodeMapping>,
+}
+
+/// Connects source code to the lines of [GeneratedCodeResult::code] it produced.
+/// Mappings can be nested, the innermost one is the most precise
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeMapping {
+    /// In the document of the request
+    pub source: Range,
+    pub generated: Range,
+}
+
+pub fn generated_code(
+    linker: &Linker,
+    file_id: FileUUID,
+    position: usize,
+    instance: Option<&serde_json::Value>,
+    selection: &InstanceSelection,
+) -> Result<Option<GeneratedCodeResult>, String> {
+    let file_data = &linker.files[file_id];
+    let Some(md_id) = file_data
+        .associated_values
+        .iter()
+        .find_map(|global| match *global {
+            GlobalUUID::Module(md_id)
+                if linker.modules[md_id].link_info.span.contains_pos(position) =>
+            {
+                Some(md_id)
+            }
+            _ => None,
+        })
+    else {
+        return Ok(None);
+    };
+    let md = &linker.modules[md_id];
+
+    let inst = match instance {
+        Some(instance) => {
+            let name = find_instance_name(linker, md_id, instance)?;
+            linker
+                .instantiator
+                .borrow()
+                .iter_for_module(md_id)
+                .find(|(_, inst)| inst.name == name)
+                .map(|(_, inst)| inst.clone())
+        }
+        None => selection.get(linker, md_id),
+    };
+    let Some(inst) = inst else {
+        return Err(format!("'{}' has not been instantiated", md.link_info.name));
+    };
+
+    let mut result = GeneratedCodeResult {
+        module: md.link_info.name.clone(),
+        instance: inst.name.clone(),
+        instances: instance_names(linker, md_id),
+        code: String::new(),
+        source_map: Vec::new(),
+    };
+    if inst.errors.did_error {
+        return Ok(Some(result));
+    }
+    let generated = gen_verilog_code_with_source_map(&inst, linker);
+    let generated_text = FileText::new(generated.code);
+    result.source_map = generated
+        .source_map
+        .into_iter()
+        .map(|mapping| CodeMapping {
+            source: span_to_lsp_range(&file_data.file_text, mapping.span),
+            generated: span_to_lsp_range(&generated_text, mapping.generated.into()),
+        })
+        .collect();
+    result.code = generated_text.file_text;
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text};
+
+    #[test]
+    fn test_source_lines_map_to_verilog_lines() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let position = TWO_DELAYS.find("d1.a = x").unwrap();
+        let result = generated_code(&linker, file, position, None, &InstanceSelection::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (result.module.as_str(), result.instance.as_str()),
+            ("top", "top #()")
+        );
+
+        // The first Verilog line of each part generated for `source_line`
+        let code_lines: Vec<&str> = result.code.lines().collect();
+        let verilog_lines = |source_line: u32| -> Vec<&str> {
+            result
+                .source_map
+                .iter()
+                .filter(|m| m.source.start.line == source_line)
+                .map(|m| code_lines[m.generated.start.line as usize])
+                .collect()
+        };
+        // `d1.a = x`
+        assert!(verilog_lines(10).contains(&"\t_d1_a = x;"));
+        // `delay #(T: type bool[2]) d2` produces the wires of its ports, the instance, and the block that drives its input
+        assert_eq!(
+            verilog_lines(14),
+            [
+                "/*mux_wire*/ logic[1:0] _d2_a;",
+                "wire[1:0] _d2_b;",
+                "delay_T_type_bool_2 d2(",
+                "always_comb begin",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_instance_can_be_picked() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let position = TWO_DELAYS.find("reg b").unwrap();
+        let pick = serde_json::json!(1);
+        let result = generated_code(
+            &linker,
+            file,
+            position,
+            Some(&pick),
+            &InstanceSelection::default(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(result.instances.len(), 2);
+        assert_eq!(result.instance, result.instances[1]);
+        assert!(result.code.contains("input wire[1:0] a"));
+    }
+}
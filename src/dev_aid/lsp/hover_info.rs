@@ -72,16 +72,33 @@ impl HoverCollector<'_> {
 
     fn gather_submodule_hover_infos(&mut self, md_id: ModuleUUID, submodule_instr: FlatID) {
         for (_template_args, inst) in self.linker.instantiator.borrow().iter_for_module(md_id) {
-            for (_id, sm) in &inst.submodules {
+            for (sm_id, sm) in &inst.submodules {
                 if sm.original_instruction == submodule_instr {
                     self.sus_code(sm.display_interface(self.linker).to_string());
                     self.monospace(
                         display_all_infer_params(self.linker, &inst.submodules, sm).to_string(),
                     );
+                    let sm_md = &self.linker.modules[sm.refers_to.id];
+                    for (interface_id, interface) in &sm_md.interfaces {
+                        if let Some(ii) =
+                            inst.submodule_interface_initiation_interval(sm_md, sm_id, interface_id)
+                        {
+                            self.monospace(format!("{}: initiation interval {ii}", interface.name));
+                        }
+                    }
                 }
             }
         }
     }
+
+    fn gather_interface_initiation_intervals(&mut self, md_id: ModuleUUID, interface: InterfaceID) {
+        let md = &self.linker.modules[md_id];
+        for (_template_args, inst) in self.linker.instantiator.borrow().iter_for_module(md_id) {
+            if let Some(ii) = inst.interface_initiation_interval(md, interface) {
+                self.monospace(format!("{}: initiation interval {ii}", inst.name));
+            }
+        }
+    }
 }
 
 pub fn hover(info: LocationInfo, linker: &Linker, file_data: &FileData) -> Vec<MarkedString> {
@@ -141,6 +158,7 @@ pub fn hover(info: LocationInfo, linker: &Linker, file_data: &FileData) -> Vec<M
                     md.display_interface_info(interface, &file_data.file_text, true)
                         .to_string(),
                 );
+                hover.gather_interface_initiation_intervals(md_id, interface.interface_id);
             }
 
             hover.gather_hover_infos(obj_id, decl_id, false);
@@ -0,0 +1,194 @@
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintTooltip};
+
+use crate::flattening::Instruction;
+use crate::instantiation::InstantiatedModule;
+use crate::linker::{FileData, GlobalUUID};
+use crate::prelude::*;
+
+use super::byte_to_position;
+use super::instance_selection::InstanceSelection;
+
+fn make_hint(
+    file_data: &FileData,
+    after: Span,
+    label: String,
+    kind: Option<InlayHintKind>,
+    inst: &InstantiatedModule,
+) -> InlayHint {
+    InlayHint {
+        position: byte_to_position(&file_data.file_text, after.as_range().end),
+        label: InlayHintLabel::String(label),
+        kind,
+        text_edits: None,
+        tooltip: Some(InlayHintTooltip::String(format!("From {}", inst.name))),
+        padding_left: Some(kind == Some(InlayHintKind::TYPE)),
+        padding_right: None,
+        data: None,
+    }
+}
+
+/// Distinct values joined with `|`. Declarations inside generative `for` loops produce many wires
+fn join_distinct(values: Vec<String>) -> Option<String> {
+    let mut distinct: Vec<String> = Vec::new();
+    for v in values {
+        if !distinct.contains(&v) {
+            distinct.push(v);
+        }
+    }
+    if distinct.is_empty() {
+        None
+    } else {
+        Some(distinct.join(" | "))
+    }
+}
+
+fn gather_hints_for_instance(
+    file_data: &FileData,
+    linker: &Linker,
+    md_id: ModuleUUID,
+    inst: &InstantiatedModule,
+    visible: std::ops::Range<usize>,
+    hints: &mut Vec<InlayHint>,
+) {
+    let md = &linker.modules[md_id];
+    let in_view = |span: Span| {
+        let r = span.as_range();
+        r.end >= visible.start && r.start <= visible.end
+    };
+    for (id, instr) in &md.link_info.instructions {
+        match instr {
+            Instruction::Declaration(decl)
+                if !decl.decl_kind.is_generative() && in_view(decl.name_span) =>
+            {
+                let wires: Vec<_> = inst
+                    .wires
+                    .iter()
+                    .filter(|(_, w)| w.original_instruction == id)
+                    .map(|(_, w)| w)
+                    .collect();
+                if decl.latency_specifier.is_none()
+                    && let Some(latencies) = join_distinct(
+                        wires
+                            .iter()
+                            .map(|w| format!("'{}", w.absolute_latency))
+                            .collect(),
+                    )
+                {
+                    hints.push(make_hint(file_data, decl.name_span, latencies, None, inst));
+                }
+                if let Some(types) = join_distinct(
+                    wires
+                        .iter()
+                        .map(|w| w.typ.display(linker).to_string())
+                        .collect(),
+                ) {
+                    hints.push(make_hint(
+                        file_data,
+                        decl.name_span,
+                        format!(": {types}"),
+                        Some(InlayHintKind::TYPE),
+                        inst,
+                    ));
+                }
+            }
+            Instruction::SubModule(sm_instr) if in_view(sm_instr.module_ref.get_total_span()) => {
+                let args = join_distinct(
+                    inst.submodules
+                        .iter()
+                        .filter(|(_, sm)| {
+                            sm.original_instruction == id && !sm.refers_to.template_args.is_empty()
+                        })
+                        .map(|(_, sm)| sm.refers_to.display_template_args(linker).to_string())
+                        .collect(),
+                );
+                if let Some(args) = args {
+                    hints.push(make_hint(
+                        file_data,
+                        sm_instr.module_ref.get_total_span(),
+                        args,
+                        Some(InlayHintKind::PARAMETER),
+                        inst,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Latencies and concrete types of declarations, and the template arguments of submodules. Taken from the selected instance of each module
+pub fn make_inlay_hints(
+    linker: &Linker,
+    file_id: FileUUID,
+    visible: std::ops::Range<usize>,
+    selection: &InstanceSelection,
+) -> Vec<InlayHint> {
+    let file_data = &linker.files[file_id];
+    let mut hints = Vec::new();
+    for global in &file_data.associated_values {
+        let GlobalUUID::Module(md_id) = *global else {
+            continue;
+        };
+        if let Some(inst) = selection.get(linker, md_id) {
+            gather_hints_for_instance(file_data, linker, md_id, &inst, visible.clone(), &mut hints);
+        }
+    }
+    hints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text};
+
+    fn hints_on_line(hints: &[InlayHint], line: u32) -> Vec<String> {
+        hints
+            .iter()
+            .filter(|h| h.position.line == line)
+            .map(|h| match &h.label {
+                InlayHintLabel::String(label) => label.clone(),
+                InlayHintLabel::LabelParts(_) => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_latency_type_and_template_hints() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let hints = make_inlay_hints(&linker, file, 0..usize::MAX, &InstanceSelection::default());
+        // `input T a'0` has an explicit latency, so only its type is shown
+        assert_eq!(hints_on_line(&hints, 2), [": bool #()"]);
+        assert_eq!(hints_on_line(&hints, 3), ["'1", ": bool #()"]);
+        assert_eq!(hints_on_line(&hints, 9), ["#(T: type bool #())"]);
+        assert_eq!(hints_on_line(&hints, 14), ["#(T: type bool #()[2])"]);
+    }
+
+    #[test]
+    fn test_hints_follow_selected_instance() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let mut selection = InstanceSelection::default();
+        selection
+            .select(
+                &linker,
+                "delay",
+                &serde_json::json!("delay #(T: type bool #()[2])"),
+            )
+            .unwrap();
+        let hints = make_inlay_hints(&linker, file, 0..usize::MAX, &selection);
+        assert_eq!(hints_on_line(&hints, 3), ["'1", ": bool #()[2]"]);
+    }
+
+    #[test]
+    fn test_only_visible_range_gets_hints() {
+        let (linker, file) = compile_text(TWO_DELAYS);
+        let top_start = TWO_DELAYS.find("module top").unwrap();
+        let hints = make_inlay_hints(
+            &linker,
+            file,
+            top_start..usize::MAX,
+            &InstanceSelection::default(),
+        );
+        assert!(hints.iter().all(|h| h.position.line >= 6));
+        assert!(!hints.is_empty());
+    }
+}
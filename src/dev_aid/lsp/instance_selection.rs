@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::instantiation::InstantiatedModule;
+use crate::prelude::*;
+
+/// Command to pick the instance that instance-specific LSP features like inlay hints show.
+///
+/// Arguments: `[module_name, instance]`, where `instance` is either the full instance name as it appears in the log (`FIFO #(T: type int #(FROM: 0, TO: 7), DEPTH: 3)`), or its index.
+pub const SELECT_INSTANCE_COMMAND: &str = "sus.selectInstance";
+/// Command that lists all instance names of a module. Arguments: `[module_name]`
+pub const LIST_INSTANCES_COMMAND: &str = "sus.listInstances";
+
+/// The instance the user picked per module. Modules without a selection use their first instance.
+///
+/// Keyed by name rather than [ModuleUUID], because ids don't survive recompilation
+#[derive(Debug, Default)]
+pub struct InstanceSelection {
+    selected: HashMap<String, String>,
+}
+
+impl InstanceSelection {
+    pub fn get(&self, linker: &Linker, md_id: ModuleUUID) -> Option<Rc<InstantiatedModule>> {
+        let selected_name = self.selected.get(&linker.modules[md_id].link_info.name);
+        let instantiator = linker.instantiator.borrow();
+        let mut first = None;
+        for (_, inst) in instantiator.iter_for_module(md_id) {
+            if selected_name == Some(&inst.name) {
+                return Some(inst.clone());
+            }
+            first.get_or_insert(inst);
+        }
+        first.cloned()
+    }
+
+    /// Returns the name of the newly selected instance, or an error message for the user
+    pub fn select(
+        &mut self,
+        linker: &Linker,
+        md_name: &str,
+        instance: &serde_json::Value,
+    ) -> Result<String, String> {
+        let Some(md_id) = linker.modules.find(|_, md| md.link_info.name == md_name) else {
+            return Err(format!("No module named '{md_name}'"));
+        };
+        let name = find_instance_name(linker, md_id, instance)?;
+        self.selected.insert(md_name.to_string(), name.clone());
+        Ok(name)
+    }
+}
+
+/// `instance` is either the full instance name, or its index in [instance_names]
+pub fn find_instance_name(
+    linker: &Linker,
+    md_id: ModuleUUID,
+    instance: &serde_json::Value,
+) -> Result<String, String> {
+    let md_name = &linker.modules[md_id].link_info.name;
+    let names = instance_names(linker, md_id);
+    match instance {
+        serde_json::Value::Number(idx) => idx
+            .as_u64()
+            .and_then(|idx| names.get(idx as usize))
+            .cloned()
+            .ok_or_else(|| format!("'{md_name}' has only {} instances", names.len())),
+        serde_json::Value::String(name) if names.contains(name) => Ok(name.clone()),
+        other => Err(format!("'{md_name}' has no instance {other}")),
+    }
+}
+
+pub fn instance_names(linker: &Linker, md_id: ModuleUUID) -> Vec<String> {
+    linker
+        .instantiator
+        .borrow()
+        .iter_for_module(md_id)
+        .map(|(_, inst)| inst.name.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text};
+    use serde_json::json;
+
+    #[test]
+    fn test_select_by_name_or_index() {
+        let (linker, _) = compile_text(TWO_DELAYS);
+        let md_id = linker
+            .modules
+            .find(|_, md| md.link_info.name == "delay")
+            .unwrap();
+        let names = instance_names(&linker, md_id);
+        assert_eq!(names.len(), 2);
+
+        let mut selection = InstanceSelection::default();
+        // Without a selection, the first instance is used
+        assert_eq!(selection.get(&linker, md_id).unwrap().name, names[0]);
+
+        assert_eq!(
+            selection.select(&linker, "delay", &json!(1)),
+            Ok(names[1].clone())
+        );
+        assert_eq!(selection.get(&linker, md_id).unwrap().name, names[1]);
+
+        assert_eq!(
+            selection.select(&linker, "delay", &json!(names[0])),
+            Ok(names[0].clone())
+        );
+        assert_eq!(selection.get(&linker, md_id).unwrap().name, names[0]);
+    }
+
+    #[test]
+    fn test_bad_selections_are_reported() {
+        let (linker, _) = compile_text(TWO_DELAYS);
+        let mut selection = InstanceSelection::default();
+        assert!(
+            selection
+                .select(&linker, "no_such_module", &json!(0))
+                .is_err()
+        );
+        assert!(selection.select(&linker, "delay", &json!(2)).is_err());
+        assert!(
+            selection
+                .select(&linker, "delay", &json!("delay #()"))
+                .is_err()
+        );
+    }
+}
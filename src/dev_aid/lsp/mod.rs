@@ -1,5 +1,15 @@
+mod call_hierarchy;
+mod code_actions;
+mod code_lens;
+mod completion;
+mod formatting;
+mod generated_code;
 mod hover_info;
+mod inlay_hints;
+mod instance_selection;
 mod semantic_tokens;
+mod signature_help;
+mod symbols;
 mod tree_walk;
 
 use crate::{
@@ -12,16 +22,33 @@ use crate::{
     util::contains_duplicates,
 };
 
+use call_hierarchy::{incoming_calls, outgoing_calls, prepare_call_hierarchy};
+use code_actions::make_code_actions;
+use code_lens::{OPEN_GENERATED_CODE_COMMAND, make_code_lenses, write_generated_code};
+use completion::gather_completions;
+use formatting::make_formatting_edits;
+use generated_code::{GeneratedCodeParams, GeneratedCodeRequest, generated_code};
 use hover_info::hover;
+use inlay_hints::make_inlay_hints;
+use instance_selection::{
+    InstanceSelection, LIST_INSTANCES_COMMAND, SELECT_INSTANCE_COMMAND, instance_names,
+};
 use lsp_types::{notification::*, request::Request, *};
 use semantic_tokens::{make_semantic_tokens, semantic_token_capabilities};
-use std::{collections::HashMap, error::Error, net::SocketAddr, path::Path};
+use signature_help::make_signature_help;
+use std::{
+    collections::HashMap,
+    error::Error,
+    net::SocketAddr,
+    path::Path,
+    sync::atomic::{AtomicI32, Ordering},
+};
+use symbols::{make_document_symbols, make_workspace_symbols};
 
 use crate::{
     config::config,
     errors::{CompileError, ErrorLevel},
     file_position::{FileText, LineCol},
-    flattening::Instruction,
     linker::FileData,
 };
 
@@ -29,23 +56,31 @@ use tree_walk::{InGlobal, LocationInfo, get_selected_object};
 
 use self::tree_walk::RefersTo;
 
+/// LSP positions count columns in UTF-16 code units, so the [LineCol] does too. Convert with [FileText::utf16_linecol_to_byte_clamp]
 fn from_position(pos: lsp_types::Position) -> LineCol {
     LineCol {
         line: pos.line as usize,
         col: pos.character as usize,
     }
 }
-fn to_position(char_line: LineCol) -> lsp_types::Position {
+fn position_to_byte(file_text: &FileText, pos: lsp_types::Position) -> usize {
+    file_text.utf16_linecol_to_byte_clamp(from_position(pos))
+}
+fn lsp_range_to_bytes(file_text: &FileText, range: lsp_types::Range) -> std::ops::Range<usize> {
+    position_to_byte(file_text, range.start)..position_to_byte(file_text, range.end)
+}
+fn byte_to_position(file_text: &FileText, byte: usize) -> lsp_types::Position {
+    let utf16_line_col = file_text.byte_to_utf16_linecol(byte);
     lsp_types::Position {
-        line: char_line.line as u32,
-        character: char_line.col as u32,
+        line: utf16_line_col.line as u32,
+        character: utf16_line_col.col as u32,
     }
 }
 fn span_to_lsp_range(file_text: &FileText, ch_sp: Span) -> lsp_types::Range {
-    let rng = file_text.get_span_linecol_range(ch_sp);
+    let rng = ch_sp.as_range();
     Range {
-        start: to_position(rng.start),
-        end: to_position(rng.end),
+        start: byte_to_position(file_text, rng.start),
+        end: byte_to_position(file_text, rng.end),
     }
 }
 fn cvt_location_list(location_vec: Vec<SpanFile>, linker: &Linker) -> Vec<Location> {
@@ -86,10 +121,37 @@ impl Linker {
     fn find_uri(&self, uri: &Url) -> Option<FileUUID> {
         self.find_file(uri.as_str())
     }
-    fn update_text(&mut self, uri: &Url, new_file_text: String, manager: &mut LSPFileManager) {
-        self.add_or_update_file(uri.as_str(), new_file_text, manager);
-
-        self.recompile_all_report_panics();
+    /// Incremental changes are applied to the existing text, a change without a range replaces the whole file
+    fn update_text(
+        &mut self,
+        uri: &Url,
+        mut changes: Vec<TextDocumentContentChangeEvent>,
+        manager: &mut LSPFileManager,
+    ) {
+        // Anything before the last full replacement doesn't matter
+        if let Some(last_full) = changes.iter().rposition(|change| change.range.is_none()) {
+            let edits = changes.split_off(last_full + 1);
+            let full_text = changes.pop().unwrap().text;
+            let to_recompile = self.add_or_update_file(uri.as_str(), full_text, manager);
+            self.recompile_incremental_report_panics(&to_recompile);
+            changes = edits;
+        }
+        if changes.is_empty() {
+            return;
+        }
+        let Some(file_id) = self.find_uri(uri) else {
+            warn!("Incremental change to unknown file {uri}, ignoring");
+            return;
+        };
+        let edits = changes.into_iter().map(|change| {
+            let range = change.range.unwrap();
+            (
+                from_position(range.start)..from_position(range.end),
+                change.text,
+            )
+        });
+        let to_recompile = self.edit_file(file_id, edits, manager);
+        self.recompile_incremental_report_panics(&to_recompile);
     }
     fn ensure_contains_file(&mut self, uri: &Url, manager: &mut LSPFileManager) -> FileUUID {
         if let Some(found) = self.find_uri(uri) {
@@ -97,9 +159,9 @@ impl Linker {
         } else {
             let file_text = std::fs::read_to_string(uri.to_file_path().unwrap()).unwrap();
 
-            let file_uuid = self.add_file_text(uri.to_string(), file_text, manager);
-            self.recompile_all_report_panics();
-            file_uuid
+            let to_recompile = self.add_or_update_file(uri.as_str(), file_text, manager);
+            self.recompile_incremental_report_panics(&to_recompile);
+            self.find_uri(uri).unwrap()
         }
     }
     fn location_in_file(
@@ -110,9 +172,7 @@ impl Linker {
         let file_id = self.ensure_contains_file(&text_pos.text_document.uri, manager);
         let file_data = &self.files[file_id];
 
-        let position = file_data
-            .file_text
-            .linecol_to_byte_clamp(from_position(text_pos.position));
+        let position = position_to_byte(&file_data.file_text, text_pos.position);
 
         (file_id, position)
     }
@@ -156,7 +216,7 @@ fn convert_diagnostic(err: CompileError, main_file_text: &FileText, linker: &Lin
     Diagnostic::new(
         error_pos,
         Some(severity),
-        None,
+        Some(NumberOrString::String(err.code.to_string())),
         None,
         err.reason,
         Some(related_info),
@@ -170,7 +230,7 @@ fn push_all_errors(
 ) -> Result<(), Box<dyn Error + Sync + Send>> {
     let errs = linker.collect_all_errors();
 
-    for (_file_id, file_data, errs_for_file) in zip_eq(linker.files.iter(), errs.into_iter()) {
+    for (_file_id, file_data, errs_for_file) in zip_eq(linker.files.iter(), errs) {
         let diag_vec: Vec<Diagnostic> = errs_for_file
             .into_iter()
             .map(|e| convert_diagnostic(e, &file_data.file_text, linker))
@@ -193,7 +253,7 @@ fn push_all_errors(
     Ok(())
 }
 
-struct LSPFileManager {}
+pub(crate) struct LSPFileManager {}
 
 impl LinkerExtraFileInfoManager for LSPFileManager {
     fn convert_filename(&self, path: &Path) -> String {
@@ -233,46 +293,6 @@ fn initialize_all_files(init_params: &InitializeParams) -> (Linker, LSPFileManag
     (linker, manager)
 }
 
-fn gather_completions(linker: &Linker, file_id: FileUUID, position: usize) -> Vec<CompletionItem> {
-    let mut result = Vec::new();
-
-    for (_, m) in &linker.modules {
-        result.push(CompletionItem {
-            label: m.link_info.name.to_string(),
-            kind: Some(CompletionItemKind::FUNCTION),
-            ..Default::default()
-        });
-
-        if m.link_info.file == file_id && m.link_info.span.contains_pos(position) {
-            for (_id, v) in &m.link_info.instructions {
-                if let Instruction::Declaration(d) = v {
-                    result.push(CompletionItem {
-                        label: d.name.to_string(),
-                        kind: Some(CompletionItemKind::VARIABLE),
-                        ..Default::default()
-                    });
-                }
-            }
-        }
-    }
-    for (_, c) in &linker.constants {
-        result.push(CompletionItem {
-            label: c.link_info.name.to_string(),
-            kind: Some(CompletionItemKind::CONSTANT),
-            ..Default::default()
-        });
-    }
-    for (_, t) in &linker.types {
-        result.push(CompletionItem {
-            label: t.link_info.name.to_string(),
-            kind: Some(CompletionItemKind::STRUCT),
-            ..Default::default()
-        });
-    }
-
-    result
-}
-
 fn gather_references_in_file(
     linker: &Linker,
     file_data: &FileData,
@@ -398,11 +418,37 @@ fn goto_definition(linker: &mut Linker, file_uuid: FileUUID, pos: usize) -> Vec<
     goto_definition_list
 }
 
+/// Ids of the requests the server sends to the client. Every request needs its own
+static NEXT_SERVER_REQUEST_ID: AtomicI32 = AtomicI32::new(0);
+
+/// Asks the client to open `uri`. The response is ignored in [main_loop]
+fn show_document(connection: &lsp_server::Connection, uri: Url) {
+    let params = ShowDocumentParams {
+        uri: uri.clone(),
+        external: Some(false),
+        take_focus: Some(true),
+        selection: None,
+    };
+    let request = lsp_server::Request {
+        id: lsp_server::RequestId::from(NEXT_SERVER_REQUEST_ID.fetch_add(1, Ordering::Relaxed)),
+        method: request::ShowDocument::METHOD.to_owned(),
+        params: serde_json::to_value(params).unwrap(),
+    };
+    if let Err(e) = connection
+        .sender
+        .send(lsp_server::Message::Request(request))
+    {
+        warn!("Could not send {}: {e}", request::ShowDocument::METHOD);
+    }
+}
+
 fn handle_request(
+    connection: &lsp_server::Connection,
     method: &str,
     params: serde_json::Value,
     linker: &mut Linker,
     manager: &mut LSPFileManager,
+    selection: &mut InstanceSelection,
 ) -> Result<serde_json::Value, serde_json::Error> {
     match method {
         request::HoverRequest::METHOD => {
@@ -534,6 +580,183 @@ fn handle_request(
                 linker, file_uuid, position,
             )))
         }
+        request::SignatureHelpRequest::METHOD => {
+            let params: SignatureHelpParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("SignatureHelp");
+
+            let (file_uuid, position) =
+                linker.location_in_file(&params.text_document_position_params, manager);
+
+            serde_json::to_value(make_signature_help(linker, file_uuid, position))
+        }
+        request::CallHierarchyPrepare::METHOD => {
+            let params: CallHierarchyPrepareParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("CallHierarchyPrepare");
+
+            let (file_uuid, position) =
+                linker.location_in_file(&params.text_document_position_params, manager);
+
+            serde_json::to_value(prepare_call_hierarchy(linker, file_uuid, position))
+        }
+        request::CallHierarchyIncomingCalls::METHOD => {
+            let params: CallHierarchyIncomingCallsParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("CallHierarchyIncomingCalls {}", params.item.name);
+
+            serde_json::to_value(incoming_calls(linker, &params.item))
+        }
+        request::CallHierarchyOutgoingCalls::METHOD => {
+            let params: CallHierarchyOutgoingCallsParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("CallHierarchyOutgoingCalls {}", params.item.name);
+
+            serde_json::to_value(outgoing_calls(linker, &params.item))
+        }
+        request::DocumentSymbolRequest::METHOD => {
+            let params: DocumentSymbolParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("DocumentSymbol");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+
+            serde_json::to_value(DocumentSymbolResponse::Nested(make_document_symbols(
+                linker, file_id,
+            )))
+        }
+        request::WorkspaceSymbolRequest::METHOD => {
+            let params: WorkspaceSymbolParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("WorkspaceSymbol {}", params.query);
+
+            serde_json::to_value(WorkspaceSymbolResponse::Nested(make_workspace_symbols(
+                linker,
+                &params.query,
+            )))
+        }
+        request::InlayHintRequest::METHOD => {
+            let params: InlayHintParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("InlayHint");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+            let file_text = &linker.files[file_id].file_text;
+            let visible = lsp_range_to_bytes(file_text, params.range);
+
+            serde_json::to_value(make_inlay_hints(linker, file_id, visible, selection))
+        }
+        request::CodeActionRequest::METHOD => {
+            let params: CodeActionParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("CodeAction");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+            let file_text = &linker.files[file_id].file_text;
+            let range = lsp_range_to_bytes(file_text, params.range);
+
+            serde_json::to_value(make_code_actions(linker, file_id, range))
+        }
+        request::Formatting::METHOD => {
+            let params: DocumentFormattingParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("Formatting");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+
+            serde_json::to_value(make_formatting_edits(
+                linker,
+                file_id,
+                None,
+                &params.options,
+            ))
+        }
+        request::RangeFormatting::METHOD => {
+            let params: DocumentRangeFormattingParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("RangeFormatting");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+            let file_text = &linker.files[file_id].file_text;
+            let range = lsp_range_to_bytes(file_text, params.range);
+
+            serde_json::to_value(make_formatting_edits(
+                linker,
+                file_id,
+                Some(range),
+                &params.options,
+            ))
+        }
+        request::CodeLensRequest::METHOD => {
+            let params: CodeLensParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("CodeLens");
+
+            let file_id = linker.ensure_contains_file(&params.text_document.uri, manager);
+
+            serde_json::to_value(make_code_lenses(linker, file_id, selection))
+        }
+        GeneratedCodeRequest::METHOD => {
+            let params: GeneratedCodeParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("GeneratedCode");
+
+            let (file_id, pos) =
+                linker.location_in_file(&params.text_document_position_params, manager);
+
+            match generated_code(linker, file_id, pos, params.instance.as_ref(), selection) {
+                Ok(result) => serde_json::to_value(result),
+                Err(e) => {
+                    warn!("{}: {e}", GeneratedCodeRequest::METHOD);
+                    Ok(serde_json::Value::Null)
+                }
+            }
+        }
+        request::ExecuteCommand::METHOD => {
+            let params: ExecuteCommandParams =
+                serde_json::from_value(params).expect("JSON Encoding Error while parsing params");
+            info!("ExecuteCommand {}", params.command);
+
+            let md_name = params.arguments.first().and_then(|a| a.as_str());
+            match (params.command.as_str(), md_name) {
+                (SELECT_INSTANCE_COMMAND, Some(md_name)) => {
+                    let instance = params.arguments.get(1).unwrap_or(&serde_json::Value::Null);
+                    match selection.select(linker, md_name, instance) {
+                        Ok(selected) => serde_json::to_value(selected),
+                        Err(e) => {
+                            warn!("{SELECT_INSTANCE_COMMAND}: {e}");
+                            Ok(serde_json::Value::Null)
+                        }
+                    }
+                }
+                (LIST_INSTANCES_COMMAND, Some(md_name)) => {
+                    let names = linker
+                        .modules
+                        .find(|_, md| md.link_info.name == md_name)
+                        .map(|md_id| instance_names(linker, md_id))
+                        .unwrap_or_default();
+                    serde_json::to_value(names)
+                }
+                (OPEN_GENERATED_CODE_COMMAND, Some(md_name)) => {
+                    let instance_name = params
+                        .arguments
+                        .get(1)
+                        .and_then(|a| a.as_str())
+                        .unwrap_or_default();
+                    match write_generated_code(linker, md_name, instance_name) {
+                        Ok(path) => {
+                            show_document(connection, Url::from_file_path(path).unwrap());
+                        }
+                        Err(e) => warn!("{OPEN_GENERATED_CODE_COMMAND}: {e}"),
+                    }
+                    Ok(serde_json::Value::Null)
+                }
+                (command, _) => {
+                    warn!("Unknown command or missing module name: {command}");
+                    Ok(serde_json::Value::Null)
+                }
+            }
+        }
         req => {
             info!("Other request: {req:?}");
             Ok(serde_json::Value::Null)
@@ -554,11 +777,7 @@ fn handle_notification(
             let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)
                 .expect("JSON Encoding Error while parsing params");
 
-            let mut content_change_iter = params.content_changes.into_iter();
-            let only_change = content_change_iter.next().unwrap();
-            assert!(content_change_iter.next().is_none());
-            assert!(only_change.range.is_none());
-            linker.update_text(&params.text_document.uri, only_change.text, manager);
+            linker.update_text(&params.text_document.uri, params.content_changes, manager);
 
             push_all_errors(connection, linker)?;
         }
@@ -585,6 +804,7 @@ fn main_loop(
     let initialize_params: InitializeParams = serde_json::from_value(initialize_params).unwrap();
 
     let (mut linker, mut manager) = initialize_all_files(&initialize_params);
+    let mut selection = InstanceSelection::default();
 
     push_all_errors(&connection, &linker)?;
 
@@ -597,8 +817,14 @@ fn main_loop(
                     return Ok(());
                 }
 
-                let response_value =
-                    handle_request(&req.method, req.params, &mut linker, &mut manager);
+                let response_value = handle_request(
+                    &connection,
+                    &req.method,
+                    req.params,
+                    &mut linker,
+                    &mut manager,
+                    &mut selection,
+                );
 
                 let result = response_value.unwrap();
                 let response = lsp_server::Response {
@@ -671,12 +897,45 @@ pub fn lsp_main() -> Result<(), Box<dyn Error + Sync + Send>> {
         references_provider: Some(OneOf::Left(true)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
         rename_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        inlay_hint_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        document_range_formatting_provider: Some(OneOf::Left(true)),
+        call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![
+                CodeActionKind::QUICKFIX,
+                CodeActionKind::SOURCE_FIX_ALL,
+            ]),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+            resolve_provider: None,
+        })),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![
+                SELECT_INSTANCE_COMMAND.to_string(),
+                LIST_INSTANCES_COMMAND.to_string(),
+                OPEN_GENERATED_CODE_COMMAND.to_string(),
+            ],
+            ..Default::default()
+        }),
         semantic_tokens_provider: Some(semantic_token_capabilities()),
         completion_provider: Some(CompletionOptions {
             resolve_provider: Some(true),
+            trigger_characters: Some(vec![".".to_string(), "(".to_string()]),
             ..Default::default()
         }),
-        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        }),
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
         ..Default::default()
     })
     .unwrap();
@@ -688,3 +947,47 @@ pub fn lsp_main() -> Result<(), Box<dyn Error + Sync + Send>> {
     info!("shutting down server");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_count_utf16_code_units() {
+        let file_text = FileText::new("// 🦀 x\nmodule".to_string());
+        let x = file_text.file_text.find('x').unwrap();
+        let x_pos = Position {
+            line: 0,
+            character: 6,
+        };
+        assert_eq!(byte_to_position(&file_text, x), x_pos);
+        assert_eq!(position_to_byte(&file_text, x_pos), x);
+        let module = file_text.file_text.find("module").unwrap();
+        assert_eq!(
+            span_to_lsp_range(&file_text, Span::from(module..module + 6)),
+            Range {
+                start: Position {
+                    line: 1,
+                    character: 0
+                },
+                end: Position {
+                    line: 1,
+                    character: 6
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_show_document_requests_have_unique_ids() {
+        let (server, client) = lsp_server::Connection::memory();
+        let uri = Url::parse("file:///generated.sv").unwrap();
+        show_document(&server, uri.clone());
+        show_document(&server, uri);
+        let [first, second] = [(); 2].map(|_| match client.receiver.recv().unwrap() {
+            lsp_server::Message::Request(request) => request.id,
+            other => panic!("Expected a request, got {other:?}"),
+        });
+        assert_ne!(first, second);
+    }
+}
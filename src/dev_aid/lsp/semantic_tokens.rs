@@ -7,7 +7,7 @@ use lsp_types::{
 };
 
 use crate::{
-    dev_aid::lsp::to_position,
+    dev_aid::lsp::byte_to_position,
     linker::{FileData, GlobalUUID},
 };
 
@@ -72,7 +72,7 @@ fn convert_to_semantic_tokens(
     file_data: &FileData,
     ide_tokens: &mut [(Span, IDEIdentifierType)],
 ) -> Vec<SemanticToken> {
-    ide_tokens.sort_by(|a, b| a.0.cmp(&b.0));
+    ide_tokens.sort_by_key(|a| a.0);
 
     let mut cursor = Position {
         line: 0,
@@ -85,9 +85,8 @@ fn convert_to_semantic_tokens(
             let typ = get_semantic_token_type_from_ide_token(*ide_kind);
             let mod_bits = get_modifiers_for_token(*ide_kind);
 
-            let tok_range = file_data.file_text.get_span_linecol_range(*span);
-            let start_pos = to_position(tok_range.start);
-            let end_pos = to_position(tok_range.end);
+            let start_pos = byte_to_position(&file_data.file_text, span.as_range().start);
+            let end_pos = byte_to_position(&file_data.file_text, span.as_range().end);
 
             assert!(end_pos.line == start_pos.line);
 
@@ -0,0 +1,404 @@
+use lsp_types::{ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation};
+
+use crate::flattening::{DeclarationKind, Instruction, InterfaceDeclKind, Module};
+use crate::linker::{GlobalUUID, LinkInfo};
+use crate::prelude::*;
+use crate::typing::template::TemplateKind;
+
+use sus_proc_macro::{kind, kw};
+use tree_sitter::{Node, Tree};
+
+use super::completion::{
+    documentation, innermost_open_bracket, template_args_owner, tokens_before,
+};
+
+/// What the innermost open `(` or `#(` in front of the cursor belongs to
+#[derive(Debug, PartialEq, Eq)]
+enum Callee<'t> {
+    /// `Global #(`
+    TemplateArgs { of: &'t str },
+    /// `Module(` or `submodule(`, which call the main interface
+    Direct { name: &'t str },
+    /// `submodule.interface(`
+    Interface {
+        submodule: &'t str,
+        interface: &'t str,
+    },
+}
+
+/// What the innermost open `(` or `#(` in front of the cursor belongs to, and the tokens written since
+fn call_context<'t>(
+    tree: &'t Tree,
+    text: &'t str,
+    position: usize,
+) -> Option<(Callee<'t>, Vec<Node<'t>>)> {
+    let tokens = tokens_before(tree, position);
+    let open_idx = innermost_open_bracket(&tokens)?;
+    let open = tokens[open_idx];
+    let args = tokens[open_idx + 1..].to_vec();
+
+    if open.kind_id() == kw!("#(") {
+        let of = template_args_owner(open, &tokens, text)?;
+        return Some((Callee::TemplateArgs { of }, args));
+    }
+    if open.kind_id() != kw!("(") {
+        return None;
+    }
+    let mut callee_end = open_idx;
+    // `Module #(...)(`
+    if tokens[..callee_end].last()?.kind_id() == kw!(")") {
+        callee_end = innermost_open_bracket(&tokens[..callee_end - 1])?;
+        if tokens[callee_end].kind_id() != kw!("#(") {
+            return None;
+        }
+    }
+    let ident_text =
+        |token: &Node| (token.kind_id() == kind!("identifier")).then(|| &text[token.byte_range()]);
+    let callee = match &tokens[..callee_end] {
+        [.., submodule, dot, interface] if dot.kind_id() == kw!(".") => Callee::Interface {
+            submodule: ident_text(submodule)?,
+            interface: ident_text(interface)?,
+        },
+        [.., name] => Callee::Direct {
+            name: ident_text(name)?,
+        },
+        [] => return None,
+    };
+    Some((callee, args))
+}
+
+/// The tokens of each top-level argument in `args`, the last one being the argument the cursor is in
+fn split_args<'a, 't>(args: &'a [Node<'t>]) -> Vec<&'a [Node<'t>]> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (idx, token) in args.iter().enumerate() {
+        let kind = token.kind_id();
+        if [kw!("("), kw!("["), kw!("#("), kw!("{")].contains(&kind) {
+            depth += 1;
+        } else if [kw!(")"), kw!("]"), kw!("}")].contains(&kind) {
+            depth -= 1;
+        } else if kind == kw!(",") && depth == 0 {
+            result.push(&args[arg_start..idx]);
+            arg_start = idx + 1;
+        }
+    }
+    result.push(&args[arg_start..]);
+    result
+}
+
+/// Builds up a signature label, remembering where each parameter is in it
+struct LabelBuilder {
+    label: String,
+    parameters: Vec<ParameterInformation>,
+}
+
+impl LabelBuilder {
+    fn new(start: String) -> Self {
+        Self {
+            label: start,
+            parameters: Vec::new(),
+        }
+    }
+    fn push_str(&mut self, s: &str) {
+        self.label.push_str(s);
+    }
+    fn push_param(&mut self, text: &str, documentation: Option<lsp_types::Documentation>) {
+        // Offsets are in UTF-16 code units
+        let start = self.label.encode_utf16().count() as u32;
+        self.label.push_str(text);
+        let end = self.label.encode_utf16().count() as u32;
+        self.parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation,
+        });
+    }
+}
+
+fn template_signature(
+    linker: &Linker,
+    global_name: &str,
+    args: &[&[Node]],
+    text: &str,
+) -> Option<SignatureInformation> {
+    let global = linker.get_by_name(global_name).ok()?;
+    let link_info = &linker.globals[global];
+    let file_text = &linker.files[link_info.file].file_text;
+
+    let mut builder = LabelBuilder::new(format!("{} #(", link_info.name));
+    for (idx, (_, param)) in link_info.parameters.iter().enumerate() {
+        if idx != 0 {
+            builder.push_str(", ");
+        }
+        match &param.kind {
+            TemplateKind::Type(_) => builder.push_param(&format!("type {}", param.name), None),
+            TemplateKind::Value(v) => builder.push_param(&file_text[v.decl_span], None),
+        }
+    }
+    builder.push_str(")");
+
+    // Template arguments are named, `NAME: value`. Fall back to the position for an argument without a name yet
+    let active_parameter = match args.last().copied().unwrap_or_default() {
+        [name, colon, ..] if colon.kind_id() == kw!(":") => {
+            let name = &text[name.byte_range()];
+            link_info
+                .parameters
+                .iter()
+                .position(|(_, param)| param.name == name)
+        }
+        _ => None,
+    }
+    .unwrap_or(args.len().saturating_sub(1));
+
+    Some(SignatureInformation {
+        label: builder.label,
+        documentation: documentation(&link_info.documentation, link_info.file, linker),
+        parameters: Some(builder.parameters),
+        active_parameter: Some(active_parameter as u32),
+    })
+}
+
+fn find_submodule_target<'l>(
+    linker: &'l Linker,
+    current: &LinkInfo,
+    name: &str,
+) -> Option<&'l Module> {
+    current
+        .instructions
+        .iter()
+        .find_map(|(_, instr)| match instr {
+            Instruction::SubModule(sm) if sm.name == name => {
+                Some(&linker.modules[sm.module_ref.id])
+            }
+            _ => None,
+        })
+}
+
+fn interface_signature(
+    linker: &Linker,
+    md: &Module,
+    interface_name: &str,
+    args: &[&[Node]],
+) -> Option<SignatureInformation> {
+    let decl_id = md.interfaces.iter().find_map(|(_, interface)| {
+        match interface.declaration_instruction {
+            Some(InterfaceDeclKind::Interface(decl_id)) if interface.name == interface_name => {
+                Some(decl_id)
+            }
+            _ => None,
+        }
+    })?;
+    let link_info = &md.link_info;
+    let file_text = &linker.files[link_info.file].file_text;
+    let interface = link_info.instructions[decl_id].unwrap_interface();
+
+    let mut builder = LabelBuilder::new(format!("{}(", interface.name));
+    let push_ports = |builder: &mut LabelBuilder, ports: &[FlatID]| {
+        for (idx, port) in ports.iter().enumerate() {
+            if idx != 0 {
+                builder.push_str(", ");
+            }
+            let decl = link_info.instructions[*port].unwrap_declaration();
+            let direction = match decl.decl_kind {
+                DeclarationKind::Port { direction, .. } => format!("{direction} "),
+                _ => String::new(),
+            };
+            // The declaration span includes the latency specifier
+            builder.push_param(
+                &format!("{direction}{}", &file_text[decl.decl_span]),
+                documentation(&decl.documentation, link_info.file, linker),
+            );
+        }
+    };
+    push_ports(&mut builder, &interface.inputs);
+    builder.push_str(")");
+    if !interface.outputs.is_empty() {
+        builder.push_str(" -> ");
+        // Outputs aren't passed as arguments, but are shown for completeness
+        let num_inputs = builder.parameters.len();
+        push_ports(&mut builder, &interface.outputs);
+        builder.parameters.truncate(num_inputs);
+    }
+
+    Some(SignatureInformation {
+        label: builder.label,
+        // The main interface is usually documented on the module itself
+        documentation: documentation(&interface.documentation, link_info.file, linker).or_else(
+            || {
+                (interface.interface_id == InterfaceID::MAIN_INTERFACE)
+                    .then(|| documentation(&link_info.documentation, link_info.file, linker))
+                    .flatten()
+            },
+        ),
+        parameters: Some(builder.parameters),
+        active_parameter: Some(args.len().saturating_sub(1) as u32),
+    })
+}
+
+/// Parameter list of the interface call or template argument list the cursor is in
+pub fn make_signature_help(
+    linker: &Linker,
+    file_id: FileUUID,
+    position: usize,
+) -> Option<SignatureHelp> {
+    let file_data = &linker.files[file_id];
+    let text = &file_data.file_text.file_text;
+    let (callee, arg_tokens) = call_context(&file_data.tree, text, position)?;
+    let args = split_args(&arg_tokens);
+
+    let current = file_data
+        .associated_values
+        .iter()
+        .map(|global| &linker.globals[*global])
+        .find(|link_info| link_info.span.contains_pos(position));
+
+    let signature = match callee {
+        Callee::TemplateArgs { of } => template_signature(linker, of, &args, text)?,
+        Callee::Direct { name } => {
+            let md = current
+                .and_then(|current| find_submodule_target(linker, current, name))
+                .or_else(|| match linker.get_by_name(name) {
+                    Ok(GlobalUUID::Module(md_id)) => Some(&linker.modules[md_id]),
+                    _ => None,
+                })?;
+            // The main interface carries the name of the module
+            interface_signature(linker, md, &md.link_info.name, &args)?
+        }
+        Callee::Interface {
+            submodule,
+            interface,
+        } => {
+            let md = find_submodule_target(linker, current?, submodule)?;
+            interface_signature(linker, md, interface, &args)?
+        }
+    };
+    let active_parameter = signature.active_parameter;
+    Some(SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::compile_text;
+
+    /// The callee and number of arguments at the `|` in `body`, inside a module
+    fn call_at(body: &str) -> Option<(String, usize)> {
+        let text = format!("module m {{\n{body}\n    bool done\n}}\n");
+        let position = text.find('|').unwrap();
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_sus::language()).unwrap();
+        let tree = parser.parse(text.replace('|', " "), None).unwrap();
+        let (callee, args) = call_context(&tree, &text, position)?;
+        Some((format!("{callee:?}"), split_args(&args).len()))
+    }
+
+    #[test]
+    fn test_call_context() {
+        let interface = r#"Interface { submodule: "fifo", interface: "push" }"#;
+        assert_eq!(call_at("    fifo.push(|)"), Some((interface.into(), 1)));
+        let adder = r#"Direct { name: "Adder" }"#;
+        assert_eq!(call_at("    x = Adder(a, b|)"), Some((adder.into(), 2)));
+        assert_eq!(call_at("    x = Adder #(W: 3)(a|"), Some((adder.into(), 1)));
+        assert_eq!(
+            call_at("    FIFO #(DEPTH: 3, T|) f"),
+            Some((r#"TemplateArgs { of: "FIFO" }"#.into(), 2))
+        );
+        // Nested brackets are part of one argument
+        assert_eq!(
+            call_at("    x = Adder(f(a, b)[1], |)"),
+            Some((adder.into(), 2))
+        );
+        assert_eq!(call_at("    x = f(a[3], (b|))"), None);
+        assert_eq!(call_at("    x = a[3|]"), None);
+        assert_eq!(call_at("    x = a + b|"), None);
+        // Brackets in comments don't count
+        assert_eq!(call_at("    x = a // Adder(\n    y = |"), None);
+    }
+
+    const MODULES: &str = "
+/// Inverts a bit
+module inv {
+    interface inv : bool a -> bool b
+    b = !a
+}
+module pair {
+    interface both : bool x, bool y -> bool o
+    o = x & y
+}
+module outer #(T, int N) {
+    pair p
+    bool r = p.both(true, false)
+    bool s = inv(r)
+}
+module user {
+    outer #(T: type bool, N: 3) o
+}
+";
+
+    fn help_after(after: &str) -> SignatureHelp {
+        let (linker, file) = compile_text(MODULES);
+        let position = MODULES.find(after).unwrap() + after.len();
+        make_signature_help(&linker, file, position).unwrap()
+    }
+
+    fn param_labels(help: &SignatureHelp) -> Vec<String> {
+        let signature = &help.signatures[0];
+        let label: Vec<u16> = signature.label.encode_utf16().collect();
+        signature
+            .parameters
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|param| {
+                let ParameterLabel::LabelOffsets([start, end]) = param.label else {
+                    panic!("Parameters are given as offsets");
+                };
+                String::from_utf16(&label[start as usize..end as usize]).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_interface_signature() {
+        let help = help_after("p.both(true, ");
+        assert_eq!(
+            help.signatures[0].label,
+            "both(input bool x, input bool y) -> output bool o"
+        );
+        assert_eq!(param_labels(&help), ["input bool x", "input bool y"]);
+        assert_eq!(help.active_parameter, Some(1));
+    }
+
+    #[test]
+    fn test_direct_call_signature() {
+        let help = help_after("inv(");
+        assert_eq!(param_labels(&help), ["input bool a"]);
+        assert_eq!(help.active_parameter, Some(0));
+        // The main interface shows the documentation of the module
+        let Some(lsp_types::Documentation::String(doc)) = &help.signatures[0].documentation else {
+            panic!("inv is documented");
+        };
+        assert_eq!(doc.trim(), "Inverts a bit");
+    }
+
+    #[test]
+    fn test_template_signature() {
+        let help = help_after("outer #(");
+        assert_eq!(help.signatures[0].label, "outer #(type T, int N)");
+        assert_eq!(help.active_parameter, Some(0));
+        // Named arguments select their parameter, wherever they are written
+        let help = help_after("outer #(T: type bool, N: ");
+        assert_eq!(help.active_parameter, Some(1));
+    }
+
+    #[test]
+    fn test_no_signature_outside_calls() {
+        let (linker, file) = compile_text(MODULES);
+        let position = MODULES.find("bool r = ").unwrap() + "bool r = ".len();
+        assert!(make_signature_help(&linker, file, position).is_none());
+    }
+}
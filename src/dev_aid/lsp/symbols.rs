@@ -0,0 +1,308 @@
+use lsp_types::{DocumentSymbol, Location, OneOf, SymbolKind, Url, WorkspaceSymbol};
+
+use crate::flattening::{DeclarationKind, Instruction, InterfaceDeclKind, Module};
+use crate::linker::{FileData, GlobalUUID, LinkInfo};
+use crate::prelude::*;
+
+use super::span_to_lsp_range;
+
+#[allow(deprecated)] // DocumentSymbol::deprecated has no Default
+fn mk_symbol(
+    file_data: &FileData,
+    name: &str,
+    detail: Option<String>,
+    kind: SymbolKind,
+    span: Span,
+    name_span: Span,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    DocumentSymbol {
+        name: name.to_string(),
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: span_to_lsp_range(&file_data.file_text, span),
+        selection_range: span_to_lsp_range(&file_data.file_text, name_span),
+        children: (!children.is_empty()).then_some(children),
+    }
+}
+
+fn sort_by_position(symbols: &mut [DocumentSymbol]) {
+    symbols.sort_by_key(|s| (s.range.start.line, s.range.start.character));
+}
+
+fn port_symbols(
+    file_data: &FileData,
+    md: &Module,
+    in_interface: impl Fn(&DeclarationKind) -> bool,
+) -> Vec<DocumentSymbol> {
+    md.ports
+        .iter()
+        .filter_map(|(_, port)| {
+            // The trigger port of an action or trigger is the interface itself
+            let Instruction::Declaration(decl) =
+                &md.link_info.instructions[port.declaration_instruction]
+            else {
+                return None;
+            };
+            in_interface(&decl.decl_kind).then(|| {
+                let typ = &file_data.file_text[decl.typ_expr.get_span()];
+                mk_symbol(
+                    file_data,
+                    &port.name,
+                    Some(format!("{} {typ}", port.direction)),
+                    SymbolKind::PROPERTY,
+                    port.decl_span,
+                    port.name_span,
+                    Vec::new(),
+                )
+            })
+        })
+        .collect()
+}
+
+fn module_children(file_data: &FileData, md: &Module, linker: &Linker) -> Vec<DocumentSymbol> {
+    let mut children = Vec::new();
+    for (_, domain) in &md.domains {
+        if let Some(name_span) = domain.name_span {
+            children.push(mk_symbol(
+                file_data,
+                &domain.name,
+                Some("domain".to_string()),
+                SymbolKind::NAMESPACE,
+                name_span,
+                name_span,
+                Vec::new(),
+            ));
+        }
+    }
+    for (interface_id, interface) in &md.interfaces {
+        // Standalone ports are their own interface, they're listed as ports below
+        let Some(InterfaceDeclKind::Interface(decl_id)) = interface.declaration_instruction else {
+            continue;
+        };
+        let decl = md.link_info.instructions[decl_id].unwrap_interface();
+        let ports = port_symbols(file_data, md, |kind| match kind {
+            DeclarationKind::Port {
+                parent_interface, ..
+            } => *parent_interface == interface_id,
+            _ => false,
+        });
+        children.push(mk_symbol(
+            file_data,
+            &interface.name,
+            Some("interface".to_string()),
+            SymbolKind::INTERFACE,
+            decl.decl_span,
+            interface.name_span,
+            ports,
+        ));
+    }
+    children.extend(port_symbols(file_data, md, |kind| {
+        matches!(
+            kind,
+            DeclarationKind::Port {
+                is_standalone_port: true,
+                ..
+            }
+        )
+    }));
+    for (_, instr) in &md.link_info.instructions {
+        if let Instruction::SubModule(sm) = instr {
+            let module_name = &linker.modules[sm.module_ref.id].link_info.name;
+            children.push(mk_symbol(
+                file_data,
+                &sm.name,
+                Some(module_name.clone()),
+                SymbolKind::OBJECT,
+                Span::new_overarching(sm.module_ref.get_total_span(), sm.name_span),
+                sm.name_span,
+                Vec::new(),
+            ));
+        }
+    }
+    sort_by_position(&mut children);
+    children
+}
+
+fn global_kind(global: GlobalUUID) -> SymbolKind {
+    match global {
+        GlobalUUID::Module(_) => SymbolKind::MODULE,
+        GlobalUUID::Type(_) => SymbolKind::STRUCT,
+        GlobalUUID::Constant(_) => SymbolKind::CONSTANT,
+    }
+}
+
+/// Outline of a file: modules with their domains, interfaces, ports and submodules, and structs and constants
+pub fn make_document_symbols(linker: &Linker, file_id: FileUUID) -> Vec<DocumentSymbol> {
+    let file_data = &linker.files[file_id];
+    let mut symbols: Vec<DocumentSymbol> = file_data
+        .associated_values
+        .iter()
+        .map(|global| {
+            let link_info: &LinkInfo = &linker.globals[*global];
+            let children = match global {
+                GlobalUUID::Module(md_id) => {
+                    module_children(file_data, &linker.modules[*md_id], linker)
+                }
+                GlobalUUID::Type(_) | GlobalUUID::Constant(_) => Vec::new(),
+            };
+            mk_symbol(
+                file_data,
+                &link_info.name,
+                None,
+                global_kind(*global),
+                link_info.span,
+                link_info.name_span,
+                children,
+            )
+        })
+        .collect();
+    sort_by_position(&mut symbols);
+    symbols
+}
+
+/// Case insensitive subsequence match. Lower is better: exact and prefix matches, then substrings, then by how spread out the match is
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let name = name.to_lowercase();
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(&query) {
+        return Some(1);
+    }
+    if name.contains(&query) {
+        return Some(2);
+    }
+    let mut name_chars = name.chars().enumerate();
+    let mut gaps = 0;
+    let mut prev_idx = None;
+    for q in query.chars() {
+        let (idx, _) = name_chars.find(|(_, c)| *c == q)?;
+        if prev_idx.is_some_and(|prev| idx != prev + 1) {
+            gaps += 1;
+        }
+        prev_idx = Some(idx);
+    }
+    Some(3 + gaps)
+}
+
+/// Fuzzy search over all globals, including the standard library
+pub fn make_workspace_symbols(linker: &Linker, query: &str) -> Vec<WorkspaceSymbol> {
+    let mut found: Vec<(usize, WorkspaceSymbol)> = Vec::new();
+    for (_, file_data) in &linker.files {
+        for global in &file_data.associated_values {
+            let link_info: &LinkInfo = &linker.globals[*global];
+            let Some(score) = fuzzy_score(query, &link_info.name) else {
+                continue;
+            };
+            let location = Location {
+                uri: Url::parse(&file_data.file_identifier).unwrap(),
+                range: span_to_lsp_range(&file_data.file_text, link_info.name_span),
+            };
+            found.push((
+                score,
+                WorkspaceSymbol {
+                    name: link_info.name.clone(),
+                    kind: global_kind(*global),
+                    tags: None,
+                    container_name: None,
+                    location: OneOf::Left(location),
+                    data: None,
+                },
+            ));
+        }
+    }
+    found.sort_by(|(score_a, a), (score_b, b)| {
+        score_a.cmp(score_b).then_with(|| a.name.cmp(&b.name))
+    });
+    found.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TEST_FILE_URL, compile_text_lsp};
+
+    const OUTLINE: &str = "
+module inv {
+    interface inv : bool a -> bool b
+    b = !a
+}
+module outer {
+    domain fast
+    input bool x
+    output bool y
+    inv i
+    y = i(x)
+}
+";
+
+    fn names(symbols: &[DocumentSymbol]) -> Vec<(&str, SymbolKind)> {
+        symbols.iter().map(|s| (s.name.as_str(), s.kind)).collect()
+    }
+
+    #[test]
+    fn test_document_symbols_outline() {
+        let (linker, file) = compile_text_lsp(OUTLINE);
+        let symbols = make_document_symbols(&linker, file);
+        assert_eq!(
+            names(&symbols),
+            [("inv", SymbolKind::MODULE), ("outer", SymbolKind::MODULE)]
+        );
+
+        let inv_children = symbols[0].children.as_deref().unwrap();
+        assert_eq!(names(inv_children), [("inv", SymbolKind::INTERFACE)]);
+        let interface_ports = inv_children[0].children.as_deref().unwrap();
+        assert_eq!(
+            names(interface_ports),
+            [("a", SymbolKind::PROPERTY), ("b", SymbolKind::PROPERTY)]
+        );
+        assert_eq!(interface_ports[0].detail.as_deref(), Some("input bool"));
+
+        let outer_children = symbols[1].children.as_deref().unwrap();
+        assert_eq!(
+            names(outer_children),
+            [
+                ("fast", SymbolKind::NAMESPACE),
+                ("x", SymbolKind::PROPERTY),
+                ("y", SymbolKind::PROPERTY),
+                ("i", SymbolKind::OBJECT),
+            ]
+        );
+        assert_eq!(outer_children[2].detail.as_deref(), Some("output bool"));
+        assert_eq!(outer_children[3].detail.as_deref(), Some("inv"));
+        // The selection range is the name, on the line of the declaration
+        assert_eq!(outer_children[3].selection_range.start.line, 9);
+    }
+
+    #[test]
+    fn test_workspace_symbols_ranks_and_locates() {
+        let (linker, _file) = compile_text_lsp(OUTLINE);
+        let symbols = make_workspace_symbols(&linker, "outer");
+        assert_eq!(symbols[0].name, "outer");
+        assert_eq!(symbols[0].kind, SymbolKind::MODULE);
+        let OneOf::Left(location) = &symbols[0].location else {
+            panic!("Workspace symbols should have a full location");
+        };
+        assert_eq!(location.uri.as_str(), TEST_FILE_URL);
+        assert_eq!(location.range.start.line, 5);
+
+        // Standard library globals are searched too
+        let symbols = make_workspace_symbols(&linker, "crossdomain");
+        assert_eq!(symbols[0].name, "CrossDomain");
+        assert!(make_workspace_symbols(&linker, "qqqq").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("fifo", "FIFO"), Some(0));
+        assert_eq!(fuzzy_score("fi", "FIFO"), Some(1));
+        assert_eq!(fuzzy_score("ifo", "FIFO"), Some(2));
+        assert_eq!(fuzzy_score("cdom", "CrossDomain"), Some(4));
+        assert_eq!(fuzzy_score("xyz", "CrossDomain"), None);
+        assert!(fuzzy_score("", "anything").is_some());
+    }
+}
@@ -0,0 +1,250 @@
+//! `--message-format json` and `--message-format sarif`: the errors of [Linker::collect_all_errors] for other tools to consume.
+//!
+//! Lines and columns are 1-based, columns count unicode code points like [crate::file_position::LineCol].
+//! Byte ranges are 0-based and exclusive at the end, like [Span]
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::prelude::*;
+
+use crate::errors::{CompileError, ErrorLevel};
+use crate::file_position::FileText;
+use crate::linker::FileData;
+
+use super::fix::display_path;
+
+#[derive(Debug, Serialize)]
+struct JsonRange {
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+}
+
+impl JsonRange {
+    fn new(file_text: &FileText, span: Span) -> Self {
+        let range = file_text.get_span_linecol_range(span);
+        let bytes = span.as_range();
+        JsonRange {
+            byte_start: bytes.start,
+            byte_end: bytes.end,
+            line_start: range.start.line + 1,
+            column_start: range.start.col + 1,
+            line_end: range.end.line + 1,
+            column_end: range.end.col + 1,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonInfo {
+    file: String,
+    range: JsonRange,
+    info: String,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSuggestion {
+    range: JsonRange,
+    replace_with: String,
+}
+
+/// One line of `--message-format json` output
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic {
+    file: String,
+    code: String,
+    level: &'static str,
+    reason: String,
+    range: JsonRange,
+    infos: Vec<JsonInfo>,
+    suggestions: Vec<JsonSuggestion>,
+}
+
+fn level_name(level: &ErrorLevel) -> &'static str {
+    match level {
+        ErrorLevel::Error => "error",
+        ErrorLevel::Warning => "warning",
+    }
+}
+
+fn to_json_diagnostic(linker: &Linker, file_data: &FileData, err: CompileError) -> JsonDiagnostic {
+    JsonDiagnostic {
+        file: file_data.file_identifier.clone(),
+        code: err.code.to_string(),
+        level: level_name(&err.level),
+        range: JsonRange::new(&file_data.file_text, err.position),
+        reason: err.reason,
+        infos: err
+            .infos
+            .into_iter()
+            .map(|info| {
+                let info_file = &linker.files[info.file];
+                JsonInfo {
+                    file: info_file.file_identifier.clone(),
+                    range: JsonRange::new(&info_file.file_text, info.position),
+                    info: info.info,
+                }
+            })
+            .collect(),
+        suggestions: err
+            .suggestions
+            .into_iter()
+            .map(|s| JsonSuggestion {
+                range: JsonRange::new(&file_data.file_text, s.position),
+                replace_with: s.replace_with,
+            })
+            .collect(),
+    }
+}
+
+/// Prints one JSON object per line for every error and warning
+pub fn print_all_errors_json(linker: &Linker) {
+    let errors = linker.collect_all_errors();
+    for (_file_id, file_data, errs_for_file) in crate::alloc::zip_eq(linker.files.iter(), errors) {
+        for err in errs_for_file {
+            let diagnostic = to_json_diagnostic(linker, file_data, err);
+            println!("{}", serde_json::to_string(&diagnostic).unwrap());
+        }
+    }
+}
+
+/// SARIF wants URIs. Paths relative to the working directory stay relative, such that code scanning dashboards can match them to the repository
+fn path_to_uri(file_identifier: &str) -> String {
+    let path = display_path(file_identifier);
+    let mut uri = String::with_capacity(path.len());
+    if path.starts_with('/') {
+        uri.push_str("file://");
+    }
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+fn sarif_location(file_data: &FileData, span: Span) -> Value {
+    json!({
+        "artifactLocation": {"uri": path_to_uri(&file_data.file_identifier)},
+        "region": sarif_region(&file_data.file_text, span),
+    })
+}
+
+fn sarif_region(file_text: &FileText, span: Span) -> Value {
+    let range = JsonRange::new(file_text, span);
+    json!({
+        "startLine": range.line_start,
+        "startColumn": range.column_start,
+        "endLine": range.line_end,
+        "endColumn": range.column_end,
+        "byteOffset": range.byte_start,
+        "byteLength": range.byte_end - range.byte_start,
+    })
+}
+
+fn sarif_result(linker: &Linker, file_data: &FileData, err: CompileError) -> Value {
+    let rule_index = ErrorCode::ALL.iter().position(|c| *c == err.code).unwrap();
+    let related_locations: Vec<Value> = err
+        .infos
+        .into_iter()
+        .enumerate()
+        .map(|(idx, info)| {
+            json!({
+                "id": idx,
+                "physicalLocation": sarif_location(&linker.files[info.file], info.position),
+                "message": {"text": info.info},
+            })
+        })
+        .collect();
+    let fixes: Vec<Value> = err
+        .suggestions
+        .into_iter()
+        .map(|s| {
+            let description = if s.replace_with.is_empty() {
+                "Remove this".to_string()
+            } else {
+                format!("Replace this with \"{}\"", s.replace_with)
+            };
+            json!({
+                "description": {"text": description},
+                "artifactChanges": [{
+                    "artifactLocation": {"uri": path_to_uri(&file_data.file_identifier)},
+                    "replacements": [{
+                        "deletedRegion": sarif_region(&file_data.file_text, s.position),
+                        "insertedContent": {"text": s.replace_with},
+                    }],
+                }],
+            })
+        })
+        .collect();
+    json!({
+        "ruleId": err.code.to_string(),
+        "ruleIndex": rule_index,
+        "level": level_name(&err.level),
+        "message": {"text": err.reason},
+        "locations": [{"physicalLocation": sarif_location(file_data, err.position)}],
+        "relatedLocations": related_locations,
+        "fixes": fixes,
+    })
+}
+
+/// Prints a single SARIF 2.1.0 log with every error and warning as a result. Every [ErrorCode] is a rule
+pub fn print_all_errors_sarif(linker: &Linker) {
+    let rules: Vec<Value> = ErrorCode::ALL
+        .iter()
+        .map(|code| {
+            json!({
+                "id": code.to_string(),
+                "name": format!("{code:?}"),
+                "shortDescription": {"text": code.title()},
+                "fullDescription": {"text": code.explanation()},
+            })
+        })
+        .collect();
+
+    let errors = linker.collect_all_errors();
+    let mut results = Vec::new();
+    for (_file_id, file_data, errs_for_file) in crate::alloc::zip_eq(linker.files.iter(), errors) {
+        for err in errs_for_file {
+            results.push(sarif_result(linker, file_data, err));
+        }
+    }
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+    println!("{}", serde_json::to_string_pretty(&sarif).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_range() {
+        let text = FileText::new("ab\ncδe\n".to_string());
+        let range = JsonRange::new(&text, Span::from(3..7));
+        assert_eq!((range.byte_start, range.byte_end), (3, 7));
+        assert_eq!((range.line_start, range.column_start), (2, 1));
+        assert_eq!((range.line_end, range.column_end), (2, 4));
+    }
+}
@@ -2,6 +2,16 @@ pub mod ariadne_interface;
 
 pub mod dot_graphs;
 
+pub mod doc;
+
+pub mod fix;
+
+pub mod format;
+
+pub mod latency_html;
+
+pub mod message_format;
+
 #[cfg(feature = "lsp")]
 pub mod lsp;
 
@@ -0,0 +1,940 @@
+//! Stable codes for every kind of diagnostic the compiler produces, like `SUS0301`
+//!
+//! The number of a code must never change once released, such that they can be searched for and referred to.
+//! New codes get the next free number within the hundred of their compiler stage:
+//!
+//! - `00xx` Parsing
+//! - `01xx` Names and declarations
+//! - `02xx` Domains and generative code
+//! - `03xx` Abstract typing
+//! - `04xx` Lints
+//! - `05xx` Instantiation
+//! - `06xx` Latency counting
+//!
+//! Warnings are lints: they have a [ErrorCode::lint_name], and their level can be configured, see [crate::lint_levels].
+//!
+//! `sus_compiler --explain SUS0301` prints the [ErrorCode::explanation]
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+macro_rules! error_codes {
+    ($($code:ident = $number:literal: $title:literal $(lint $lint:literal)?,)*) => {
+        /// See [crate::error_codes]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $($code,)*
+        }
+
+        impl ErrorCode {
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$code,)*];
+
+            pub fn number(self) -> u16 {
+                match self {
+                    $(ErrorCode::$code => $number,)*
+                }
+            }
+
+            /// One line summary of what this code means
+            pub fn title(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$code => $title,)*
+                }
+            }
+
+            /// Name for configuring the level of this lint, like `unused-variable`. Hard errors are not lints
+            pub fn lint_name(self) -> Option<&'static str> {
+                match self {
+                    $(ErrorCode::$code => None$(.or(Some($lint)))?,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    SyntaxError = 1: "Syntax error",
+    NotYetImplemented = 2: "Feature not yet implemented",
+
+    UnknownName = 101: "Unknown name",
+    GlobalNameConflict = 102: "Multiple globals with the same name",
+    WrongKindOfGlobal = 103: "Global of the wrong kind",
+    WrongKindOfName = 104: "Local name of the wrong kind",
+    DuplicateDeclaration = 105: "Duplicate declaration",
+    MisplacedKeyword = 106: "Misplaced or duplicate keyword",
+    MisplacedLatencySpecifier = 107: "Latency specifier not allowed here",
+    InvalidInterfaceBlock = 108: "Invalid block on an interface",
+    NotAWireReference = 109: "Expected a wire reference",
+    IncompletePartSelect = 110: "Incomplete part-select",
+    InvalidLiteral = 111: "Invalid literal",
+    TruncatedLiteral = 112: "Literal is truncated" lint "truncated-literal",
+    UnusedExpressionResult = 113: "Unused expression result" lint "unused-result",
+    MultipleStatementsOnLine = 114: "Multiple statements on one line" lint "multiple-statements",
+    MisplacedWriteModifier = 115: "Write modifier without an assignment",
+    InvalidTemplateArgument = 116: "Invalid template argument",
+
+    ImplicitClockPort = 201: "Port on the implicit clock domain",
+    InvalidClockRatio = 202: "Invalid clock ratio",
+    DomainMismatch = 203: "Values from different domains combined",
+    RuntimeValueInGenerativeContext = 204: "Runtime value where a compile-time value is required",
+    IfWhenMismatch = 205: "`if` and `when` used in the wrong context",
+
+    TypeMismatch = 301: "Type mismatch",
+    UnresolvedType = 302: "Type could not be inferred",
+    NoSuchField = 303: "No such field",
+    ArgumentCountMismatch = 304: "Wrong number of arguments or outputs",
+    NotCallable = 305: "Not a callable interface",
+    MultipleOutputsInAssignment = 306: "Assignment of an expression with several outputs",
+    InvalidConditionalBinding = 307: "Invalid conditional binding",
+
+    WriteToReadOnly = 401: "Write to a read-only value",
+    ReadFromInputPort = 402: "Read from an input port of a submodule",
+    AccessOnInlineModule = 403: "Access on an inline module",
+    InterfaceUsedAsValue = 404: "Interface used as a value",
+    GenerativeWriteInWhen = 405: "Generative variable written in a `when` block",
+    InvalidInitialValue = 406: "Invalid `initial` value",
+    ExternTypeParameter = 407: "`type` parameter on an `extern` module",
+    ExternPortWithoutLatency = 408: "Port of an `extern` module without latency",
+    UnusedVariable = 409: "Unused variable" lint "unused-variable",
+    NonSynthesizableType = 410: "Type can only be used for generative values",
+    UnknownLint = 411: "Unknown lint in a `sus:` comment" lint "unknown-lints",
+
+    GenerativeExecutionError = 501: "Error while executing generative code",
+    NotInstantiated = 502: "Module not instantiated due to errors" lint "not-instantiated",
+    SubmoduleInstantiationFailed = 503: "Submodule could not be instantiated",
+    DisabledPortUsed = 504: "Use of a disabled port",
+    UnusedPort = 505: "Unused port" lint "unused-port",
+    InvalidConcreteType = 506: "Invalid concrete type",
+    RankMismatch = 507: "Array rank mismatch for an operator",
+    PossibleDivisionByZero = 508: "Possible division by zero",
+    NotASubtype = 509: "Value does not fit its type",
+    IndexOutOfBounds = 510: "Index out of bounds",
+    UnsynchronizedCrossing = 511: "Clock domain crossing without a synchronizer" lint "unsynchronized-crossing",
+    MultiBitCrossing = 512: "Multi-bit clock domain crossing without a valid signal" lint "multi-bit-crossing",
+    CombinationalLoop = 513: "Combinational loop",
+    ConstantCondition = 514: "Condition is constant in this instantiation" lint "constant-condition",
+    UnreachableHardware = 515: "Declaration is unused in this instantiation" lint "unreachable-hardware",
+    IntTruncation = 516: "`unsafe_int_cast` truncates its input" lint "int-truncation",
+    IntRangeGrowth = 517: "Integer range grows far beyond its use" lint "int-range-growth",
+    StateWithoutReset = 518: "State without reset or initial value" lint "state-without-reset",
+
+    UnreachableLatencyNode = 601: "Latency could not be determined",
+    NetPositiveLatencyCycle = 602: "Net positive latency cycle",
+    IndeterminablePortLatency = 603: "Ambiguous port latency",
+    PortsNotStronglyConnected = 604: "Port not connected to the other ports",
+    ConflictingSpecifiedLatency = 605: "Conflicting specified latencies",
+    ImpossibleInitiationInterval = 606: "State feedback loop can't meet its initiation interval",
+}
+
+impl ErrorCode {
+    /// Longer explanation with a minimal example, shown by `--explain`
+    pub fn explanation(self) -> &'static str {
+        match self {
+            ErrorCode::SyntaxError => {
+                r#"The parser could not make sense of the source code. The error message names the syntax node it was parsing, and what it found instead.
+
+```sus
+module m {
+    int x = 3 +
+}
+```
+
+Check for missing operands, unbalanced brackets, and misspelled keywords."#
+            }
+            ErrorCode::NotYetImplemented => {
+                r#"The code uses a language feature the compiler does not support yet, such as namespaces.
+
+```sus
+module m {
+    int x = a::b
+}
+```"#
+            }
+            ErrorCode::UnknownName => {
+                r#"No local variable or global (module, type or constant) with this name exists.
+
+```sus
+module m {
+    output int o
+    o = undeclared_value
+}
+```
+
+Check the spelling, and that the file declaring the global is passed to the compiler."#
+            }
+            ErrorCode::GlobalNameConflict => {
+                r#"Several globals (modules, types or constants) have the same name. Global names must be unique across all files, including the standard library.
+
+```sus
+module adder {}
+module adder {}
+```
+
+Rename one of them."#
+            }
+            ErrorCode::WrongKindOfGlobal => {
+                r#"A global is used where a different kind of global is required, such as a type where a value is expected.
+
+```sus
+module m {
+    int x = bool
+}
+```
+
+Here `bool` is a type, so it can't be used as a value."#
+            }
+            ErrorCode::WrongKindOfName => {
+                r#"A local name refers to something of the wrong kind, such as a domain or submodule where a value or a type is expected.
+
+```sus
+module m {
+    domain d
+    input int x
+    int y = d
+}
+```"#
+            }
+            ErrorCode::DuplicateDeclaration => {
+                r#"Two declarations in the same scope have the same name. This applies to wires, submodules, interfaces, domains and template parameters.
+
+```sus
+module m {
+    int x = 1
+    int x = 2
+}
+```
+
+Rename one of them."#
+            }
+            ErrorCode::MisplacedKeyword => {
+                r#"A declaration modifier like `input`, `output`, `state` or `gen` is repeated, or used where it has no meaning.
+
+```sus
+module m {
+    gen state int x = 3
+}
+```
+
+A generative value exists only at compile time, so it can't be a `state` register, or a port."#
+            }
+            ErrorCode::MisplacedLatencySpecifier => {
+                r#"A latency specifier `'N` is only allowed on wire declarations, ports, and the trigger ports of actions and triggers.
+
+```sus
+module a {}
+module m {
+    a sub'3
+}
+```
+
+Remove the specifier. The compiler suggests this as a fix."#
+            }
+            ErrorCode::InvalidInterfaceBlock => {
+                r#"Only actions and triggers can have a code block. Plain interfaces can't have an `else` block, and actions require a block.
+
+```sus
+module m {
+    action go : int x
+}
+```"#
+            }
+            ErrorCode::NotAWireReference => {
+                r#"A place that requires a wire reference (a name, possibly with indices and fields) was given another expression, like an operator or a constant. This happens when assigning to, or calling, an expression.
+
+```sus
+module m {
+    int a
+    int b
+    a + b = 3
+}
+```"#
+            }
+            ErrorCode::IncompletePartSelect => {
+                r#"An indexed part-select `arr[start +: width]` or `arr[start -: width]` is missing its start or width.
+
+```sus
+module m {
+    input bool[8] v
+    output bool[4] o
+    o = v[+:4]
+}
+```"#
+            }
+            ErrorCode::InvalidLiteral => {
+                r#"A literal could not be parsed, like a string with an invalid escape sequence, or a malformed boolean array literal.
+
+```sus
+module m {
+    gen string s = "\q"
+}
+```"#
+            }
+            ErrorCode::TruncatedLiteral => {
+                r#"A boolean array literal is given a size that is smaller than the position of its highest `1` bit. The bits above the size are dropped.
+
+Give the literal a size that fits all of its bits."#
+            }
+            ErrorCode::UnusedExpressionResult => {
+                r#"An expression is used as a statement, but its result is not assigned anywhere. Only function calls that return nothing may stand on their own.
+
+```sus
+module m {
+    input int a
+    a + 3
+}
+```"#
+            }
+            ErrorCode::MultipleStatementsOnLine => {
+                r#"Several standalone declarations or expressions are written as one comma separated statement.
+
+```sus
+module m {
+    int a, int b
+}
+```
+
+Put each declaration on its own line."#
+            }
+            ErrorCode::MisplacedWriteModifier => {
+                r#"Write modifiers like `reg` and `initial` only make sense on the left side of an assignment.
+
+```sus
+module m {
+    reg int a
+}
+```"#
+            }
+            ErrorCode::InvalidTemplateArgument => {
+                r#"A template argument doesn't match the parameters of the global it's passed to. It names no parameter, is given twice, or passes a value for a type parameter or the other way around.
+
+```sus
+module m #(int W) {}
+module user {
+    m #(W: 3, W: 4) a
+    m #(WIDTH: 3) b
+}
+```
+
+Type arguments are written with the `type` keyword: `#(T: type int[3])`."#
+            }
+            ErrorCode::ImplicitClockPort => {
+                r#"Once a module declares explicit domains, all ports must be declared after a domain. Ports before the first `domain` statement would be on the implicit `clk` domain.
+
+```sus
+module m {
+    input int a
+    domain d
+    input int b
+}
+```
+
+Declare a domain before the first port."#
+            }
+            ErrorCode::InvalidClockRatio => {
+                r#"A clock ratio relates a domain to a previously declared domain with a positive rational factor, like `2 * clk` or `clk / 2`. Only `*` and `/`, integers and a single domain are allowed.
+
+```sus
+module m {
+    domain fast = clk + 2
+}
+```"#
+            }
+            ErrorCode::DomainMismatch => {
+                r#"Values from different clock domains are combined without crossing domains explicitly. This would be a clock domain crossing bug in hardware.
+
+```sus
+module m {
+    domain a
+    input int x
+    domain b
+    output int y
+    y = x
+}
+```
+
+Cross domains explicitly with `CrossDomain`, and synchronize the result in the new domain (see SUS0511 and SUS0512)."#
+            }
+            ErrorCode::RuntimeValueInGenerativeContext => {
+                r#"A value that is only known at runtime is used where a compile-time (generative) value is required, such as a `gen` variable, an array size, a template argument or a `for` loop bound.
+
+```sus
+module m {
+    input int x
+    gen int g = x
+}
+```"#
+            }
+            ErrorCode::IfWhenMismatch => {
+                r#"`if` selects code at compile time, and requires a generative condition. `when` creates a runtime multiplexer, and requires a runtime condition.
+
+```sus
+module m {
+    input bool b
+    output int o
+    if b {
+        o = 1
+    } else {
+        o = 0
+    }
+}
+```
+
+Use `when b` here. The reverse, `when` with a generative condition, is also an error."#
+            }
+            ErrorCode::TypeMismatch => {
+                r#"A value of one type is used where another type is expected.
+
+```sus
+module m {
+    input bool b
+    output int o
+    o = b
+}
+```"#
+            }
+            ErrorCode::UnresolvedType => {
+                r#"The compiler could not infer the full type of an object, or all template arguments of a submodule. This is usually a consequence of the object never being used in a way that determines its type.
+
+Specify the missing template arguments or types explicitly."#
+            }
+            ErrorCode::NoSuchField => {
+                r#"A field is accessed with `.` that the object doesn't have. On submodules, the fields are the ports and interfaces of the module.
+
+```sus
+module a {
+    input int x
+}
+module m {
+    a sub
+    sub.y = 3
+}
+```"#
+            }
+            ErrorCode::ArgumentCountMismatch => {
+                r#"An interface is called with a different number of arguments than it has inputs, its results are assigned to a different number of targets than it has outputs, or a conditional binding binds a different number of values than the trigger provides.
+
+```sus
+module add {
+    interface add : int a, int b -> int c
+    c = a + b
+}
+module m {
+    int r = add(1, 2, 3)
+}
+```"#
+            }
+            ErrorCode::NotCallable => {
+                r#"Something is called like a function, but it is not an interface that can be called, such as a plain wire, or an interface that only groups ports.
+
+```sus
+module m {
+    input int x
+    int y = x(3)
+}
+```"#
+            }
+            ErrorCode::MultipleOutputsInAssignment => {
+                r#"Only function calls can have multiple outputs. Any other expression produces exactly one value, so it must be assigned to exactly one target.
+
+```sus
+module m {
+    int a
+    int b
+    a, b = 3
+}
+```"#
+            }
+            ErrorCode::InvalidConditionalBinding => {
+                r#"Values can only be bound in the condition of a `when` on a trigger, like `when sub.t : int v { ... }`. Actions, plain interfaces and regular booleans provide no values to bind.
+
+```sus
+module m {
+    input bool b
+    when b : int v {}
+}
+```"#
+            }
+            ErrorCode::WriteToReadOnly => {
+                r#"A value is written that can only be read, such as an input port, a global constant, or an output port of a submodule.
+
+```sus
+module m {
+    input int x
+    x = 3
+}
+```"#
+            }
+            ErrorCode::ReadFromInputPort => {
+                r#"An input port of a submodule is read. Input ports of submodules can only be written to, their value comes from the parent.
+
+```sus
+module a {
+    input int x
+}
+module m {
+    a sub
+    int y = sub.x
+}
+```"#
+            }
+            ErrorCode::AccessOnInlineModule => {
+                r#"A module that is instantiated inline in an expression, like `Module(a, b)`, can't have its ports or interfaces accessed.
+
+```sus
+module a {
+    interface a : int x -> int y
+    y = x
+}
+module m {
+    int z = a.y
+}
+```
+
+Declare the submodule on its own line, like `a sub`, then use `sub.y`."#
+            }
+            ErrorCode::InterfaceUsedAsValue => {
+                r#"An interface is assigned to like a wire. Interfaces are called with arguments, or their ports are accessed.
+
+```sus
+module a {
+    interface a : bool x -> bool y
+    y = x
+}
+module m {
+    a sub
+    sub.a = true
+}
+```
+
+Call it as `bool z = sub.a(true)` instead."#
+            }
+            ErrorCode::GenerativeWriteInWhen => {
+                r#"A generative (`gen`) variable is assigned inside a runtime `when` block. The condition of a `when` is only known at runtime, so the assignment can't happen at compile time.
+
+```sus
+module m {
+    input bool b
+    gen int g = 0
+    when b {
+        g = 1
+    }
+}
+```
+
+Use a generative `if` instead."#
+            }
+            ErrorCode::InvalidInitialValue => {
+                r#"`initial` gives a `state` register its value at startup. It can't be used on other declarations, and the value must fit the type of the register.
+
+```sus
+module m {
+    int x
+    initial x = 3
+}
+```"#
+            }
+            ErrorCode::ExternTypeParameter => {
+                r#"`extern` modules are implemented in SystemVerilog, which can't receive SUS types as parameters. Only value parameters are allowed.
+
+```sus
+extern module ext #(T) {
+    input T x'0
+}
+```
+
+Convert the value to `bool[]` and pass its size as a value parameter instead."#
+            }
+            ErrorCode::ExternPortWithoutLatency => {
+                r#"The compiler can't look inside `extern` and builtin modules to infer their port latencies, so all their ports need an explicit latency specifier.
+
+```sus
+extern module ext {
+    input int x
+    output int y'1
+}
+```
+
+Write `input int x'0`."#
+            }
+            ErrorCode::UnusedVariable => {
+                r#"A variable doesn't affect any output port of its module, so it would be optimized away. This warning often reveals a missing connection.
+
+```sus
+module m {
+    input int x
+    int unused = x + 1
+}
+```
+
+Remove the variable, or use it. The compiler suggests removing it as a fix."#
+            }
+            ErrorCode::NonSynthesizableType => {
+                r#"Some types, like `string`, only exist at compile time. They can't be used for wires, ports or registers, only for `gen` variables.
+
+```sus
+module m {
+    input string s
+}
+```"#
+            }
+            ErrorCode::UnknownLint => {
+                r#"A `// sus:allow(...)`, `// sus:warn(...)` or `// sus:deny(...)` comment names a lint that doesn't exist. `sus_compiler --help` lists the lints.
+
+```sus
+// sus:allow(unused-varaible)
+module m {}
+```"#
+            }
+            ErrorCode::GenerativeExecutionError => {
+                r#"Executing the generative code of a module for a specific instantiation failed. Examples are array indices out of bounds, reading a `gen` variable before it is assigned, and `for` loops whose end is before their start.
+
+```sus
+module m {
+    gen int[3] arr
+    gen int x = arr[5]
+}
+```"#
+            }
+            ErrorCode::NotInstantiated => {
+                r#"A module or one of its submodules has errors, so it isn't instantiated. Fix the errors of the listed modules first."#
+            }
+            ErrorCode::SubmoduleInstantiationFailed => {
+                r#"A submodule could not be instantiated with the template arguments it was given. The errors are reported inside the submodule."#
+            }
+            ErrorCode::DisabledPortUsed => {
+                r#"A port of a submodule is used, but that port is disabled for the template arguments the submodule is instantiated with. Ports can be disabled by generative `if` statements around their declaration.
+
+Check the template arguments of the submodule."#
+            }
+            ErrorCode::UnusedPort => {
+                r#"A port of a submodule is enabled, but never connected by the parent module. An unconnected input is undefined in hardware.
+
+```sus
+module a {
+    input bool x
+    output bool y
+    y = x
+}
+module m {
+    a sub
+}
+```"#
+            }
+            ErrorCode::InvalidConcreteType => {
+                r#"After substituting the template arguments of an instantiation, a type or template argument turned out to be invalid, for example an array with a negative size.
+
+```sus
+module m #(int N) {
+    input bool[N] x
+}
+module top {
+    m #(N: -1) sub
+}
+```"#
+            }
+            ErrorCode::RankMismatch => {
+                r#"Operators work elementwise on arrays, but both sides must have the same array dimensions as the result.
+
+```sus
+module m {
+    input int[3] a
+    input int[4] b
+    output int[3] c
+    c = a + b
+}
+```"#
+            }
+            ErrorCode::PossibleDivisionByZero => {
+                r#"The right side of a division or modulo may be zero, as zero lies within the bounds of its integer type.
+
+```sus
+module m {
+    input int #(FROM: 0, TO: 10) a
+    input int #(FROM: 0, TO: 10) b
+    output int c
+    c = a / b
+}
+```
+
+Restrict the type of the divisor, for example to `int #(FROM: 1, TO: 10)`."#
+            }
+            ErrorCode::NotASubtype => {
+                r#"A value doesn't fit the type it is assigned to, such as an integer whose range exceeds the range of the target.
+
+```sus
+module m {
+    input int #(FROM: 0, TO: 10) a
+    output int #(FROM: 0, TO: 5) b
+    b = a
+}
+```"#
+            }
+            ErrorCode::IndexOutOfBounds => {
+                r#"An array index or slice may lie outside of the array. For runtime indices, the bounds of the integer type of the index must fit in the array.
+
+```sus
+module m {
+    input bool[4] arr
+    input int #(FROM: 0, TO: 8) idx
+    output bool o
+    o = arr[idx]
+}
+```"#
+            }
+            ErrorCode::UnsynchronizedCrossing => {
+                r#"The output of a `CrossDomain` is used in its new domain before it passed through two registers. The first register can go metastable when the signal changes close to a clock edge, the second one gives it a cycle to settle.
+
+```sus
+module m {
+    domain a
+    input bool x
+    domain b
+    output bool y
+    CrossDomain #(T: type bool) cross
+    cross.in = x
+    y = cross.out
+}
+```
+
+Register the crossed signal twice before using it: `reg reg bool synced = cross.out`, or pass it to a synchronizer module that does. `CrossAction` and `CrossActionNoData` don't synchronize their valid signal, and are always reported. Use a `FIFO` for crossing data streams."#
+            }
+            ErrorCode::MultiBitCrossing => {
+                r#"A `CrossDomain` with more than one bit is used without a valid signal that crossed separately. Its bits can each be sampled at a different clock edge, so the destination domain may see a value that never existed in the source domain.
+
+```sus
+module m {
+    domain a
+    input int #(FROM: 0, TO: 16) x
+    domain b
+    output int #(FROM: 0, TO: 16) y
+    CrossDomain #(T: type int #(FROM: 0, TO: 16)) cross
+    cross.in = x
+    y = cross.out
+}
+```
+
+Only use the data under a `when` whose condition comes from a synchronized single-bit `CrossDomain`, and keep the data stable while that valid signal is crossing. Gray-coded counters are an exception, silence this lint for them with `// sus:allow(multi-bit-crossing)`."#
+            }
+            ErrorCode::CombinationalLoop => {
+                r#"A value depends on itself within the same clock cycle, without a register in between. In hardware such a loop never settles, or oscillates. The infos list the loop in order, also through submodules.
+
+```sus
+module m {
+    input bool x
+    output bool y
+    bool a
+    a = !a | x
+    y = a
+}
+```
+
+Latency counting catches loops that add latency, but not loops that stay in a single latency stage. Break the loop with a `reg`, or by making one of its wires `state`."#
+            }
+            ErrorCode::ConstantCondition => {
+                r#"The condition of a `when` is a constant once the template arguments are known, for instance because it was computed from a template argument. Either the code under it never runs, or its `else` branch doesn't. Use `if` for conditions that depend on template arguments, or check that the right argument was passed.
+
+```sus
+module m #(int N) {
+    input int #(FROM: 0, TO: 8) x
+    output int #(FROM: 0, TO: 8) y
+    bool is_three = N == 3
+    y = x
+    when is_three {
+        y = 0
+    }
+}
+
+module top {
+    input int #(FROM: 0, TO: 8) x
+    output int #(FROM: 0, TO: 8) y
+    m #(N: 5) sub
+    sub.x = x
+    y = sub.y
+}
+```"#
+            }
+            ErrorCode::UnreachableHardware => {
+                r#"A declaration doesn't reach an output port, a `state` or a submodule in this instantiation, because the code that reads it is disabled by the template arguments. Declarations that are unused in every instantiation are reported as SUS0409 instead.
+
+```sus
+module m #(bool DOUBLE) {
+    input int #(FROM: 0, TO: 8) a
+    output int #(FROM: 0, TO: 15) y
+    int #(FROM: 0, TO: 15) doubled = a * 2
+    if DOUBLE {
+        y = doubled
+    } else {
+        y = a
+    }
+}
+
+module top {
+    input int #(FROM: 0, TO: 8) a
+    output int #(FROM: 0, TO: 15) y
+    m #(DOUBLE: false) sub
+    sub.a = a
+    y = sub.y
+}
+```"#
+            }
+            ErrorCode::IntTruncation => {
+                r#"The input of an `unsafe_int_cast` can lie outside of the bounds it is cast to. Those values are truncated to the lower bits, which silently wraps them around. The infos show the inferred bounds of the arithmetic that produced the input.
+
+```sus
+module m {
+    input int #(FROM: 0, TO: 16) a
+    input int #(FROM: 0, TO: 16) b
+    output int #(FROM: 0, TO: 16) y
+    y = unsafe_int_cast #(FROM: 0, TO: 16)(a + b)
+}
+```
+
+Narrow the inputs, or use `%` or a comparison to make the wrap-around explicit."#
+            }
+            ErrorCode::IntRangeGrowth => {
+                r#"The left side of a `%` needs more than one bit more than its result. The modulo is correct, but it is expensive in hardware, and often hides arithmetic that grew larger than intended. The infos show the inferred bounds at each step.
+
+```sus
+module m {
+    input int #(FROM: 0, TO: 16) a
+    input int #(FROM: 0, TO: 16) b
+    output int #(FROM: 0, TO: 16) y
+    y = (a * b) % 16
+}
+```"#
+            }
+            ErrorCode::StateWithoutReset => {
+                r#"A `state` has no `initial` value and is not written by a reset, but it controls a `when` condition or an output. Its value is undefined until it is first written: simulators start it at `'x`, while the hardware starts at whatever it powers up with.
+
+```sus
+module m {
+    output bool running'0
+    state bool active
+    action start'0 {
+        active = true
+    }
+    running = active
+}
+```
+
+Give the state a value at startup with `initial active = false`, or write it from an action named like a reset, such as `action rst`."#
+            }
+            ErrorCode::UnreachableLatencyNode => {
+                r#"Latency counting couldn't determine when this value is available, because it isn't connected to any port or value with a known latency."#
+            }
+            ErrorCode::NetPositiveLatencyCycle => {
+                r#"A cycle in the dataflow requires a value to be available later than itself. This happens when a feedback loop contains registers. Feedback loops must go through a `state` register."#
+            }
+            ErrorCode::IndeterminablePortLatency => {
+                r#"The latency of a port can't be determined uniquely, because it could take several values without violating any constraint.
+
+Specify the latency of the port explicitly, like `output int y'2`."#
+            }
+            ErrorCode::PortsNotStronglyConnected => {
+                r#"Latency counting relates the ports of a module through the paths from inputs to outputs. A port that has no such path to the other ports has no defined latency relative to them.
+
+```sus
+module m {
+    input int a
+    output int b
+    input int c
+    b = a
+}
+```
+
+Connect the port, or annotate its absolute latency explicitly, like `input int c'0`."#
+            }
+            ErrorCode::ConflictingSpecifiedLatency => {
+                r#"Two values with explicitly specified latencies are connected by a path with more registers than the difference of their latencies allows.
+
+```sus
+module m {
+    input bool a'0
+    output bool b'0
+    reg b = a
+}
+```
+
+Adjust the specified latency or the number of registers. When the path lands on a whole cycle, the compiler suggests the matching latency as a fix."#
+            }
+            ErrorCode::ImpossibleInitiationInterval => {
+                r#"A `state` feedback loop with a negative `LatencyOffset` sets how many cycles an iteration may take: its initiation interval. The computation in the loop takes more cycles than that.
+
+```sus
+module m {
+    input bool x'0
+    output bool y'2
+    state bool acc
+    // The loop has two registers, but the offset only gives back one cycle
+    LatencyOffset #(T: type bool, OFFSET: -1) lo
+    reg reg acc = lo(acc) ^ x
+    y = acc
+}
+```
+
+Increase the negative offset to allow more cycles per iteration, or remove registers from the loop."#
+            }
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SUS{:04}", self.number())
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    /// Accepts `SUS0123`, `sus0123` and `123`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .get(..3)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("sus"))
+            .map_or(s, |_| &s[3..]);
+        let number: u16 = digits
+            .parse()
+            .map_err(|_| format!("'{s}' is not an error code like SUS0123"))?;
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|code| code.number() == number)
+            .ok_or_else(|| format!("There is no error code {s}"))
+    }
+}
+
+/// Implementation of `sus_compiler --explain`
+pub fn print_explanation(code: ErrorCode) {
+    println!("{code}: {}\n\n{}", code.title(), code.explanation());
+    if let Some(lint_name) = code.lint_name() {
+        if crate::lint_levels::OPT_IN_LINTS.contains(&code) {
+            println!(
+                "\nThis is the lint '{lint_name}'. It is off by default, enable it with `-W {lint_name}` or a `// sus:warn({lint_name})` comment above the module or declaration"
+            );
+        } else {
+            println!(
+                "\nThis is the lint '{lint_name}'. Silence it with `-A {lint_name}` or a `// sus:allow({lint_name})` comment above the module or declaration"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorCode;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_error_codes() {
+        let mut numbers = HashSet::new();
+        for code in ErrorCode::ALL {
+            assert!(numbers.insert(code.number()), "{code} is used twice");
+            assert_eq!(code.to_string().parse(), Ok(*code));
+        }
+        assert_eq!("sus301".parse(), Ok(ErrorCode::TypeMismatch));
+        assert_eq!("301".parse(), Ok(ErrorCode::TypeMismatch));
+        assert!("SUS9999".parse::<ErrorCode>().is_err());
+        assert!("SUSx".parse::<ErrorCode>().is_err());
+    }
+}
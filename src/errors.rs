@@ -28,14 +28,25 @@ pub struct ErrorInfo {
     pub info: String,
 }
 
+/// A fix the compiler proposes for a [CompileError]: replace `position` with `replace_with`.
+///
+/// Always in the same file as the error. Removals have an empty `replace_with`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub position: Span,
+    pub replace_with: String,
+}
+
 /// Represents an error or warning that the compiler produced. They can be shown in the IDE, or on the CLI
 ///
 /// All errors for a single file are stored together, which is why this struct does not contain a FileUUID
 #[derive(Debug, Clone)]
 pub struct CompileError {
     pub position: Span,
+    pub code: ErrorCode,
     pub reason: String,
     pub infos: Vec<ErrorInfo>,
+    pub suggestions: Vec<Suggestion>,
     pub level: ErrorLevel,
 }
 
@@ -90,6 +101,44 @@ impl ErrorStore {
         self.errors.extend_from_slice(&errs.errors);
     }
 
+    /// Changes the level of every error to `level_of(err)`, removing the errors for which it returns [None]
+    pub fn set_levels(&mut self, mut level_of: impl FnMut(&CompileError) -> Option<ErrorLevel>) {
+        self.errors.retain_mut(|err| match level_of(err) {
+            Some(level) => {
+                err.level = level;
+                true
+            }
+            None => false,
+        });
+        self.did_error = self.errors.iter().any(|err| err.level == ErrorLevel::Error);
+    }
+
+    /// All [Suggestion]s of these errors that can be applied together, sorted by position.
+    ///
+    /// Duplicates are removed, and of two overlapping suggestions only the first one is kept
+    pub fn compatible_suggestions(&self) -> Vec<Suggestion> {
+        let mut all: Vec<&Suggestion> = self
+            .errors
+            .iter()
+            .flat_map(|err| err.suggestions.iter())
+            .collect();
+        all.sort_by_key(|s| (s.position.as_range().start, s.position.as_range().end));
+
+        let mut result: Vec<Suggestion> = Vec::new();
+        for s in all {
+            if let Some(prev) = result.last()
+                && (prev == s
+                    || s.position.as_range().start < prev.position.as_range().end
+                    // Two insertions at the same place
+                    || s.position.as_range().start == prev.position.as_range().start)
+            {
+                continue;
+            }
+            result.push(s.clone());
+        }
+        result
+    }
+
     pub fn sort(&mut self) {
         self.errors.sort_by(|a, b| {
             a.position
@@ -173,6 +222,7 @@ impl<'linker> ErrorCollector<'linker> {
     fn push_diagnostic(
         &self,
         position: Span,
+        code: ErrorCode,
         reason: String,
         level: ErrorLevel,
     ) -> ErrorReference<'_> {
@@ -182,24 +232,37 @@ impl<'linker> ErrorCollector<'linker> {
             err_collector: self,
             built_error: Some(CompileError {
                 position,
+                code,
                 reason,
                 infos: Vec::new(),
+                suggestions: Vec::new(),
                 level,
             }),
         }
     }
 
-    pub fn error<S: Into<String>>(&self, position: Span, reason: S) -> ErrorReference<'_> {
-        self.push_diagnostic(position, reason.into(), ErrorLevel::Error)
+    pub fn error<S: Into<String>>(
+        &self,
+        position: Span,
+        code: ErrorCode,
+        reason: S,
+    ) -> ErrorReference<'_> {
+        self.push_diagnostic(position, code, reason.into(), ErrorLevel::Error)
     }
 
-    pub fn warn<S: Into<String>>(&self, position: Span, reason: S) -> ErrorReference<'_> {
-        self.push_diagnostic(position, reason.into(), ErrorLevel::Warning)
+    pub fn warn<S: Into<String>>(
+        &self,
+        position: Span,
+        code: ErrorCode,
+        reason: S,
+    ) -> ErrorReference<'_> {
+        self.push_diagnostic(position, code, reason.into(), ErrorLevel::Warning)
     }
 
     pub fn todo<S: Into<String>>(&self, position: Span, reason: S) -> ErrorReference<'_> {
         self.push_diagnostic(
             position,
+            ErrorCode::NotYetImplemented,
             format!("TODO: {}", reason.into()),
             ErrorLevel::Error,
         )
@@ -214,6 +277,7 @@ impl<'linker> ErrorCollector<'linker> {
     ) -> ErrorReference<'_> {
         self.error(
             position,
+            ErrorCode::TypeMismatch,
             format!("Typecheck error: In {context}, found {found}, but expected {expected}"),
         )
     }
@@ -226,6 +290,7 @@ impl<'linker> ErrorCollector<'linker> {
     ) -> ErrorReference<'_> {
         self.error(
             span,
+            ErrorCode::NotASubtype,
             format!(
                 "Typecheck error: In {context}, found {found}, which is not a subtype of the expected type {expected}"
             ),
@@ -315,14 +380,38 @@ impl ErrorReference<'_> {
             .infos
             .append(&mut info_list);
     }
+    fn add_suggestion(mut self, position: Span, replace_with: String) -> Self {
+        self.err_collector.assert_span_good(position);
+        self.built_error
+            .as_mut()
+            .unwrap()
+            .suggestions
+            .push(Suggestion {
+                position,
+                replace_with,
+            });
+        self
+    }
     pub fn suggest_replace<S: Into<String>>(self, replace_span: Span, replace_with: S) -> Self {
+        let replace_with = replace_with.into();
         self.info_same_file(
             replace_span,
-            format!("SUGGEST: Replace this with \"{}\"", replace_with.into()),
+            format!("SUGGEST: Replace this with \"{replace_with}\""),
         )
+        .add_suggestion(replace_span, replace_with)
     }
     pub fn suggest_remove(self, remove_span: Span) -> Self {
         self.info_same_file(remove_span, "SUGGEST: Remove this")
+            .add_suggestion(remove_span, String::new())
+    }
+    /// `info_span` is shown with the suggestion, because the empty insertion point itself is hard to see
+    pub fn suggest_insert<S: Into<String>>(self, info_span: Span, at: usize, insert: S) -> Self {
+        let insert = insert.into();
+        self.info_same_file(
+            info_span,
+            format!("SUGGEST: Insert \"{}\" after this", insert.trim()),
+        )
+        .add_suggestion(Span::from(at..at), insert)
     }
 }
 
@@ -445,3 +534,47 @@ impl FileKnowingErrorInfoObject for Module {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_err(suggestions: &[(std::ops::Range<usize>, &str)]) -> CompileError {
+        CompileError {
+            position: Span::from(0..1),
+            code: ErrorCode::UnusedVariable,
+            reason: String::new(),
+            infos: Vec::new(),
+            suggestions: suggestions
+                .iter()
+                .map(|(range, replace_with)| Suggestion {
+                    position: Span::from(range.clone()),
+                    replace_with: replace_with.to_string(),
+                })
+                .collect(),
+            level: ErrorLevel::Warning,
+        }
+    }
+
+    #[test]
+    fn test_compatible_suggestions() {
+        let mut store = ErrorStore::new();
+        store.push(mk_err(&[(10..15, "")]));
+        store.push(mk_err(&[(2..4, "x"), (10..15, "")]));
+        store.push(mk_err(&[(12..20, "y"), (15..16, "z")]));
+
+        let positions: Vec<_> = store
+            .compatible_suggestions()
+            .into_iter()
+            .map(|s| (s.position.as_range(), s.replace_with))
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                (2..4, "x".to_string()),
+                (10..15, String::new()),
+                (15..16, "z".to_string())
+            ]
+        );
+    }
+}
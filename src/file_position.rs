@@ -157,6 +157,23 @@ impl FileText {
     }
     /// Errors when byte is outside of file
     pub fn byte_to_linecol(&self, byte_pos: usize) -> LineCol {
+        self.byte_to_linecol_counting(byte_pos, |_| 1)
+    }
+    /// Clamps the linecol to be within the file, so cannot error.
+    pub fn linecol_to_byte_clamp(&self, linecol: LineCol) -> usize {
+        self.linecol_to_byte_clamp_counting(linecol, |_| 1)
+    }
+    /// Like [Self::byte_to_linecol], but the column counts UTF-16 code units, like LSP positions do
+    pub fn byte_to_utf16_linecol(&self, byte_pos: usize) -> LineCol {
+        self.byte_to_linecol_counting(byte_pos, char::len_utf16)
+    }
+    /// Like [Self::linecol_to_byte_clamp], but the column counts UTF-16 code units, like LSP positions do.
+    /// A column in the middle of a surrogate pair is moved to the end of its character
+    pub fn utf16_linecol_to_byte_clamp(&self, linecol: LineCol) -> usize {
+        self.linecol_to_byte_clamp_counting(linecol, char::len_utf16)
+    }
+    /// `col_width` is the number of columns each character takes up
+    fn byte_to_linecol_counting(&self, byte_pos: usize, col_width: fn(char) -> usize) -> LineCol {
         assert!(byte_pos <= self.file_text.len());
         let line = match self.lines_start_at.binary_search(&byte_pos) {
             Ok(exact_newline) => exact_newline,
@@ -166,11 +183,14 @@ impl FileText {
 
         LineCol {
             line,
-            col: text_before.chars().count(),
+            col: text_before.chars().map(col_width).sum(),
         }
     }
-    /// Clamps the linecol to be within the file, so cannot error.
-    pub fn linecol_to_byte_clamp(&self, linecol: LineCol) -> usize {
+    fn linecol_to_byte_clamp_counting(
+        &self,
+        linecol: LineCol,
+        col_width: fn(char) -> usize,
+    ) -> usize {
         let line_end = match (linecol.line + 1).cmp(&self.lines_start_at.len()) {
             std::cmp::Ordering::Less => self.lines_start_at[linecol.line + 1] - 1,
             std::cmp::Ordering::Equal => self.file_text.len(),
@@ -180,12 +200,11 @@ impl FileText {
         let line_text = &self.file_text[line_start..line_end];
 
         let mut cols_left = linecol.col;
-        let mut char_indices = line_text.char_indices();
-        for (byte, _) in &mut char_indices {
+        for (byte, c) in line_text.char_indices() {
             if cols_left == 0 {
                 return line_start + byte;
             }
-            cols_left -= 1;
+            cols_left = cols_left.saturating_sub(col_width(c));
         }
         line_end
     }
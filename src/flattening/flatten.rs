@@ -1,10 +1,13 @@
 use std::cell::OnceCell;
+use std::collections::HashSet;
 use std::num::NonZeroU16;
 
 use crate::alloc::{ArenaAllocator, UUID, UUIDRange};
 
+use crate::latency::try_compute_domain_time_bases;
 use crate::linker::passes::{GlobalResolver, LinkerPass};
 use crate::prelude::*;
+use crate::util::gcd;
 
 use ibig::{IBig, UBig};
 use ordered_float::NotNan;
@@ -150,12 +153,17 @@ struct FlatteningContext<'l, 'errs> {
 // Otherwise clippy reports silly things like kind!("number") | kind!("float") | kind!("bool_array_literal") as "make this a range" errors
 #[allow(clippy::manual_range_patterns)]
 impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
-    fn flatten_latency_specifier(&mut self, cursor: &mut Cursor<'c>) -> Option<(FlatID, Span)> {
+    /// Returns the latency expression, its span, and the span of the whole specifier including the `'`
+    fn flatten_latency_specifier(
+        &mut self,
+        cursor: &mut Cursor<'c>,
+    ) -> Option<(FlatID, Span, Span)> {
         cursor.optional_field(field!("latency_specifier")).then(|| {
+            let specifier_span = cursor.span();
             cursor.go_down_content(kind!("latency_specifier"), |cursor| {
                 let expr = self.flatten_subexpr(cursor);
                 let span = cursor.span();
-                (expr, span)
+                (expr, span, specifier_span)
             })
         })
     }
@@ -193,22 +201,22 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                             Some(TemplateKind::Value(wire_read_id))
                         }
                         Some(NamedLocal::SubModule(sm)) => {
-                            self.errors.error(name_span, format!("{name} does not name a Type or a Value. Local submodules are not allowed!"))
+                            self.errors.error(name_span, ErrorCode::WrongKindOfName, format!("{name} does not name a Type or a Value. Local submodules are not allowed!"))
                                 .info_obj_same_file(self.instructions[sm].unwrap_submodule());
                             None
                         }
                         Some(NamedLocal::DomainDecl(dom)) => {
-                            self.errors.error(name_span, format!("{name} does not name a Type or a Value. Domains are not allowed!"))
+                            self.errors.error(name_span, ErrorCode::WrongKindOfName, format!("{name} does not name a Type or a Value. Domains are not allowed!"))
                                 .info_obj_same_file(&self.domains[dom]);
                             None
                         }
                         Some(NamedLocal::LocalInterface(interf)) => {
-                            self.errors.error(name_span, format!("{name} does not name a Type or a Value. Local Interfaces are not allowed!"))
+                            self.errors.error(name_span, ErrorCode::WrongKindOfName, format!("{name} does not name a Type or a Value. Local Interfaces are not allowed!"))
                                 .info_obj_same_file(self.instructions[interf].unwrap_interface());
                             None
                         }
                         None => {
-                            self.errors.error(name_span, format!("{name} does not name a Type or a Value."));
+                            self.errors.error(name_span, ErrorCode::UnknownName, format!("{name} does not name a Type or a Value."));
                             None
                         },
                     }, name_span)
@@ -338,6 +346,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                         self.errors
                             .error(
                                 span,
+                                ErrorCode::WrongKindOfName,
                                 format!(
                                     "This is not a {accepted_text}, it is a local variable instead!"
                                 ),
@@ -350,6 +359,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                         self.errors
                             .error(
                                 span,
+                                ErrorCode::WrongKindOfName,
                                 format!("This is not a {accepted_text}, it is a domain instead!"),
                             )
                             .info_obj_same_file(&self.domains[domain_id]);
@@ -403,6 +413,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
         {
             let err_ref = self.errors.error(
                 name_span,
+                ErrorCode::DuplicateDeclaration,
                 "This declaration conflicts with a previous declaration in the same scope",
             );
 
@@ -428,8 +439,11 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
 
     fn forbid_keyword(&self, kw_span: Option<Span>, context: &str) {
         if let Some(kw_span) = kw_span {
-            self.errors
-                .error(kw_span, format!("This can't be used {context}"));
+            self.errors.error(
+                kw_span,
+                ErrorCode::MisplacedKeyword,
+                format!("This can't be used {context}"),
+            );
         }
     }
 
@@ -460,7 +474,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                     };
                     if let Some(prev_span) = *selected_kw {
                         self.errors
-                            .error(span, "Duplicate keyword!")
+                            .error(span, ErrorCode::MisplacedKeyword, "Duplicate keyword!")
                             .info_same_file(prev_span, "Previously used here");
                     }
                     *selected_kw = Some(span);
@@ -473,7 +487,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
             let (name_span, name) = cursor.field_span(field!("name"), kind!("identifier"));
 
             let span_latency_specifier = self.flatten_latency_specifier(cursor);
-            let latency_specifier = span_latency_specifier.map(|(ls, _)| ls);
+            let latency_specifier = span_latency_specifier.map(|(ls, _, _)| ls);
             // Parsing components done
 
             let documentation = cursor.extract_gathered_comments();
@@ -484,9 +498,14 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                 ModuleOrWrittenType::WrittenType(typ) => typ,
                 ModuleOrWrittenType::Module(module_ref) => {
                     assert!(ALLOW_MODULES);
-                    if let Some((_, span)) = span_latency_specifier {
+                    if let Some((_, span, specifier_span)) = span_latency_specifier {
                         self.errors
-                            .error(span, "Cannot add latency specifier to module instances");
+                            .error(
+                                span,
+                                ErrorCode::MisplacedLatencySpecifier,
+                                "Cannot add latency specifier to module instances",
+                            )
+                            .suggest_remove(specifier_span);
                     }
 
                     let new_submod = SubModuleInstance {
@@ -744,6 +763,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                             let from = from.unwrap_or_else(|| {
                                 self.errors.error(
                                     bracket_span.inner_span().empty_span_at_front(),
+                                    ErrorCode::IncompletePartSelect,
                                     "Missing indexed part-select slices start index",
                                 );
 
@@ -752,6 +772,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                             let width = to.unwrap_or_else(|| {
                                 self.errors.error(
                                     bracket_span.inner_span().empty_span_at_front(),
+                                    ErrorCode::IncompletePartSelect,
                                     "Missing indexed part-select slices width",
                                 );
 
@@ -807,7 +828,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                 true,
             ) => ExpressionOutput::MultiWrite(Vec::new()),
             (_, true) => {
-                self.errors.warn(span, "The result of this expression is not used. Only function calls can return nothing. ");
+                self.errors.warn(span, ErrorCode::UnusedExpressionResult, "The result of this expression is not used. Only function calls can return nothing. ");
                 ExpressionOutput::SubExpression(TyCell::new())
             }
         };
@@ -943,7 +964,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
             }
         }
         if specified_size < minimum_non_truncating_size {
-            self.errors.warn(expr_span, format!("Truncating ones in this boolean array literal! Last '1' bit occurs at position {} but specified size is {specified_size}", minimum_non_truncating_size - 1));
+            self.errors.warn(expr_span, ErrorCode::TruncatedLiteral, format!("Truncating ones in this boolean array literal! Last '1' bit occurs at position {} but specified size is {specified_size}", minimum_non_truncating_size - 1));
         }
         bools.resize(specified_size, Value::Bool(false));
         Ok(ExpressionSource::Literal(Value::Array(bools)))
@@ -974,15 +995,19 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                 if let Some(escaped) = unescape::unescape(text) {
                     ExpressionSource::Literal(Value::String(escaped))
                 } else {
-                    self.errors
-                        .error(expr_span, "Invalid escape sequence in string!");
+                    self.errors.error(
+                        expr_span,
+                        ErrorCode::InvalidLiteral,
+                        "Invalid escape sequence in string!",
+                    );
                     ExpressionSource::WireRef(self.new_error(expr_span))
                 }
             }
             kind!("bool_array_literal") => match self.parse_bool_array_literal(cursor, expr_span) {
                 Ok(v) => v,
                 Err((err_span, err_reason)) => {
-                    self.errors.error(err_span, err_reason);
+                    self.errors
+                        .error(err_span, ErrorCode::InvalidLiteral, err_reason);
                     ExpressionSource::WireRef(self.new_error(expr_span))
                 }
             },
@@ -1111,6 +1136,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                             self.errors
                                 .error(
                                     span,
+                                    ErrorCode::WrongKindOfName,
                                     format!(
                                         "Expected a value, but instead found template type '{}'",
                                         self.parameters[template_id].name
@@ -1124,6 +1150,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                             self.errors
                                 .error(
                                     span,
+                                    ErrorCode::WrongKindOfName,
                                     format!(
                                         "Expected a value, but instead found domain '{}'",
                                         domain.name
@@ -1192,25 +1219,33 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
                 wire_ref
             }),
             kind!("number") | kind!("float") | kind!("bool_array_literal") | kind!("string") => {
-                self.errors
-                    .error(expr_span, "A constant is not a wire reference");
+                self.errors.error(
+                    expr_span,
+                    ErrorCode::NotAWireReference,
+                    "A constant is not a wire reference",
+                );
                 self.new_error(expr_span)
             }
             kind!("unary_op") | kind!("binary_op") => {
                 self.errors.error(
                     expr_span,
+                    ErrorCode::NotAWireReference,
                     "The result of an operator is not a wire reference",
                 );
                 self.new_error(expr_span)
             }
             kind!("func_call") => {
-                self.errors
-                    .error(expr_span, "A submodule call is not a wire reference");
+                self.errors.error(
+                    expr_span,
+                    ErrorCode::NotAWireReference,
+                    "A submodule call is not a wire reference",
+                );
                 self.new_error(expr_span)
             }
             kind!("parenthesis_expression") => {
                 self.errors.error(
                     expr_span,
+                    ErrorCode::NotAWireReference,
                     "Parentheses are not allowed within a wire reference",
                 );
                 self.new_error(expr_span)
@@ -1218,6 +1253,7 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
             kind!("array_list_expression") => {
                 self.errors.error(
                     expr_span,
+                    ErrorCode::NotAWireReference,
                     "array literals are not allowed within a wire reference",
                 );
                 self.new_error(expr_span)
@@ -1481,9 +1517,9 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
 
         let (name_span, name) = cursor.field_span(field!("name"), kind!("identifier"));
         let parsed_latency_specifier = self.flatten_latency_specifier(cursor);
-        let latency_specifier = parsed_latency_specifier.map(|(l, _)| l);
+        let latency_specifier = parsed_latency_specifier.map(|(l, _, _)| l);
 
-        let interface_decl_span = if let Some((_, span)) = parsed_latency_specifier {
+        let interface_decl_span = if let Some((_, span, _)) = parsed_latency_specifier {
             Span::new_overarching(interface_kw_span, span)
         } else {
             Span::new_overarching(interface_kw_span, name_span)
@@ -1590,21 +1626,32 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
 
                     match interface_kind {
                         InterfaceKind::RegularInterface => {
-                            if let Some((_, lat_spec_span)) = parsed_latency_specifier {
-                                slf.errors.error(
-                                    lat_spec_span,
-                                    "Can only add latency specifiers to actions or triggers",
-                                );
+                            if let Some((_, lat_spec_span, specifier_span)) =
+                                parsed_latency_specifier
+                            {
+                                slf.errors
+                                    .error(
+                                        lat_spec_span,
+                                        ErrorCode::MisplacedLatencySpecifier,
+                                        "Can only add latency specifiers to actions or triggers",
+                                    )
+                                    .suggest_remove(specifier_span);
                             }
                             if let Some(else_span) = else_span {
-                                slf.errors
-                                    .error(else_span, "Regular interfaces cannot take else blocks");
+                                slf.errors.error(
+                                    else_span,
+                                    ErrorCode::InvalidInterfaceBlock,
+                                    "Regular interfaces cannot take else blocks",
+                                );
                             }
                         }
                         InterfaceKind::Action(_) => {
                             if then_span.is_none() {
-                                slf.errors
-                                    .error(interface_kw_span, "An action requires a block");
+                                slf.errors.error(
+                                    interface_kw_span,
+                                    ErrorCode::InvalidInterfaceBlock,
+                                    "An action requires a block",
+                                );
                             }
                         }
                         InterfaceKind::Trigger(_) => {}
@@ -1621,13 +1668,27 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
             && let Some(existing_port) = self.ports.iter().next()
         {
             // Sad Path: Having ports on the implicit clk domain is not allowed.
-            self.errors.error(domain_name_span, "When using explicit domains, no port is allowed to be declared on the implicit 'clk' domain.")
+            self.errors.error(domain_name_span, ErrorCode::ImplicitClockPort, "When using explicit domains, no port is allowed to be declared on the implicit 'clk' domain.")
                         .info_same_file(existing_port.1.decl_span, "A domain should be explicitly defined before this port");
         }
+        let clock_ratio = if cursor.optional_field(field!("assign_value")) {
+            self.flatten_clock_ratio(cursor)
+        } else {
+            None
+        };
         let domain_id = self.domains.alloc(DomainInfo {
             name: domain_name.to_owned(),
             name_span: Some(domain_name_span),
+            clock_ratio,
         });
+        if try_compute_domain_time_bases(&self.domains).is_err() {
+            let ratio = self.domains[domain_id].clock_ratio.take().unwrap();
+            self.errors.error(
+                ratio.span,
+                ErrorCode::InvalidClockRatio,
+                "This clock ratio is too extreme to be related to the other domains in a common time base",
+            );
+        }
         self.current_domain = domain_id;
 
         self.alloc_local_name(
@@ -1637,6 +1698,127 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
         );
     }
 
+    /// Parses the right hand side of `domain fast_clk = 2 * clk`. Only integer products and quotients of a single earlier domain are allowed.
+    fn flatten_clock_ratio(&mut self, cursor: &mut Cursor<'c>) -> Option<ClockRatio> {
+        let span = cursor.span();
+        let (numerator, denominator, base) = self.flatten_clock_ratio_factor(cursor)?;
+        let Some(base) = base else {
+            self.errors.error(
+                span,
+                ErrorCode::InvalidClockRatio,
+                "A clock ratio must refer to a previously declared domain, like `2 * clk`",
+            );
+            return None;
+        };
+        if numerator == 0 || denominator == 0 {
+            self.errors.error(
+                span,
+                ErrorCode::InvalidClockRatio,
+                "A clock ratio must be strictly positive",
+            );
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        Some(ClockRatio {
+            base,
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+            span,
+        })
+    }
+
+    fn flatten_clock_ratio_factor(
+        &mut self,
+        cursor: &mut Cursor<'c>,
+    ) -> Option<(u64, u64, Option<DomainID>)> {
+        let (kind, span) = cursor.kind_span();
+        match kind {
+            kind!("number") => {
+                let text = cursor.file_data.file_text[span].replace('_', "");
+                let Ok(value) = text.parse::<u64>() else {
+                    self.errors
+                        .error(span, ErrorCode::InvalidClockRatio, "This clock ratio factor is too large");
+                    return None;
+                };
+                Some((value, 1, None))
+            }
+            kind!("template_global") => match self.flatten_local_or_template_global(cursor) {
+                LocalOrGlobal::Local(_, NamedLocal::DomainDecl(domain_id)) => {
+                    Some((1, 1, Some(domain_id)))
+                }
+                LocalOrGlobal::NotFound(_) => None,
+                _ => {
+                    self.errors
+                        .error(span, ErrorCode::InvalidClockRatio, "Expected a domain or an integer in this clock ratio");
+                    None
+                }
+            },
+            kind!("parenthesis_expression") => cursor.go_down_content(kind, |cursor| {
+                self.flatten_clock_ratio_factor(cursor)
+            }),
+            kind!("binary_op") => cursor.go_down_no_check(|cursor| {
+                cursor.field(field!("left"));
+                let left = self.flatten_clock_ratio_factor(cursor);
+
+                cursor.field(field!("operator"));
+                let op_kind = cursor.kind();
+
+                cursor.field(field!("right"));
+                let right_span = cursor.span();
+                let right = self.flatten_clock_ratio_factor(cursor);
+
+                let (l_num, l_den, l_base) = left?;
+                let (r_num, r_den, r_base) = right?;
+                match op_kind {
+                    kw!("*") => {
+                        if let (Some(_), Some(_)) = (l_base, r_base) {
+                            self.errors
+                                .error(span, ErrorCode::InvalidClockRatio, "A clock ratio can only refer to a single domain");
+                            return None;
+                        }
+                        let (Some(num), Some(den)) =
+                            (l_num.checked_mul(r_num), l_den.checked_mul(r_den))
+                        else {
+                            self.errors
+                                .error(span, ErrorCode::InvalidClockRatio, "This clock ratio is too large");
+                            return None;
+                        };
+                        Some((num, den, l_base.or(r_base)))
+                    }
+                    kw!("/") => {
+                        if r_base.is_some() {
+                            self.errors
+                                .error(right_span, ErrorCode::InvalidClockRatio, "Cannot divide by a domain in a clock ratio");
+                            return None;
+                        }
+                        let (Some(num), Some(den)) =
+                            (l_num.checked_mul(r_den), l_den.checked_mul(r_num))
+                        else {
+                            self.errors
+                                .error(span, ErrorCode::InvalidClockRatio, "This clock ratio is too large");
+                            return None;
+                        };
+                        Some((num, den, l_base))
+                    }
+                    _ => {
+                        self.errors.error(
+                            span, ErrorCode::InvalidClockRatio,
+                            "Only '*' and '/' are allowed in a clock ratio, like `2 * clk` or `clk / 2`",
+                        );
+                        None
+                    }
+                }
+            }),
+            _ => {
+                self.errors.error(
+                    span, ErrorCode::InvalidClockRatio,
+                    "A clock ratio must be of the form `2 * clk` or `clk / 2`",
+                );
+                None
+            }
+        }
+    }
+
     fn flatten_write_modifiers(&self, cursor: &mut Cursor<'c>) -> WriteModifiers {
         if cursor.optional_field(field!("write_modifiers")) {
             let modifiers_span = cursor.span();
@@ -1713,12 +1895,12 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
         cursor.list(kind!("assign_left_side"), |cursor| {
             cursor.go_down(kind!("assign_to"), |cursor| {
                 if !is_first_item {
-                    self.errors.warn(cursor.span(), "Standalone declarations and expressions should be on their own line.");
+                    self.errors.warn(cursor.span(), ErrorCode::MultipleStatementsOnLine, "Standalone declarations and expressions should be on their own line.");
                 }
                 is_first_item = false;
 
                 if let Some(span) = cursor.optional_field_span(field!("write_modifiers"), kind!("write_modifiers")) {
-                    self.errors.error(span, "No write modifiers are allowed on non-assigned to declarations or expressions");
+                    self.errors.error(span, ErrorCode::MisplacedWriteModifier, "No write modifiers are allowed on non-assigned to declarations or expressions");
                 }
 
                 cursor.field(field!("expr_or_decl"));
@@ -1820,6 +2002,8 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
         name: &'c str,
         name_span: Span,
     ) {
+        // The global's own doc comment belongs to its LinkInfo, not to its first template parameter
+        cursor.clear_gathered_comments();
         if cursor.optional_field(field!("template_declaration_arguments")) {
             cursor.list(
                 kind!("template_declaration_arguments"),
@@ -1903,10 +2087,19 @@ impl<'l, 'c: 'l> FlatteningContext<'l, '_> {
 /// Flattens all globals in the project.
 ///
 /// Requires that first, all globals have been initialized.
-pub fn flatten_all_globals(linker: &mut Linker) {
+/// Flattens the globals in `global_ids`, which must have been reset to [crate::linker::checkpoint::AFTER_INITIAL_PARSE_CP]
+pub fn flatten_globals(linker: &mut Linker, global_ids: &[GlobalUUID]) {
+    let to_flatten: HashSet<GlobalUUID> = global_ids.iter().copied().collect();
     let linker_files: *const ArenaAllocator<FileData, FileUUIDMarker> = &linker.files;
     // SAFETY we won't be touching the files anywere. This is just to get the compiler to stop complaining about linker going into the closure.
     for (_file_id, file) in unsafe { &*linker_files } {
+        if !file
+            .associated_values
+            .iter()
+            .any(|global| to_flatten.contains(global))
+        {
+            continue;
+        }
         let Ok(mut cursor) = Cursor::new_at_root(file) else {
             assert!(file.associated_values.is_empty());
             continue; // Error already handled in initialization
@@ -1919,6 +2112,9 @@ pub fn flatten_all_globals(linker: &mut Linker) {
                 let global_obj = *associated_value_iter
                     .next()
                     .expect("Iterator cannot be exhausted");
+                if !to_flatten.contains(&global_obj) {
+                    return;
+                }
 
                 linker.pass("Flattening", global_obj, |pass, errors, _files| {
                     flatten_global(pass, errors, cursor);
@@ -1986,6 +2182,7 @@ fn flatten_global(pass: &mut LinkerPass, errors: &ErrorCollector, cursor: &mut C
                 domains.alloc(DomainInfo {
                     name: "clk".to_string(),
                     name_span: None,
+                    clock_ratio: None,
                 });
             }
             md.domains = domains;
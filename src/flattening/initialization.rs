@@ -16,9 +16,11 @@ pub fn gather_initial_file_data(mut builder: FileBuilder) {
     let mut cursor = match Cursor::new_at_root(builder.file_data) {
         Ok(cursor) => cursor,
         Err(file_span) => {
-            builder
-                .other_parsing_errors
-                .error(file_span, "An ERROR node at the root of the syntax tree!");
+            builder.other_parsing_errors.error(
+                file_span,
+                ErrorCode::SyntaxError,
+                "An ERROR node at the root of the syntax tree!",
+            );
 
             return;
         }
@@ -13,7 +13,7 @@ use crate::typing::domain_type::DomainType;
 use std::cell::{Cell, OnceCell};
 
 use crate::latency::port_latency_inference::PortLatencyInferenceInfo;
-pub use flatten::flatten_all_globals;
+pub use flatten::flatten_globals;
 pub use initialization::gather_initial_file_data;
 
 use crate::linker::{Documentation, LinkInfo};
@@ -145,13 +145,27 @@ pub struct NamedConstant {
 
 /// Information about a (clock) domain.
 ///
-/// Right now this only contains the domain name, but when actual clock domains are implemented (#7),
+/// Right now this only contains the domain name and an optional [ClockRatio], but when actual clock domains are implemented (#7),
 /// this will contain information about the Clock.
 #[derive(Debug, Clone)]
 pub struct DomainInfo {
     pub name: String,
     /// May be [None] for the default `clk` domain
     pub name_span: Option<Span>,
+    /// Set for synchronous domains declared as `domain fast_clk = 2 * clk`
+    pub clock_ratio: Option<ClockRatio>,
+}
+
+/// The frequency of a domain relative to a previously declared domain.
+///
+/// `domain fast_clk = 2 * clk` gives `fast_clk` a frequency of `numerator / denominator` times that of `clk`.
+/// Domains related by [ClockRatio]s are synchronous, so latency counting can relate them in a common time base.
+#[derive(Debug, Clone, Copy)]
+pub struct ClockRatio {
+    pub base: DomainID,
+    pub numerator: u64,
+    pub denominator: u64,
+    pub span: Span,
 }
 
 /// With this struct, we convert the domains of a submodule, to their connecting domains in the containing module
@@ -785,7 +799,7 @@ impl<ID: Copy> GlobalReference<ID> {
                     (TemplateKind::Value(_), Some(TemplateKind::Type(_))) => {
                         errors
                             .error(
-                                arg.name_span,
+                                arg.name_span, ErrorCode::InvalidTemplateArgument,
                                 format!(
                                 "'{name}' is not a value. `type` keyword cannot be used for values"
                             ),
@@ -794,7 +808,7 @@ impl<ID: Copy> GlobalReference<ID> {
                     }
                     (TemplateKind::Type(_), Some(TemplateKind::Value(_))) => {
                         errors
-                            .error(arg.name_span, format!("'{name}' is not a type. To use template type arguments use the `type` keyword like `T: type int[123]`"))
+                            .error(arg.name_span, ErrorCode::InvalidTemplateArgument, format!("'{name}' is not a type. To use template type arguments use the `type` keyword like `T: type int[123]`"))
                             .info((param.name_span, target.file), "Declared here");
                     }
                     _ => {}
@@ -804,6 +818,7 @@ impl<ID: Copy> GlobalReference<ID> {
                     errors
                         .error(
                             arg.name_span,
+                            ErrorCode::InvalidTemplateArgument,
                             format!("'{name}' has already been defined previously"),
                         )
                         .info_same_file(prev_use, format!("'{name}' specified here previously"));
@@ -814,6 +829,7 @@ impl<ID: Copy> GlobalReference<ID> {
                 errors
                     .error(
                         arg.name_span,
+                        ErrorCode::InvalidTemplateArgument,
                         format!("'{name}' is not a valid template argument of {full_object_name}"),
                     )
                     .info_obj(target);
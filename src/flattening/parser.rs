@@ -339,6 +339,7 @@ impl<'t> Cursor<'t> {
             errors
                 .error(
                     span,
+                    ErrorCode::SyntaxError,
                     format!(
                         "While parsing '{parent_node_name}', parser found a {error_type} {of_name}"
                     ),
@@ -163,7 +163,7 @@ impl<'l> TypeCheckingContext<'l> {
                             let expr_domain = (expr.domain, expr.span);
                             if expr_domain.0 != DomainType::Generative {
                                 if target_domain.0 == DomainType::Generative {
-                                    self.errors.error(expr_domain.1, "Attempting to write from a non-generative value to a generative value").info_same_file(target_domain.1, "This is a generative value");
+                                    self.errors.error(expr_domain.1, ErrorCode::RuntimeValueInGenerativeContext, "Attempting to write from a non-generative value to a generative value").info_same_file(target_domain.1, "This is a generative value");
                                 } else {
                                     self.unify_physicals(target_domain, expr_domain, "assignment");
                                 }
@@ -172,7 +172,7 @@ impl<'l> TypeCheckingContext<'l> {
                         let expr_domain = (expression.domain.get(), expression.span);
                         if expr_domain.0 != DomainType::Generative {
                             if target_domain.0 == DomainType::Generative {
-                                self.errors.error(expr_domain.1, "Attempting to write from a non-generative value to a generative value").info_same_file(target_domain.1, "This is a generative value");
+                                self.errors.error(expr_domain.1, ErrorCode::RuntimeValueInGenerativeContext, "Attempting to write from a non-generative value to a generative value").info_same_file(target_domain.1, "This is a generative value");
                             } else {
                                 self.unify_physicals(target_domain, expr_domain, "assignment");
                             }
@@ -190,12 +190,14 @@ impl<'l> TypeCheckingContext<'l> {
                     (true, false) => {
                         self.errors.error(
                             if_statement.if_keyword_span,
+                            ErrorCode::IfWhenMismatch,
                             "Used 'if' in a non generative context, use 'when' instead",
                         );
                     }
                     (false, true) => {
                         self.errors.error(
                             if_statement.if_keyword_span,
+                            ErrorCode::IfWhenMismatch,
                             "Used 'when' in a generative context, use 'if' instead",
                         );
                     }
@@ -337,6 +339,7 @@ impl<'l> TypeCheckingContext<'l> {
         if expr.domain != DomainType::Generative {
             self.errors.error(
                 expr.span,
+                ErrorCode::RuntimeValueInGenerativeContext,
                 format!("{context} must be a compile-time expression"),
             );
         }
@@ -50,7 +50,11 @@ impl LintContext<'_> {
                 let decl = self.working_on.instructions[*decl_id].unwrap_declaration();
                 if is_writing_to && decl.decl_kind.is_read_only() {
                     self.errors
-                        .error(wire_ref.root_span, format!("'{}' is read-only", decl.name))
+                        .error(
+                            wire_ref.root_span,
+                            ErrorCode::WriteToReadOnly,
+                            format!("'{}' is read-only", decl.name),
+                        )
                         .info_obj_same_file(decl);
                 }
             }
@@ -82,12 +86,20 @@ impl LintContext<'_> {
                                         (true, Direction::Input) | (false, Direction::Output) => {}
                                         (true, Direction::Output) => {
                                             self.errors
-                                                .error(*name_span, "Cannot write to an output port")
+                                                .error(
+                                                    *name_span,
+                                                    ErrorCode::WriteToReadOnly,
+                                                    "Cannot write to an output port",
+                                                )
                                                 .info_obj(&module_port_decl);
                                         }
                                         (false, Direction::Input) => {
                                             self.errors
-                                                .error(*name_span, "Cannot read from an input port")
+                                                .error(
+                                                    *name_span,
+                                                    ErrorCode::ReadFromInputPort,
+                                                    "Cannot read from an input port",
+                                                )
                                                 .info_obj(&module_port_decl);
                                         }
                                     }
@@ -101,13 +113,17 @@ impl LintContext<'_> {
             WireReferenceRoot::NamedConstant(cst) => {
                 if is_writing_to {
                     self.errors
-                        .error(cst.name_span, "Cannot write to a global constant!")
+                        .error(
+                            cst.name_span,
+                            ErrorCode::WriteToReadOnly,
+                            "Cannot write to a global constant!",
+                        )
                         .info_obj(&self.globals.get_constant(cst.id).link_info);
                 }
             }
             WireReferenceRoot::NamedModule(_global_md) => {
                 if let Some(first_path_elem) = wire_ref.path.first() {
-                    self.errors.error(first_path_elem.get_span(), "Cannot perform any accesses on an inline declared module. Declare it on a separate line!");
+                    self.errors.error(first_path_elem.get_span(), ErrorCode::AccessOnInlineModule, "Cannot perform any accesses on an inline declared module. Declare it on a separate line!");
                 }
             }
             WireReferenceRoot::Error => {}
@@ -116,7 +132,7 @@ impl LintContext<'_> {
     fn cant_be_interface(&self, operation: &'static str, wire_ref: &WireReference) {
         match &wire_ref.output_typ.inner {
             AbstractInnerType::Interface(_, _) | AbstractInnerType::LocalInterface(_) => {
-                self.errors.error(wire_ref.get_total_span(), format!("Can't {operation} an interface. Use a function call or interface connector instead"));
+                self.errors.error(wire_ref.get_total_span(), ErrorCode::InterfaceUsedAsValue, format!("Can't {operation} an interface. Use a function call or interface connector instead"));
             }
             AbstractInnerType::Template(_)
             | AbstractInnerType::Named(_)
@@ -170,7 +186,7 @@ impl LintContext<'_> {
                         let to_decl = self.working_on.instructions[root_flat].unwrap_declaration();
 
                         if *parent_condition != to_decl.parent_condition {
-                            let mut err_ref = self.errors.error(wr.to_span, "Cannot write to compiletime variable through runtime 'when' blocks");
+                            let mut err_ref = self.errors.error(wr.to_span, ErrorCode::GenerativeWriteInWhen, "Cannot write to compiletime variable through runtime 'when' blocks");
                             err_ref = err_ref.info_obj_same_file(decl);
 
                             let mut cur = *parent_condition;
@@ -213,7 +229,7 @@ impl LintContext<'_> {
                 if decl.decl_kind.is_generative() {
                     self.errors
                         .error(
-                            initial_kw_span,
+                            initial_kw_span, ErrorCode::InvalidInitialValue,
                             "'initial' cannot be used with generative variables! Just assign a generative value as normal",
                         )
                         .info_obj_same_file(decl);
@@ -223,6 +239,7 @@ impl LintContext<'_> {
                     self.errors
                         .error(
                             initial_kw_span,
+                            ErrorCode::InvalidInitialValue,
                             "Initial values can only be given to state registers",
                         )
                         .info_obj_same_file(decl);
@@ -240,6 +257,7 @@ impl LintContext<'_> {
             if let TemplateKind::Type(_) = &arg.kind {
                 self.errors.error(
                     arg.name_span,
+                    ErrorCode::ExternTypeParameter,
                     "'extern' modules may not have 'type' arguments. Convert to bool[] first",
                 );
             }
@@ -260,6 +278,7 @@ impl LintContext<'_> {
             if instr.get_latency_specifier().is_none() {
                 self.errors.error(
                     instr.get_span(),
+                    ErrorCode::ExternPortWithoutLatency,
                     format!(
                         "In {} modules all ports must have a specified latency!",
                         self.working_on.is_extern
@@ -340,11 +359,9 @@ impl LintContext<'_> {
                         wire_to_explore_queue.push(instr_id);
                     }
                 }
-                Instruction::Declaration(decl) => {
-                    if decl.name.starts_with('_') {
-                        is_instance_used_map[instr_id] = true;
-                        wire_to_explore_queue.push(instr_id);
-                    }
+                Instruction::Declaration(decl) if decl.name.starts_with('_') => {
+                    is_instance_used_map[instr_id] = true;
+                    wire_to_explore_queue.push(instr_id);
                 }
                 _ => {}
             }
@@ -364,9 +381,84 @@ impl LintContext<'_> {
             if !is_instance_used_map[id]
                 && let Instruction::Declaration(decl) = inst
             {
-                self.errors.warn(decl.name_span, "Unused Variable: This variable does not affect the output ports of this module");
+                let err = self.errors.warn(decl.name_span, ErrorCode::UnusedVariable, "Unused Variable: This variable does not affect the output ports of this module");
+                if let Some(line_span) =
+                    self.removable_declaration_line(id, decl, &instruction_fanins)
+                {
+                    err.suggest_remove(line_span);
+                }
+            }
+        }
+    }
+    /// The line of an unused declaration, if removing it can't break anything else.
+    ///
+    /// That is: nothing reads it, it isn't a port or loop variable, and it is the only thing on its line.
+    /// It may be initialized on the same line, like `int a = x + 1`, as long as no function is called, those could have side effects
+    fn removable_declaration_line(
+        &self,
+        decl_id: FlatID,
+        decl: &Declaration,
+        instruction_fanins: &FlatAlloc<Vec<FlatID>, FlatIDMarker>,
+    ) -> Option<Span> {
+        match decl.decl_kind {
+            DeclarationKind::RegularWire { .. } | DeclarationKind::RegularGenerative { .. } => {}
+            _ => return None,
+        }
+        for (id, instr) in &self.working_on.instructions {
+            if id != decl_id && instruction_fanins[id].contains(&decl_id) {
+                return None; // Read somewhere
+            }
+            if let Instruction::ForStatement(stm) = instr
+                && stm.loop_var_decl == decl_id
+            {
+                return None;
             }
         }
+        let mut removed_range = decl.decl_span.as_range();
+        for from in &instruction_fanins[decl_id] {
+            let Instruction::Expression(Expression {
+                span,
+                output: ExpressionOutput::MultiWrite(writes),
+                ..
+            }) = &self.working_on.instructions[*from]
+            else {
+                continue;
+            };
+            let is_initializer = writes.len() == 1
+                && decl
+                    .decl_span
+                    .contains_pos(writes[0].to_span.as_range().start)
+                && !self.calls_function(*from, instruction_fanins);
+            if !is_initializer {
+                return None;
+            }
+            removed_range.end = removed_range.end.max(span.as_range().end);
+        }
+
+        let text = &self.file_data.file_text.file_text;
+        let line_start = text[..removed_range.start]
+            .rfind('\n')
+            .map_or(0, |nl| nl + 1);
+        let line_end = text[removed_range.end..]
+            .find('\n')
+            .map_or(text.len(), |nl| removed_range.end + nl + 1);
+        let only_decl_on_line = text[line_start..removed_range.start].trim().is_empty()
+            && text[removed_range.end..line_end].trim().is_empty();
+        only_decl_on_line.then(|| Span::from(line_start..line_end))
+    }
+    /// Whether the expression `expr`, or one of its subexpressions, is a function call
+    fn calls_function(
+        &self,
+        expr: FlatID,
+        instruction_fanins: &FlatAlloc<Vec<FlatID>, FlatIDMarker>,
+    ) -> bool {
+        let Instruction::Expression(expression) = &self.working_on.instructions[expr] else {
+            return false; // Reading another declaration
+        };
+        matches!(expression.source, ExpressionSource::FuncCall(_))
+            || instruction_fanins[expr]
+                .iter()
+                .any(|from| self.calls_function(*from, instruction_fanins))
     }
     fn make_fanins(&self) -> FlatAlloc<Vec<FlatID>, FlatIDMarker> {
         // Setup Wire Fanouts List for faster processing
@@ -492,7 +584,11 @@ impl LintContext<'_> {
                 Entry::Occupied(occupied_entry) => {
                     let (existing_span, existing_kind) = occupied_entry.get();
                     self.errors
-                        .error(span, format!("Duplicate {kind} '{name}' declaration"))
+                        .error(
+                            span,
+                            ErrorCode::DuplicateDeclaration,
+                            format!("Duplicate {kind} '{name}' declaration"),
+                        )
                         .info_same_file(
                             *existing_span,
                             format!("{existing_kind} '{name}' declared here"),
@@ -523,6 +619,7 @@ impl LintContext<'_> {
                         };
                     self.errors.error(
                         declaration.decl_span,
+                        ErrorCode::NonSynthesizableType,
                         format!("'{non_synthesizeable_typ_name}' cannot be non-generative."),
                     );
                 }
@@ -535,3 +632,55 @@ impl LintContext<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::{compile_text, errors_with_code};
+
+    /// The text each unused variable warning suggests removing, in order
+    fn removed_lines(text: &str) -> Vec<Option<String>> {
+        let (linker, file) = compile_text(text);
+        errors_with_code(&linker, file, ErrorCode::UnusedVariable)
+            .iter()
+            .map(|err| {
+                err.suggestions
+                    .first()
+                    .map(|s| text[s.position.as_range()].to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_declaration_removal() {
+        let removed = removed_lines(
+            "
+module unused_decls {
+    input int#(FROM: 0, TO: 4) x
+    output int#(FROM: 0, TO: 4) y
+    int unused_plain
+    int unused_init = x + 1
+    int unused_call = counter(x)
+    int unused_split
+    unused_split = x
+    y = x
+}
+module counter {
+    interface counter : int#(FROM: 0, TO: 4) i -> int#(FROM: 0, TO: 4) o
+    o = i
+}
+",
+        );
+        assert_eq!(
+            removed,
+            [
+                Some("    int unused_plain\n".to_string()),
+                Some("    int unused_init = x + 1\n".to_string()),
+                // Calls could have side effects
+                None,
+                // The write is a separate statement
+                None,
+            ]
+        );
+    }
+}
@@ -99,7 +99,7 @@ pub fn typecheck(pass: &mut LinkerPass, errors: &ErrorCollector) {
             let expected_name = expected.display(&md.domains);
             let found_name = found.display(&md.domains);
             errors
-            .error(span, format!("Domain error: Attempting to combine domains {found_name} and {expected_name} in {context}"))
+            .error(span, ErrorCode::DomainMismatch, format!("Domain error: Attempting to combine domains {found_name} and {expected_name} in {context}"))
             .add_info_list(infos);
 
             assert_ne!(found, expected);
@@ -128,6 +128,7 @@ pub fn typecheck(pass: &mut LinkerPass, errors: &ErrorCollector) {
         errors
             .error(
                 span,
+                ErrorCode::TypeMismatch,
                 format!(
                     "Typing Error: {context} expects '{expected_name}' but was given '{found_name}'"
                 ),
@@ -147,6 +148,7 @@ pub fn typecheck(pass: &mut LinkerPass, errors: &ErrorCollector) {
         for (typ, span) in finalize_ctx.substitution_failures {
             errors.error(
                 span,
+                ErrorCode::UnresolvedType,
                 format!(
                     "Could not fully figure out the type of this object. {}",
                     typ.display(globals.globals, link_info)
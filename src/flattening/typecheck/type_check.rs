@@ -154,7 +154,7 @@ impl<'l> TypeCheckingContext<'l> {
                             let template_arg = &self.link_info.parameters[*template_id];
                             self.errors
                                 .error(
-                                    *name_span,
+                                    *name_span, ErrorCode::NoSuchField,
                                     format!(
                                         "The type of this object is the template parameter '{}'. You cannot use struct fields on template args",
                                         template_arg.name
@@ -168,7 +168,7 @@ impl<'l> TypeCheckingContext<'l> {
                                 self.link_info.instructions[*interface_id].unwrap_interface();
                             self.errors
                                 .error(
-                                    *name_span,
+                                    *name_span, ErrorCode::NoSuchField,
                                     format!(
                                         "The type of this object is a local interface '{}'. You cannot use struct fields on local interfaces",
                                         interface_decl.name
@@ -217,7 +217,7 @@ impl<'l> TypeCheckingContext<'l> {
                                     });
                                 self.errors
                                     .error(
-                                        *name_span,
+                                        *name_span, ErrorCode::NoSuchField,
                                         format!("No such field '{name}' on {obj_name}. Available fields are {field_names}"),
                                     )
                                     .info_obj(obj);
@@ -377,12 +377,12 @@ impl<'l> TypeCheckingContext<'l> {
                 );
 
                 self.errors
-                    .error(excess_args_span, format!("Excess argument. Function takes {expected_arg_count} args, but {arg_count} were passed."))
+                    .error(excess_args_span, ErrorCode::ArgumentCountMismatch, format!("Excess argument. Function takes {expected_arg_count} args, but {arg_count} were passed."))
                     .info_obj(interface);
             } else {
                 // Too few args, mention missing argument names
                 self.errors
-                    .error(func_call.arguments_span.close_bracket(), format!("Too few arguments. Function takes {expected_arg_count} args, but {arg_count} were passed."))
+                    .error(func_call.arguments_span.close_bracket(), ErrorCode::ArgumentCountMismatch, format!("Too few arguments. Function takes {expected_arg_count} args, but {arg_count} were passed."))
                     .info_obj(interface);
             }
         }
@@ -399,11 +399,11 @@ impl<'l> TypeCheckingContext<'l> {
 
                 let excess_results_span = Span::new_overarching(start_span, end_span);
                 self.errors
-                    .error(excess_results_span, format!("Excess output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
+                    .error(excess_results_span, ErrorCode::ArgumentCountMismatch, format!("Excess output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
                     .info_obj(interface);
             } else {
                 self.errors
-                    .error(whole_func_span, format!("Too few output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
+                    .error(whole_func_span, ErrorCode::ArgumentCountMismatch, format!("Too few output targets. Function returns {num_func_outputs} results, but {num_targets} targets were given."))
                     .info_obj(interface);
             }
         }
@@ -444,7 +444,7 @@ impl<'l> TypeCheckingContext<'l> {
                     );
 
                     self.errors
-                        .error(excess_args_span, format!("Excess bindings. This interface provides {expected_arg_count} {name}, but {arg_count} were provided."))
+                        .error(excess_args_span, ErrorCode::ArgumentCountMismatch, format!("Excess bindings. This interface provides {expected_arg_count} {name}, but {arg_count} were provided."))
                         .info_obj(interface);
                 } else {
                     // Too few args, mention missing argument names
@@ -458,7 +458,7 @@ impl<'l> TypeCheckingContext<'l> {
                     };
 
                     self.errors
-                        .error(too_few_args_span, format!("Too few bindings. This interface provides {expected_arg_count} {name}, but {arg_count} were provided."))
+                        .error(too_few_args_span, ErrorCode::ArgumentCountMismatch, format!("Too few bindings. This interface provides {expected_arg_count} {name}, but {arg_count} were provided."))
                         .info_obj(interface);
                 }
             }
@@ -475,6 +475,7 @@ impl<'l> TypeCheckingContext<'l> {
         let ExpressionSource::WireRef(wire_ref) = &wire_ref_expr.source else {
             self.errors.error(
                 wire_ref_expr.span,
+                ErrorCode::NotAWireReference,
                 "Cannot function-call on any expression. It must be a wire reference",
             );
             return None;
@@ -489,7 +490,7 @@ impl<'l> TypeCheckingContext<'l> {
                         "{context} expects this to be a callable interface, the interface `{name}` is not callable"
                     );
                     self.errors
-                        .error(wire_ref.get_total_span(), err_text)
+                        .error(wire_ref.get_total_span(), ErrorCode::NotCallable, err_text)
                         .info_obj_different_file(interface, submod.md.link_info.file);
                     return None;
                 };
@@ -508,6 +509,7 @@ impl<'l> TypeCheckingContext<'l> {
             | AbstractInnerType::Unknown(_) => {
                 self.errors.error(
                     wire_ref.get_total_span(),
+                    ErrorCode::NotCallable,
                     format!("{context} expects this to be an interface, but found a regular wire"),
                 );
                 None
@@ -696,6 +698,7 @@ impl<'l> TypeCheckingContext<'l> {
                 if multi_write.len() > 1 {
                     self.errors.error(
                         expr.span,
+                        ErrorCode::MultipleOutputsInAssignment,
                         format!(
                             "Non-function assignments must output exactly 1 output instead of {}",
                             multi_write.len()
@@ -733,7 +736,7 @@ impl<'l> TypeCheckingContext<'l> {
                     }
                 } else {
                     if let Some(bindings_span) = if_stm.conditional_bindings_span {
-                        self.errors.error(bindings_span, "Cannot use conditional bingings because the condition isn't an action or a trigger");
+                        self.errors.error(bindings_span, ErrorCode::InvalidConditionalBinding, "Cannot use conditional bingings because the condition isn't an action or a trigger");
                     }
 
                     self.type_checker.unify_report_error(
@@ -787,7 +790,13 @@ impl<'l> TypeCheckingContext<'l> {
             let err = format!(
                 "Can only use conditional bindings on triggers. '{interface_name}' is an {kind_str}"
             );
-            self.errors.error(condition_expr.span, err).info_obj(&trig);
+            self.errors
+                .error(
+                    condition_expr.span,
+                    ErrorCode::InvalidConditionalBinding,
+                    err,
+                )
+                .info_obj(&trig);
         }
 
         self.report_errors_for_bad_binding(
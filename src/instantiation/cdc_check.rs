@@ -0,0 +1,310 @@
+//! Clock domain crossing lints. Every `CrossDomain` in user code must either be
+//! - A single bit that is registered twice in its new domain before it is used, possibly inside of a submodule
+//! - Data that is only used under a `when` whose condition comes from another `CrossDomain`, its valid signal
+//!
+//! The standard library and [SYNCHRONIZER_MODULES] are trusted to do their own synchronization.
+
+use std::collections::HashSet;
+
+use ibig::UBig;
+
+use crate::flattening::Direction;
+use crate::latency::{DomainTimeBase, compute_domain_time_bases};
+use crate::linker::IsExtern;
+
+use super::{
+    ForEachContainedWire, IsPort, ModuleTypingContext, RealWire, RealWireDataSource, SubModule,
+};
+
+use crate::prelude::*;
+
+/// Modules whose internal crossings are not checked. Wires may be connected to them from any domain
+pub const SYNCHRONIZER_MODULES: &[&str] = &["FIFO", "SameCycleReadFIFO"];
+
+/// Modules of the standard library that cross domains without synchronizing
+const UNSYNCHRONIZED_STD_MODULES: &[&str] = &["CrossAction", "CrossActionNoData"];
+
+/// How many registers must follow a crossing before the signal may be used
+const SYNCHRONIZER_STAGES: i64 = 2;
+
+/// For every wire, the wires that read it
+fn compute_fanouts(
+    wires: &FlatAlloc<RealWire, WireIDMarker>,
+) -> FlatAlloc<Vec<WireID>, WireIDMarker> {
+    let mut fanouts = wires.map(|_| Vec::new());
+    for (id, w) in wires {
+        w.source.for_each_wire(&mut |from| {
+            if fanouts[from].last() != Some(&id) {
+                fanouts[from].push(id);
+            }
+        });
+    }
+    fanouts
+}
+
+/// If `reader` only copies `from`, returns the registers it adds
+fn plain_copy_registers(reader: &RealWire, from: &RealWire, from_id: WireID) -> Option<i64> {
+    match &reader.source {
+        RealWireDataSource::Select { root, path } if *root == from_id && path.is_empty() => Some(0),
+        RealWireDataSource::Multiplexer { is_state, sources } => {
+            let [source] = sources.as_slice() else {
+                return None;
+            };
+            if source.from != from_id || !source.to_path.is_empty() || !source.condition.is_empty()
+            {
+                return None;
+            }
+            let latency_diff = reader.absolute_latency.get()? - from.absolute_latency.get()?;
+            Some(latency_diff + i64::from(is_state.is_some()))
+        }
+        _ => None,
+    }
+}
+
+/// Follows `wire` through plain copies and into submodules, until it has passed [SYNCHRONIZER_STAGES] registers
+fn is_synchronized(
+    wires: &FlatAlloc<RealWire, WireIDMarker>,
+    submodules: &FlatAlloc<SubModule, SubModuleIDMarker>,
+    mut wire: WireID,
+    mut registers: i64,
+) -> bool {
+    let fanouts = compute_fanouts(wires);
+    // Bounds the walk for state registers that feed back into themselves
+    for _ in 0..wires.len() {
+        if registers >= SYNCHRONIZER_STAGES {
+            return true;
+        }
+        if let IsPort::SubmodulePort(sm_id, port_id, Direction::Input) = wires[wire].is_port {
+            let Some(instance) = submodules[sm_id].instance.get() else {
+                return false;
+            };
+            let Some(port) = &instance.interface_ports[port_id] else {
+                return false;
+            };
+            return is_synchronized(&instance.wires, &instance.submodules, port.wire, registers);
+        }
+        let [reader] = fanouts[wire].as_slice() else {
+            return false;
+        };
+        let Some(regs) = plain_copy_registers(&wires[*reader], &wires[wire], wire) else {
+            return false;
+        };
+        registers += regs;
+        wire = *reader;
+    }
+    false
+}
+
+impl ModuleTypingContext<'_> {
+    /// Does `wire` depend on the output of one of `crossings`?
+    fn depends_on_crossing(&self, wire: WireID, crossings: &[WireID]) -> bool {
+        let mut seen = HashSet::new();
+        let mut to_visit = vec![wire];
+        while let Some(w) = to_visit.pop() {
+            if crossings.contains(&w) {
+                return true;
+            }
+            if seen.insert(w) {
+                self.wires[w]
+                    .source
+                    .for_each_wire(&mut |from| to_visit.push(from));
+            }
+        }
+        false
+    }
+
+    /// Is `wire` only stored or output under a condition that comes from one of `valid_crossings`?
+    fn is_qualified(
+        &self,
+        fanouts: &FlatAlloc<Vec<WireID>, WireIDMarker>,
+        wire: WireID,
+        valid_crossings: &[WireID],
+        seen: &mut HashSet<WireID>,
+    ) -> bool {
+        if !seen.insert(wire) {
+            return true;
+        }
+        fanouts[wire].iter().all(|reader_id| {
+            let reader = &self.wires[*reader_id];
+            if plain_copy_registers(reader, &self.wires[wire], wire).is_some() {
+                return !matches!(
+                    reader.is_port,
+                    IsPort::Port(_, Direction::Output)
+                        | IsPort::SubmodulePort(_, _, Direction::Input)
+                ) && self.is_qualified(fanouts, *reader_id, valid_crossings, seen);
+            }
+            let RealWireDataSource::Multiplexer { sources, .. } = &reader.source else {
+                // Combinatorial logic on the data is fine, as long as its result is qualified
+                return self.is_qualified(fanouts, *reader_id, valid_crossings, seen);
+            };
+            sources.iter().all(|s| {
+                let mut reads_wire = false;
+                s.to_path.for_each_wire(&mut |w| reads_wire |= w == wire);
+                s.condition.for_each_wire(&mut |w| reads_wire |= w == wire);
+                if reads_wire {
+                    return false;
+                }
+                s.from != wire
+                    || s.condition
+                        .iter()
+                        .any(|cond| self.depends_on_crossing(cond.condition_wire, valid_crossings))
+            })
+        })
+    }
+
+    pub fn check_clock_domain_crossings(&self) {
+        let file = &self.linker.files[self.link_info.file];
+        if file.is_std || SYNCHRONIZER_MODULES.contains(&self.link_info.name.as_str()) {
+            return;
+        }
+
+        // Domains with a clock ratio between them are synchronous, crossing between them is safe
+        let time_bases = compute_domain_time_bases(&self.md.domains);
+        let group_of = |w: WireID| DomainTimeBase::of(&time_bases, self.wires[w].domain).group;
+
+        // The in and out wires of every CrossDomain between asynchronous domains
+        let mut crossings = Vec::new();
+        for (_, sm) in &self.submodules {
+            let sm_md = &self.linker.modules[sm.refers_to.id];
+            let sm_name = sm_md.link_info.name.as_str();
+            if self.linker.files[sm_md.link_info.file].is_std
+                && UNSYNCHRONIZED_STD_MODULES.contains(&sm_name)
+            {
+                self.errors.warn(
+                    sm.get_span(self.link_info),
+                    ErrorCode::UnsynchronizedCrossing,
+                    format!("{sm_name} does not synchronize its valid signal in the destination domain. Use a FIFO, or synchronize a CrossDomain explicitly"),
+                );
+                continue;
+            }
+            if sm_md.link_info.is_extern != IsExtern::Builtin || sm_name != "CrossDomain" {
+                continue;
+            }
+            let [in_port, out_port] = ["in", "out"].map(|name| {
+                sm_md
+                    .ports
+                    .find(|_, p| p.name == name)
+                    .and_then(|port_id| sm.port_map[port_id].as_ref())
+            });
+            if let (Some(in_port), Some(out_port)) = (in_port, out_port) {
+                let (from, to) = (in_port.maps_to_wire, out_port.maps_to_wire);
+                if group_of(from) != group_of(to) {
+                    crossings.push((sm, from, to));
+                }
+            }
+        }
+
+        let outs: Vec<WireID> = crossings.iter().map(|(_, _, to)| *to).collect();
+        let fanouts = compute_fanouts(&self.wires);
+        for (sm, from, to) in &crossings {
+            let other_crossings: Vec<WireID> = outs.iter().copied().filter(|o| o != to).collect();
+            if self.is_qualified(&fanouts, *to, &other_crossings, &mut HashSet::new()) {
+                continue;
+            }
+            let out_wire = &self.wires[*to];
+            let domain_names = [from, to].map(|w| &self.md.domains[self.wires[*w].domain].name);
+            let crossing = format!(
+                "This CrossDomain crosses from domain '{}' to '{}'",
+                domain_names[0], domain_names[1]
+            );
+            let bits = out_wire.typ.sizeof();
+            if bits > UBig::from(1u8) {
+                self.errors.warn(
+                    sm.get_span(self.link_info),
+                    ErrorCode::MultiBitCrossing,
+                    format!("{crossing} with {bits} bits, but it is not only used under a valid signal that crossed separately"),
+                );
+            } else if !is_synchronized(&self.wires, &self.submodules, *to, 0) {
+                self.errors.warn(
+                    sm.get_span(self.link_info),
+                    ErrorCode::UnsynchronizedCrossing,
+                    format!("{crossing}, but it is used before it is registered {SYNCHRONIZER_STAGES} times"),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::{compile_text, errors_with_code};
+
+    /// The number of `unsynchronized-crossing` and `multi-bit-crossing` warnings
+    fn crossing_warnings(text: &str) -> (usize, usize) {
+        let (linker, file) = compile_text(text);
+        (
+            errors_with_code(&linker, file, ErrorCode::UnsynchronizedCrossing).len(),
+            errors_with_code(&linker, file, ErrorCode::MultiBitCrossing).len(),
+        )
+    }
+
+    /// A single bit crossing, followed by `registers`
+    fn single_bit_crossing(registers: &str) -> String {
+        format!(
+            "
+module m {{
+    domain a
+    input bool x
+    domain b
+    output bool y
+    CrossDomain #(T: type bool) cross
+    cross.in = x
+    {registers} bool synced = cross.out
+    y = synced
+}}
+"
+        )
+    }
+
+    /// Crosses 4 bits of data, and a valid signal. With `when_valid`, the data is only stored when it's valid
+    fn multi_bit_crossing(when_valid: bool) -> String {
+        let store = if when_valid {
+            "when valid {\n        stored = data_cross.out\n    }"
+        } else {
+            "stored = data_cross.out"
+        };
+        format!(
+            "
+module m {{
+    domain a
+    input bool valid_in'0
+    input int #(FROM: 0, TO: 16) data_in'0
+    domain b
+    output int #(FROM: 0, TO: 16) data_out
+    CrossDomain #(T: type bool) valid_cross
+    CrossDomain #(T: type int #(FROM: 0, TO: 16)) data_cross
+    valid_cross.in = valid_in
+    data_cross.in = data_in
+    reg reg bool valid = valid_cross.out
+    state int #(FROM: 0, TO: 16) stored
+    initial stored = 0
+    {store}
+    data_out = stored
+}}
+"
+        )
+    }
+
+    #[test]
+    fn test_unsynchronized_single_bit_crossing() {
+        assert_eq!(crossing_warnings(&single_bit_crossing("")), (1, 0));
+        // One register can still go metastable
+        assert_eq!(crossing_warnings(&single_bit_crossing("reg")), (1, 0));
+    }
+
+    #[test]
+    fn test_synchronized_single_bit_crossing() {
+        assert_eq!(crossing_warnings(&single_bit_crossing("reg reg")), (0, 0));
+    }
+
+    #[test]
+    fn test_multi_bit_crossing_without_valid_signal() {
+        assert_eq!(crossing_warnings(&multi_bit_crossing(false)), (0, 1));
+    }
+
+    #[test]
+    fn test_multi_bit_crossing_under_valid_signal() {
+        assert_eq!(crossing_warnings(&multi_bit_crossing(true)), (0, 0));
+    }
+}
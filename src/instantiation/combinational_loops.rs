@@ -0,0 +1,385 @@
+//! Combinational loop detection. Latency counting only catches loops that add latency,
+//! a loop that stays within a single latency stage has no register on it and never settles.
+//!
+//! A dependency is combinational, unless it is a write to a `state`, or the value is read at a later latency than it is produced.
+//! Submodules contribute their [InstantiatedModule::combinational_paths].
+
+use crate::flattening::{Direction, Instruction};
+use crate::linker::IsExtern;
+
+use super::{
+    ForEachContainedWire, IsPort, ModuleTypingContext, RealWireDataSource, SubModuleOrWire,
+};
+
+use crate::prelude::*;
+
+/// An input port of which an output port of the same module depends combinatorially
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombinationalPath {
+    pub from: PortID,
+    pub to: PortID,
+}
+
+/// For every wire, the wires that combinatorially depend on it, and the span of that dependency
+struct CombinationalGraph {
+    fanouts: FlatAlloc<Vec<(WireID, Span)>, WireIDMarker>,
+}
+
+impl CombinationalGraph {
+    fn new(ctx: &ModuleTypingContext) -> Self {
+        let mut fanouts = ctx.wires.map(|_| Vec::new());
+
+        for (w_id, w) in &ctx.wires {
+            let mut add_edge = |from: WireID, span: Span| {
+                let from_wire = &ctx.wires[from];
+                let registered = from_wire.domain == w.domain
+                    && w.absolute_latency.unwrap() > from_wire.absolute_latency.unwrap();
+                if !registered {
+                    fanouts[from].push((w_id, span));
+                }
+            };
+            match &w.source {
+                // Writes to a state are only read back the next cycle
+                RealWireDataSource::Multiplexer {
+                    is_state: Some(_), ..
+                } => {}
+                RealWireDataSource::Multiplexer {
+                    is_state: None,
+                    sources,
+                } => {
+                    for s in sources {
+                        s.for_each_wire(&mut |from| add_edge(from, s.write_span));
+                    }
+                }
+                other => {
+                    let span = ctx.link_info.get_instruction_span(w.original_instruction);
+                    other.for_each_wire(&mut |from| add_edge(from, span));
+                }
+            }
+        }
+
+        for (_, sm) in &ctx.submodules {
+            let Some(instance) = sm.instance.get() else {
+                continue;
+            };
+            let sm_md = &ctx.linker.modules[sm.refers_to.id];
+            let span = sm.get_span(ctx.link_info);
+            let mut add_path = |from: PortID, to: PortID| {
+                if let (Some(from), Some(to)) = (&sm.port_map[from], &sm.port_map[to]) {
+                    fanouts[from.maps_to_wire].push((to.maps_to_wire, span));
+                }
+            };
+            match sm_md.link_info.is_extern {
+                IsExtern::Normal => {
+                    for path in &instance.combinational_paths {
+                        add_path(path.from, path.to);
+                    }
+                }
+                // Builtins like LatencyOffset and CrossDomain are plain wires, whatever latency they claim.
+                // Extern modules are assumed combinational between ports of the same latency
+                IsExtern::Builtin | IsExtern::Extern => {
+                    let is_builtin = sm_md.link_info.is_extern == IsExtern::Builtin;
+                    for (from, from_port) in instance.interface_ports.iter_valids() {
+                        for (to, to_port) in instance.interface_ports.iter_valids() {
+                            if from_port.direction == Direction::Input
+                                && to_port.direction == Direction::Output
+                                && (is_builtin
+                                    || (from_port.domain == to_port.domain
+                                        && from_port.absolute_latency == to_port.absolute_latency))
+                            {
+                                add_path(from, to);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { fanouts }
+    }
+
+    /// Depth first search for back edges. Each cycle is a list of `(wire, span of its dependency on the previous wire)`.
+    /// Cycles that share a wire with an earlier cycle are skipped, to report each loop once
+    fn find_cycles(&self) -> Vec<Vec<(WireID, Span)>> {
+        const UNVISITED: usize = usize::MAX;
+        const DONE: usize = usize::MAX - 1;
+        // The position on the stack of the wires currently being visited
+        let mut stack_pos = self.fanouts.map(|_| UNVISITED);
+        let mut in_reported_cycle = self.fanouts.map(|_| false);
+        let mut cycles = Vec::new();
+
+        for (start, _) in &self.fanouts {
+            if stack_pos[start] != UNVISITED {
+                continue;
+            }
+            // (wire, span of the edge into it, next edge to visit)
+            let mut stack: Vec<(WireID, Option<Span>, usize)> = vec![(start, None, 0)];
+            stack_pos[start] = 0;
+            while let Some((node, _, next_edge)) = stack.last_mut() {
+                let node = *node;
+                let Some(&(to, span)) = self.fanouts[node].get(*next_edge) else {
+                    stack_pos[node] = DONE;
+                    stack.pop();
+                    continue;
+                };
+                *next_edge += 1;
+                match stack_pos[to] {
+                    UNVISITED => {
+                        stack_pos[to] = stack.len();
+                        stack.push((to, Some(span), 0));
+                    }
+                    DONE => {}
+                    pos => {
+                        // Back edge, the stack from `to` up to here is a cycle
+                        let cycle: Vec<(WireID, Span)> = stack[pos + 1..]
+                            .iter()
+                            .map(|(w, span, _)| (*w, span.unwrap()))
+                            .chain(std::iter::once((to, span)))
+                            .collect();
+                        if cycle.iter().all(|(w, _)| !in_reported_cycle[*w]) {
+                            for (w, _) in &cycle {
+                                in_reported_cycle[*w] = true;
+                            }
+                            cycles.push(cycle);
+                        }
+                    }
+                }
+            }
+        }
+        cycles
+    }
+}
+
+impl ModuleTypingContext<'_> {
+    /// Declarations and ports, as opposed to the wires of subexpressions
+    fn loop_step_name(&self, wire: WireID) -> Option<String> {
+        let w = &self.wires[wire];
+        match w.is_port {
+            IsPort::SubmodulePort(sm_id, port_id, _) => {
+                let sm = &self.submodules[sm_id];
+                let port = &self.linker.modules[sm.refers_to.id].ports[port_id];
+                Some(format!("{}.{}", sm.name, port.name))
+            }
+            IsPort::Port(..) => Some(w.name.to_string()),
+            IsPort::PlainWire => matches!(
+                self.link_info.instructions[w.original_instruction],
+                Instruction::Declaration(_)
+            )
+            .then(|| w.name.to_string()),
+        }
+    }
+
+    /// Reports every loop without a register on it. Requires latencies to have been computed
+    pub fn check_combinational_loops(&self) {
+        let graph = CombinationalGraph::new(self);
+        for cycle in graph.find_cycles() {
+            let mut steps: Vec<(String, Span)> = cycle
+                .iter()
+                .filter_map(|(w, span)| Some((self.loop_step_name(*w)?, *span)))
+                .collect();
+            if steps.is_empty() {
+                steps = cycle
+                    .iter()
+                    .map(|(w, span)| (self.wires[*w].name.to_string(), *span))
+                    .collect();
+            }
+            let names = steps
+                .iter()
+                .chain(std::iter::once(&steps[0]))
+                .map(|(name, _)| format!("'{name}'"))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let mut err = self.errors.error(
+                steps[0].1,
+                ErrorCode::CombinationalLoop,
+                format!("Combinational loop: {names}\nThere is no register on this loop, so it never settles. Add a `reg` or make one of the wires `state`"),
+            );
+            for (idx, (name, span)) in steps.iter().enumerate() {
+                let prev = &steps[(idx + steps.len() - 1) % steps.len()].0;
+                err =
+                    err.info_same_file(*span, format!("{}. '{name}' depends on '{prev}'", idx + 1));
+            }
+        }
+    }
+
+    /// The output ports that depend combinatorially on each input port. Used to find loops through this module when it is a submodule
+    pub fn combinational_port_paths(&self) -> Vec<CombinationalPath> {
+        let graph = CombinationalGraph::new(self);
+        let port_wire = |port: &crate::flattening::Port| match self.generation_state
+            [port.declaration_instruction]
+        {
+            SubModuleOrWire::Wire(w) => Some(w),
+            _ => None,
+        };
+        let mut result = Vec::new();
+        for (from, from_port) in &self.md.ports {
+            if from_port.direction != Direction::Input {
+                continue;
+            }
+            let Some(from_wire) = port_wire(from_port) else {
+                continue;
+            };
+            let mut reached = self.wires.map(|_| false);
+            reached[from_wire] = true;
+            let mut to_visit = vec![from_wire];
+            while let Some(w) = to_visit.pop() {
+                for (to, _) in &graph.fanouts[w] {
+                    if !reached[*to] {
+                        reached[*to] = true;
+                        to_visit.push(*to);
+                    }
+                }
+            }
+            for (to, to_port) in &self.md.ports {
+                if to_port.direction == Direction::Output
+                    && port_wire(to_port).is_some_and(|w| reached[w])
+                {
+                    result.push(CombinationalPath { from, to });
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{compile_text, errors_with_code};
+
+    fn mk_graph(edges: &[&[usize]]) -> CombinationalGraph {
+        let mut fanouts = FlatAlloc::new();
+        for (from, to) in edges.iter().enumerate() {
+            fanouts.alloc(
+                to.iter()
+                    .map(|to| {
+                        let pos = from * 10 + to;
+                        let span = Span::from(pos..pos + 1);
+                        (WireID::from_hidden_value(*to), span)
+                    })
+                    .collect(),
+            );
+        }
+        CombinationalGraph { fanouts }
+    }
+
+    fn cycle_wires(graph: &CombinationalGraph) -> Vec<Vec<usize>> {
+        graph
+            .find_cycles()
+            .iter()
+            .map(|c| c.iter().map(|(w, _)| w.get_hidden_value()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        // 0 -> 1 -> 2 -> 0, 2 -> 3 -> 3, 4 -> 0
+        let graph = mk_graph(&[&[1], &[2], &[0, 3], &[3], &[0]]);
+        assert_eq!(cycle_wires(&graph), [vec![1, 2, 0], vec![3]]);
+        // The span of each step is the edge into that wire, here from * 10 + to
+        let cycle = &graph.find_cycles()[0];
+        assert_eq!(cycle[0].1, Span::from(1..2));
+        assert_eq!(cycle[2].1, Span::from(20..21));
+    }
+
+    #[test]
+    fn test_no_cycles() {
+        let graph = mk_graph(&[&[1, 2], &[2], &[]]);
+        assert!(graph.find_cycles().is_empty());
+    }
+
+    /// The messages of all combinational loop errors in `text`
+    fn loop_errors(text: &str) -> Vec<String> {
+        let (linker, file) = compile_text(text);
+        errors_with_code(&linker, file, ErrorCode::CombinationalLoop)
+            .into_iter()
+            .map(|err| err.reason)
+            .collect()
+    }
+
+    #[test]
+    fn test_same_stage_loop() {
+        let errors = loop_errors(
+            "
+module m {
+    input bool x'0
+    output bool y
+    bool p
+    bool q
+    p = q | x
+    q = p
+    y = q
+}
+",
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].starts_with("Combinational loop: 'p' -> 'q' -> 'p'"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn test_loop_through_submodule() {
+        let errors = loop_errors(
+            "
+module inv {
+    interface inv : bool a -> bool b
+    b = !a
+}
+module m {
+    input bool x'0
+    output bool y
+    bool p
+    inv i
+    bool inverted = i(p)
+    p = inverted ^ x
+    y = p
+}
+",
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].starts_with("Combinational loop: 'p' -> 'i.a' -> 'i.b' -> 'inverted' -> 'p'"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn test_registers_break_loops() {
+        // Without a state, this is a net-positive latency cycle instead
+        assert!(
+            loop_errors(
+                "
+module m {
+    input bool x'0
+    output bool y
+    bool p
+    bool q
+    p = q | x
+    reg q = p
+    y = q
+}
+"
+            )
+            .is_empty()
+        );
+        assert!(
+            loop_errors(
+                "
+module m {
+    input bool x'0
+    output bool y
+    state bool s
+    initial s = false
+    s = s | x
+    y = s
+}
+"
+            )
+            .is_empty()
+        );
+    }
+}
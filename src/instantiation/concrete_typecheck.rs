@@ -251,7 +251,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                 if !unify_rank(rank, &right.typ, unifier) {
                     errors.error(|substitutor| {
                         self.errors
-                            .error(right.get_span(self.link_info), format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
+                            .error(right.get_span(self.link_info), ErrorCode::RankMismatch, format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
                             right.typ.display_substitute(self.linker, substitutor),
                             out.typ.display_substitute(self.linker, substitutor))
                         );
@@ -327,7 +327,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                 if !unify_rank(rank, &left.typ, unifier) {
                     errors.error(|substitutor| {
                         self.errors
-                            .error(left.get_span(self.link_info), format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
+                            .error(left.get_span(self.link_info), ErrorCode::RankMismatch, format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
                             left.typ.display_substitute(self.linker, substitutor),
                             out.typ.display_substitute(self.linker, substitutor))
                         ).info_same_file(right.get_span(self.link_info), format!("Right argument has type {}", right.typ.display_substitute(self.linker, substitutor)));
@@ -336,7 +336,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                 if !unify_rank(rank, &right.typ, unifier) {
                     errors.error(|substitutor| {
                         self.errors
-                            .error(right.get_span(self.link_info), format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
+                            .error(right.get_span(self.link_info), ErrorCode::RankMismatch, format!("Incompatible multi-rank for higher-rank operator: Found {} but output is {}",
                             right.typ.display_substitute(self.linker, substitutor),
                             out.typ.display_substitute(self.linker, substitutor))
                         ).info_same_file(left.get_span(self.link_info), format!("Left argument has type {}", left.typ.display_substitute(self.linker, substitutor)));
@@ -394,7 +394,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                             let right_bounds = IntBounds { from: rf, to: rt };
 
                             if right_bounds.contains(&IBig::from(0)) {
-                                self.errors.error(right.get_span(self.link_info), format!("Possible divide by 0, right argument bounds are {right_bounds}"));
+                                self.errors.error(right.get_span(self.link_info), ErrorCode::PossibleDivisionByZero, format!("Possible divide by 0, right argument bounds are {right_bounds}"));
                             } else {
                                 let lmax = lt - 1;
                                 let rmax = rt - 1;
@@ -414,7 +414,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                             let right_bounds = IntBounds { from: rf, to: rt };
 
                             if right_bounds.contains(&IBig::from(0)) {
-                                self.errors.error(right.get_span(self.link_info), format!("Possible divide by 0, right argument bounds are {right_bounds}"));
+                                self.errors.error(right.get_span(self.link_info), ErrorCode::PossibleDivisionByZero, format!("Possible divide by 0, right argument bounds are {right_bounds}"));
                             } else {
                                 unifier.set(out.to, rt - 1).unwrap(); // WTF: borrow error if from comes first???
                                 unifier.set(out.from, IBig::from(0)).unwrap();
@@ -561,7 +561,8 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                 let (Some(from), Some(to)) = (&sm.port_map[*from], &sm.port_map[*to]) else {
                     return InferenceResult::PortNotUsed;
                 };
-                match latency_infer_problem.infer(from.maps_to_wire, to.maps_to_wire) {
+                let from_domain = self.wires[from.maps_to_wire].domain;
+                match latency_infer_problem.infer(from.maps_to_wire, to.maps_to_wire, from_domain) {
                     Ok(result) => InferenceResult::Found(IBig::from(result)),
                     Err(InferenceFailure::BadProblem) => InferenceResult::LatencyBadProblem,
                     Err(InferenceFailure::NotReached) => InferenceResult::LatencyNotReached,
@@ -745,6 +746,8 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
             }
         }
 
+        lat_inf.dump_json();
+
         let mut any_success = false;
         // And now instantiate the modules we can
         sm_ids.retain(|id| {
@@ -779,8 +782,11 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
             .instantiate(self.linker, refers_to_clone);
 
         let Some(instance) = instance else {
-            self.errors
-                .error(submod_instr.get_span(), "Error instantiating submodule");
+            self.errors.error(
+                submod_instr.get_span(),
+                ErrorCode::SubmoduleInstantiationFailed,
+                "Error instantiating submodule",
+            );
             return;
         };
         let sub_module = &self.linker.modules[sm.refers_to.id];
@@ -801,7 +807,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                             "Port '{port_name}' is used, but the instantiated module has this port disabled"
                         );
                         self.errors
-                            .error(*span, err)
+                            .error(*span, ErrorCode::DisabledPortUsed, err)
                             .info_obj_different_file(source_code_port, sub_module.link_info.file)
                             .info_obj_same_file(submod_instr);
                     }
@@ -811,6 +817,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                     self.errors
                         .warn(
                             submod_instr.get_span(),
+                            ErrorCode::UnusedPort,
                             format!("Unused port '{}'", source_code_port.name),
                         )
                         .info_obj_different_file(source_code_port, sub_module.link_info.file)
@@ -847,6 +854,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                 if !did_already_error {
                     self.errors.error(
                         span,
+                        ErrorCode::UnresolvedType,
                         format!(
                             "Some parameters of '{}' were still unknown: {}",
                             w.name,
@@ -857,6 +865,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
             } else if !w.typ.is_valid() {
                 self.errors.error(
                     w.get_span(self.link_info),
+                    ErrorCode::InvalidConcreteType,
                     format!(
                         "The type of '{}' is invalid! {}",
                         w.name,
@@ -900,6 +909,7 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                     let sm_name = &sm.name;
                     let mut err = self.errors.error(
                         sm.get_span(self.link_info),
+                        ErrorCode::UnresolvedType,
                         format!(
                             "Some submodule parameters of {sm_name} were still unknown: {}\n{}",
                             sm.refers_to.display(self.linker),
@@ -945,7 +955,11 @@ impl<'inst, 'l: 'inst> ModuleTypingContext<'l> {
                     self.linker,
                     "Invalid arguments found in a submodule's template arguments",
                 ) {
-                    self.errors.error(sm.get_span(self.link_info), reason);
+                    self.errors.error(
+                        sm.get_span(self.link_info),
+                        ErrorCode::InvalidConcreteType,
+                        reason,
+                    );
                 }
             }
             if let Some(instance) = sm.instance.get() {
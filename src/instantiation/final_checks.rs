@@ -73,7 +73,7 @@ impl<'l> ModuleTypingContext<'l> {
         };
         if !array_bound.contains_bounds(idx_bounds) {
             self.errors.error(
-                span,
+                span, ErrorCode::IndexOutOfBounds,
                 format!(
                     "Out of bounds! The array is of size {sz}, but the {ctx} has bounds {idx_bounds}"
                 ),
@@ -88,6 +88,7 @@ impl<'l> ModuleTypingContext<'l> {
         if !array_bound.contains(idx) {
             self.errors.error(
                 span,
+                ErrorCode::IndexOutOfBounds,
                 format!("Out of bounds! The array is of size {sz}, but the index is {idx}"),
             );
         }
@@ -192,6 +193,7 @@ impl<'l> ModuleTypingContext<'l> {
                     {
                         self.errors.error(
                             w.get_span(self.link_info),
+                            ErrorCode::InvalidInitialValue,
                             "Wire's initial value is not a subtype of the wire's type!",
                         );
                     }
@@ -1,9 +1,11 @@
 use std::cell::RefCell;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::rc::Rc;
 
 use crate::errors::CompileError;
 use crate::instantiation::perform_instantiation;
+use crate::latency::solve_cache::LatencySolveCache;
+use crate::linker::GlobalUUID;
 use crate::typing::concrete_type::ConcreteGlobalReference;
 
 use crate::prelude::*;
@@ -14,10 +16,12 @@ use super::InstantiatedModule;
 /// With this you can instantiate a module for different sets of template arguments.
 /// It caches the instantiations that have been made, such that they need not be repeated.
 ///
-/// Also, with incremental builds (#49) this will be a prime area for investigation
+/// For incremental builds (#49), only the instances that depend on changed globals are removed, see [Self::remove_instances_depending_on]
 #[derive(Debug)]
 pub struct InstantiationCache {
     cache: BTreeMap<Rc<ConcreteGlobalReference<ModuleUUID>>, Rc<InstantiatedModule>>,
+    /// Shared between all instantiations, of all modules. Identical latency problems need only be solved once
+    pub latency_solve_cache: LatencySolveCache,
 }
 
 impl InstantiationCache {
@@ -30,7 +34,50 @@ impl InstantiationCache {
     }
 
     pub fn clear_instances(&mut self) {
-        self.cache.clear()
+        self.cache.clear();
+        self.latency_solve_cache.clear();
+    }
+
+    /// Removes the instances of the `changed` modules, instances that have a `changed` type as a template argument,
+    /// and all instances that contain one of those as a submodule.
+    ///
+    /// The [LatencySolveCache] is kept, it is keyed by the problem itself. It holds a bounded number of solutions, see [LatencySolveCache::solve]
+    pub fn remove_instances_depending_on(&mut self, changed: &HashSet<GlobalUUID>) {
+        let is_changed_type = |id: TypeUUID| changed.contains(&GlobalUUID::Type(id));
+        let mut stale: HashSet<Rc<ConcreteGlobalReference<ModuleUUID>>> = self
+            .cache
+            .keys()
+            .filter(|global_ref| {
+                changed.contains(&GlobalUUID::Module(global_ref.id))
+                    || global_ref
+                        .template_args
+                        .iter()
+                        .any(|(_, arg)| arg.contains_type(&is_changed_type))
+            })
+            .cloned()
+            .collect();
+        // Propagate up to the instances that use the stale ones
+        loop {
+            let newly_stale: Vec<_> = self
+                .cache
+                .iter()
+                .filter(|(global_ref, inst)| {
+                    !stale.contains(*global_ref)
+                        && inst.submodules.iter().any(|(_, sm)| {
+                            sm.instance
+                                .get()
+                                .is_some_and(|sm_inst| stale.contains(&sm_inst.global_ref))
+                        })
+                })
+                .map(|(global_ref, _)| global_ref.clone())
+                .collect();
+            if newly_stale.is_empty() {
+                break;
+            }
+            stale.extend(newly_stale);
+        }
+        self.cache
+            .retain(|global_ref, _| !stale.contains(global_ref));
     }
 
     // Also passes over invalid instances. Instance validity should not be assumed!
@@ -76,6 +123,7 @@ impl Instantiator {
     pub fn new() -> Self {
         let cache = InstantiationCache {
             cache: BTreeMap::new(),
+            latency_solve_cache: LatencySolveCache::default(),
         };
 
         Self {
@@ -134,3 +182,31 @@ impl Instantiator {
         self.cache.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TWO_DELAYS, compile_text, instance_names};
+
+    #[test]
+    fn test_remove_instances_depending_on() {
+        let text = format!("{TWO_DELAYS}\nmodule other {{\n    output bool o\n    o = true\n}}\n");
+        let (linker, _file) = compile_text(&text);
+        assert_eq!(instance_names(&linker, "delay").len(), 2);
+        let (delay, _) = linker
+            .modules
+            .iter()
+            .find(|(_, md)| md.link_info.name == "delay")
+            .unwrap();
+
+        let changed = HashSet::from([GlobalUUID::Module(delay)]);
+        linker
+            .instantiator
+            .borrow_mut()
+            .remove_instances_depending_on(&changed);
+        // Both delays, and `top` which contains them
+        assert!(instance_names(&linker, "delay").is_empty());
+        assert!(instance_names(&linker, "top").is_empty());
+        assert_eq!(instance_names(&linker, "other"), ["other #()"]);
+    }
+}
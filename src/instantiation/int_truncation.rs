@@ -0,0 +1,246 @@
+//! Opt-in lints for integer bounds that are narrowed without the type system checking it.
+//! - `unsafe_int_cast` whose input can lie outside of its output bounds
+//! - `%` of which the left side grew far beyond the bounds of the result, which hides wrap-around
+//!
+//! Both list the inferred bounds of the arithmetic that produced the narrowed value.
+
+use ibig::IBig;
+use sus_proc_macro::get_builtin_type;
+
+use crate::flattening::BinaryOperator;
+use crate::linker::IsExtern;
+use crate::typing::concrete_type::{ConcreteType, IntBounds};
+
+use super::{ModuleTypingContext, RealWireDataSource};
+
+use crate::prelude::*;
+
+/// A `%` only warns if its left side needs more than this many extra bits
+const MAX_MODULO_GROWTH_BITS: u64 = 1;
+
+/// Don't flood the error with steps for very long expressions
+const MAX_ARITHMETIC_STEPS: usize = 8;
+
+fn int_bounds(typ: &ConcreteType) -> Option<IntBounds<&IBig>> {
+    match typ {
+        ConcreteType::Named(global_ref) if global_ref.id == get_builtin_type!("int") => {
+            Some(global_ref.unwrap_int_bounds())
+        }
+        _ => None,
+    }
+}
+
+impl ModuleTypingContext<'_> {
+    /// The arithmetic operators that `wire` is computed with, in source order
+    fn arithmetic_steps(&self, wire: WireID) -> Vec<WireID> {
+        let mut steps = Vec::new();
+        let mut to_visit = vec![wire];
+        while let Some(w) = to_visit.pop() {
+            if steps.contains(&w) || int_bounds(&self.wires[w].typ).is_none() {
+                continue;
+            }
+            match &self.wires[w].source {
+                RealWireDataSource::UnaryOp { right, .. } => {
+                    steps.push(w);
+                    to_visit.push(*right);
+                }
+                RealWireDataSource::BinaryOp { left, right, .. } => {
+                    steps.push(w);
+                    to_visit.push(*left);
+                    to_visit.push(*right);
+                }
+                RealWireDataSource::Select { root, path } if path.is_empty() => {
+                    to_visit.push(*root);
+                }
+                // Through declarations like `int x = a + b`
+                RealWireDataSource::Multiplexer {
+                    is_state: None,
+                    sources,
+                } if sources.len() == 1 && sources[0].condition.is_empty() => {
+                    to_visit.push(sources[0].from);
+                }
+                _ => {}
+            }
+        }
+        let span_of = |w: &WireID| {
+            self.link_info
+                .get_instruction_span(self.wires[*w].original_instruction)
+        };
+        steps.sort_by_key(|w| span_of(w).as_range().start);
+        steps.truncate(MAX_ARITHMETIC_STEPS);
+        steps
+    }
+
+    fn add_arithmetic_step_infos(&self, mut err: crate::errors::ErrorReference<'_>, wire: WireID) {
+        for step in self.arithmetic_steps(wire) {
+            let w = &self.wires[step];
+            err = err.info_same_file(
+                self.link_info.get_instruction_span(w.original_instruction),
+                format!("Inferred as {}", w.typ.display(self.linker)),
+            );
+        }
+    }
+
+    fn check_unsafe_int_casts(&self) {
+        for (_, sm) in &self.submodules {
+            let sm_md = &self.linker.modules[sm.refers_to.id];
+            if sm_md.link_info.is_extern != IsExtern::Builtin
+                || sm_md.link_info.name != "unsafe_int_cast"
+            {
+                continue;
+            }
+            let [in_port, out_port] = ["in", "out"].map(|name| {
+                sm_md
+                    .ports
+                    .find(|_, p| p.name == name)
+                    .and_then(|port_id| sm.port_map[port_id].as_ref())
+            });
+            let (Some(in_port), Some(out_port)) = (in_port, out_port) else {
+                continue;
+            };
+            let in_wire = &self.wires[in_port.maps_to_wire];
+            let out_wire = &self.wires[out_port.maps_to_wire];
+            let (Some(in_bounds), Some(out_bounds)) =
+                (int_bounds(&in_wire.typ), int_bounds(&out_wire.typ))
+            else {
+                continue;
+            };
+            if in_bounds.from >= out_bounds.from && in_bounds.to <= out_bounds.to {
+                continue;
+            }
+            let err = self.errors.warn(
+                sm.get_span(self.link_info),
+                ErrorCode::IntTruncation,
+                format!(
+                    "unsafe_int_cast from {} to {} truncates the values outside of the output bounds",
+                    in_wire.typ.display(self.linker),
+                    out_wire.typ.display(self.linker)
+                ),
+            );
+            self.add_arithmetic_step_infos(err, in_port.maps_to_wire);
+        }
+    }
+
+    fn check_modulo_growth(&self) {
+        for (_, w) in &self.wires {
+            let RealWireDataSource::BinaryOp {
+                op: BinaryOperator::Modulo,
+                left,
+                ..
+            } = &w.source
+            else {
+                continue;
+            };
+            let left_wire = &self.wires[*left];
+            let (Some(left_bounds), Some(result_bounds)) =
+                (int_bounds(&left_wire.typ), int_bounds(&w.typ))
+            else {
+                continue;
+            };
+            if left_bounds.bitwidth() <= result_bounds.bitwidth() + MAX_MODULO_GROWTH_BITS {
+                continue;
+            }
+            let err = self.errors.warn(
+                self.link_info.get_instruction_span(w.original_instruction),
+                ErrorCode::IntRangeGrowth,
+                format!(
+                    "The left side of this % is {} ({} bits), but the result is {} ({} bits). The arithmetic before it may wrap around unexpectedly",
+                    left_wire.typ.display(self.linker),
+                    left_bounds.bitwidth(),
+                    w.typ.display(self.linker),
+                    result_bounds.bitwidth()
+                ),
+            );
+            self.add_arithmetic_step_infos(err, *left);
+        }
+    }
+
+    /// Opt-in lints for narrowed int bounds. Std modules are skipped, users can't act on them
+    pub fn check_int_truncation(&self) {
+        if self.linker.files[self.link_info.file].is_std
+            || self.link_info.is_extern != IsExtern::Normal
+        {
+            return;
+        }
+        self.check_unsafe_int_casts();
+        self.check_modulo_growth();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::CompileError;
+    use crate::prelude::*;
+    use crate::test_util::{compile_text, errors_in_file, errors_with_code};
+
+    /// `y = {expr}`, for inputs `a` and `b` of `int #(FROM: 0, TO: 16)`. The test config enables `-W int-bounds`
+    fn int_bounds_warnings(expr: &str, code: ErrorCode) -> Vec<CompileError> {
+        int_bounds_warnings_with(expr, code, "")
+    }
+
+    fn int_bounds_warnings_with(
+        expr: &str,
+        code: ErrorCode,
+        module_comment: &str,
+    ) -> Vec<CompileError> {
+        let text = format!(
+            "
+{module_comment}
+module m {{
+    input int #(FROM: 0, TO: 16) a
+    input int #(FROM: 0, TO: 16) b
+    output int #(FROM: 0, TO: 16) y
+    y = {expr}
+}}
+"
+        );
+        let (linker, file) = compile_text(&text);
+        for err in errors_in_file(&linker, file) {
+            assert!(
+                [ErrorCode::IntTruncation, ErrorCode::IntRangeGrowth].contains(&err.code),
+                "{expr}: unexpected {:?} {}",
+                err.code,
+                err.reason
+            );
+        }
+        errors_with_code(&linker, file, code)
+    }
+
+    #[test]
+    fn test_truncating_cast() {
+        let errors = int_bounds_warnings(
+            "unsafe_int_cast #(FROM: 0, TO: 16)(a + b)",
+            ErrorCode::IntTruncation,
+        );
+        assert_eq!(errors.len(), 1);
+        // The inferred bounds of `a + b`
+        assert_eq!(errors[0].infos.len(), 1);
+        assert!(errors[0].infos[0].info.contains("int #(FROM: 0, TO: 31)"));
+    }
+
+    #[test]
+    fn test_truncating_cast_can_be_allowed() {
+        let cast = "unsafe_int_cast #(FROM: 0, TO: 16)(a + b)";
+        let comment = "// sus:allow(int-truncation)";
+        assert!(int_bounds_warnings_with(cast, ErrorCode::IntTruncation, comment).is_empty());
+    }
+
+    #[test]
+    fn test_cast_within_bounds() {
+        let cast = "unsafe_int_cast #(FROM: 0, TO: 16)((a + b) / 2)";
+        assert!(int_bounds_warnings(cast, ErrorCode::IntTruncation).is_empty());
+    }
+
+    #[test]
+    fn test_modulo_of_grown_range() {
+        let errors = int_bounds_warnings("(a * b) % 16", ErrorCode::IntRangeGrowth);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].reason.contains("(8 bits)"));
+    }
+
+    #[test]
+    fn test_modulo_of_slightly_grown_range() {
+        // `a + b` needs just one bit more than the result
+        assert!(int_bounds_warnings("(a + b) % 16", ErrorCode::IntRangeGrowth).is_empty());
+    }
+}
@@ -1,12 +1,20 @@
+mod cdc_check;
+mod combinational_loops;
 mod concrete_typecheck;
 mod execute;
 mod final_checks;
 pub mod instantiation_cache;
+mod int_truncation;
+mod state_reset;
 mod unique_names;
+mod unreachable_hardware;
 
+use combinational_loops::CombinationalPath;
 use ibig::IBig;
 use unique_names::UniqueNames;
 
+use crate::latency::initiation_interval::FeedbackAnalysis;
+use crate::latency::solve_cache::LatencySummary;
 use crate::latency::{AbsLat, InferenceFailure};
 use crate::linker::LinkInfo;
 use crate::prelude::*;
@@ -235,10 +243,16 @@ pub struct InstantiatedModule {
     pub errors: ErrorStore,
     /// This matches the ports in [Module::ports]. Ports are not `None` when they are not part of this instantiation.
     pub interface_ports: FlatAlloc<Option<InstantiatedPort>, PortIDMarker>,
+    /// The port latencies of [Self::interface_ports], as parents see them
+    pub latency_summary: LatencySummary,
     pub wires: FlatAlloc<RealWire, WireIDMarker>,
     pub submodules: FlatAlloc<SubModule, SubModuleIDMarker>,
     /// See [GenerationState]
     pub generation_state: FlatAlloc<SubModuleOrWire, FlatIDMarker>,
+    /// State feedback loops and their initiation intervals
+    pub feedback: FeedbackAnalysis,
+    /// Input to output port paths without a register, see [combinational_loops]
+    pub combinational_paths: Vec<CombinationalPath>,
 }
 
 /// See [GenerationState]
@@ -381,7 +395,7 @@ impl Executed {
     ) -> (ModuleTypingContext<'l>, ValueUnifierAlloc) {
         let errors = ErrorCollector::new_empty(md.link_info.file, &linker.files);
         if let Err((position, reason)) = self.execution_status {
-            errors.error(position, reason);
+            errors.error(position, ErrorCode::GenerativeExecutionError, reason);
         }
         let ctx = ModuleTypingContext {
             mangled_name: mangle_name(&name),
@@ -469,16 +483,65 @@ impl<'l> ModuleTypingContext<'l> {
             })
         });
 
-        InstantiatedModule {
+        let latency_summary = LatencySummary::new(&interface_ports, &self.md.domains);
+
+        let (feedback, combinational_paths) = if !self.errors.did_error() {
+            (
+                self.analyze_feedback_loops(),
+                self.combinational_port_paths(),
+            )
+        } else {
+            (FeedbackAnalysis::default(), Vec::new())
+        };
+
+        let md = self.md;
+        let result = InstantiatedModule {
             global_ref: self.global_ref,
             name: self.name,
             mangled_name: self.mangled_name,
             errors: self.errors.into_storage(),
             interface_ports,
+            latency_summary,
             wires: self.wires,
             submodules: self.submodules,
             generation_state: self.generation_state,
+            feedback,
+            combinational_paths,
+        };
+
+        for (interface_id, interface) in &md.interfaces {
+            if let Some(ii) = result.interface_initiation_interval(md, interface_id) {
+                debug!(
+                    "{}: interface {} has initiation interval {ii}",
+                    result.name, interface.name
+                );
+            }
+        }
+
+        result
+    }
+}
+
+impl ModuleTypingContext<'_> {
+    /// Follows path-less selects and unconditional single-source writes back to the wire that was copied
+    fn follow_plain_copies(&self, mut wire: WireID) -> WireID {
+        // Bounded, because wires may be copied in a loop
+        for _ in 0..self.wires.len() {
+            wire = match &self.wires[wire].source {
+                RealWireDataSource::Select { root, path } if path.is_empty() => *root,
+                RealWireDataSource::Multiplexer {
+                    is_state: None,
+                    sources,
+                } => match sources.as_slice() {
+                    [source] if source.condition.is_empty() && source.to_path.is_empty() => {
+                        source.from
+                    }
+                    _ => return wire,
+                },
+                _ => return wire,
+            };
         }
+        wire
     }
 }
 
@@ -515,19 +578,22 @@ fn perform_instantiation(
         let mut errors = ErrorCollector::new_empty(md.link_info.file, &linker.files);
         errors.set_did_error();
         let msg = format!("Not Instantiating {name} due to abstract typing errors");
-        errors.warn(md.link_info.name_span, msg);
+        errors.warn(md.link_info.name_span, ErrorCode::NotInstantiated, msg);
         return InstantiatedModule {
             global_ref,
             mangled_name: mangle_name(&name),
             name,
             errors: errors.into_storage(),
             interface_ports: Default::default(),
+            latency_summary: Default::default(),
             wires: Default::default(),
             submodules: Default::default(),
             generation_state: md
                 .link_info
                 .instructions
                 .map(|_| SubModuleOrWire::Unassigned),
+            feedback: Default::default(),
+            combinational_paths: Vec::new(),
         };
     }
     let submodules_with_abs_type_errors: HashSet<_> = md
@@ -553,7 +619,7 @@ fn perform_instantiation(
         for s in submodules_with_abs_type_errors {
             writeln!(msg, "- {s}").unwrap();
         }
-        errors.warn(md.link_info.name_span, msg);
+        errors.warn(md.link_info.name_span, ErrorCode::NotInstantiated, msg);
 
         return InstantiatedModule {
             global_ref,
@@ -561,12 +627,15 @@ fn perform_instantiation(
             name,
             errors: errors.into_storage(),
             interface_ports: Default::default(),
+            latency_summary: Default::default(),
             wires: Default::default(),
             submodules: Default::default(),
             generation_state: md
                 .link_info
                 .instructions
                 .map(|_| SubModuleOrWire::Unassigned),
+            feedback: Default::default(),
+            combinational_paths: Vec::new(),
         };
     }
 
@@ -600,5 +669,21 @@ fn perform_instantiation(
     debug!("Checking array accesses {name}");
     typed.check_subtypes();
 
+    if !typed.errors.did_error() {
+        debug!("Checking combinational loops {name}");
+        typed.check_combinational_loops();
+    }
+
+    if !typed.errors.did_error() {
+        debug!("Checking clock domain crossings {name}");
+        typed.check_clock_domain_crossings();
+        debug!("Checking unreachable hardware {name}");
+        typed.check_unreachable_hardware();
+        debug!("Checking int truncation {name}");
+        typed.check_int_truncation();
+        debug!("Checking state reset {name}");
+        typed.check_state_reset();
+    }
+
     typed.into_instantiated_module()
 }
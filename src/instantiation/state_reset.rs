@@ -0,0 +1,243 @@
+//! Lint for `state` registers that start out undefined. Simulators start them at `'x`, synthesis at whatever the
+//! FPGA or ASIC powers up with, so they are a classic source of mismatches between the two.
+//!
+//! A `state` is fine if it has an `initial` value, or is written under an action named like a reset, such as `rst`.
+//! It is only reported if its value reaches a `when` condition or an output port.
+
+use std::collections::HashSet;
+
+use ibig::IBig;
+use sus_proc_macro::get_builtin_type;
+
+use crate::flattening::{Direction, Instruction};
+use crate::linker::IsExtern;
+use crate::typing::concrete_type::ConcreteType;
+use crate::value::Value;
+
+use super::{ForEachContainedWire, IsPort, ModuleTypingContext, RealWireDataSource};
+
+use crate::prelude::*;
+
+/// Input ports with one of these as a `_` separated part of their name reset the state they write to, like `rst`, `reset_n` or `sys_rst`
+const RESET_PORT_NAMES: &[&str] = &["rst", "reset"];
+
+impl ModuleTypingContext<'_> {
+    fn is_reset_port(&self, wire: WireID) -> bool {
+        let w = &self.wires[self.follow_plain_copies(wire)];
+        matches!(w.is_port, IsPort::Port(_, Direction::Input)) && {
+            let name = w.name.to_lowercase();
+            name.split('_')
+                .any(|segment| RESET_PORT_NAMES.contains(&segment))
+        }
+    }
+
+    /// The `when` condition or output port that the value of `state` reaches first, if any
+    fn state_reaches_control(
+        &self,
+        state: WireID,
+        condition_wires: &HashSet<WireID>,
+    ) -> Option<WireID> {
+        let mut fanouts = self.wires.map(|_| Vec::new());
+        for (id, w) in &self.wires {
+            w.source.for_each_wire(&mut |from| fanouts[from].push(id));
+        }
+        let mut seen = HashSet::from([state]);
+        let mut to_visit = vec![state];
+        while let Some(w) = to_visit.pop() {
+            if condition_wires.contains(&w)
+                || matches!(self.wires[w].is_port, IsPort::Port(_, Direction::Output))
+            {
+                return Some(w);
+            }
+            for reader in &fanouts[w] {
+                // Other states are checked by themselves
+                let is_state = matches!(
+                    self.wires[*reader].source,
+                    RealWireDataSource::Multiplexer {
+                        is_state: Some(_),
+                        ..
+                    }
+                );
+                if !is_state && seen.insert(*reader) {
+                    to_visit.push(*reader);
+                }
+            }
+        }
+        None
+    }
+
+    /// The text of an `initial` for scalar types, arrays don't get a suggestion
+    fn initial_value_suggestion(typ: &ConcreteType) -> Option<String> {
+        let ConcreteType::Named(global_ref) = typ else {
+            return None;
+        };
+        match global_ref.id {
+            get_builtin_type!("bool") => Some("false".to_string()),
+            get_builtin_type!("int") => {
+                let bounds = global_ref.unwrap_int_bounds();
+                let zero = IBig::from(0);
+                if bounds.from <= &zero && &zero < bounds.to {
+                    Some("0".to_string())
+                } else {
+                    Some(bounds.from.to_string())
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Reports `state`s without `initial` value or reset that control the module. Std modules are skipped, users can't act on them
+    pub fn check_state_reset(&self) {
+        if self.linker.files[self.link_info.file].is_std
+            || self.link_info.is_extern != IsExtern::Normal
+        {
+            return;
+        }
+        let mut condition_wires = HashSet::new();
+        for (_, w) in &self.wires {
+            if let RealWireDataSource::Multiplexer { sources, .. } = &w.source {
+                for s in sources {
+                    s.condition.for_each_wire(&mut |c| {
+                        condition_wires.insert(c);
+                    });
+                }
+            }
+        }
+
+        let file_text = &self.linker.files[self.link_info.file].file_text.file_text;
+        for (id, w) in &self.wires {
+            let RealWireDataSource::Multiplexer {
+                is_state: Some(Value::Unset),
+                sources,
+            } = &w.source
+            else {
+                continue;
+            };
+            let is_reset = sources.iter().any(|s| {
+                s.condition
+                    .iter()
+                    .any(|cond| !cond.inverse && self.is_reset_port(cond.condition_wire))
+            });
+            if is_reset {
+                continue;
+            }
+            let Some(controlled) = self.state_reaches_control(id, &condition_wires) else {
+                continue;
+            };
+            let Instruction::Declaration(decl) =
+                &self.link_info.instructions[w.original_instruction]
+            else {
+                continue;
+            };
+            let controlled_wire = &self.wires[controlled];
+            let what = if condition_wires.contains(&controlled) {
+                "a condition"
+            } else {
+                "an output"
+            };
+            let err = self
+                .errors
+                .warn(
+                    decl.name_span,
+                    ErrorCode::StateWithoutReset,
+                    format!(
+                        "State '{}' has no initial value and no reset in {}, but it controls {what}. It starts out undefined",
+                        decl.name, self.name
+                    ),
+                )
+                .info_same_file(
+                    self.link_info
+                        .get_instruction_span(controlled_wire.original_instruction),
+                    format!("'{}' controls this", decl.name),
+                );
+            if let Some(value) = Self::initial_value_suggestion(&w.typ) {
+                let decl_range = decl.decl_span.as_range();
+                let line_start = file_text[..decl_range.start]
+                    .rfind('\n')
+                    .map_or(0, |idx| idx + 1);
+                let line_end = file_text[decl_range.end..]
+                    .find('\n')
+                    .map_or(file_text.len(), |idx| decl_range.end + idx)
+                    .max(decl_range.end);
+                let line = &file_text[line_start..line_end];
+                let indent = &line[..line.len() - line.trim_start().len()];
+                let line_end = line_start + line.trim_end().len();
+                err.suggest_insert(
+                    decl.decl_span,
+                    line_end,
+                    format!("\n{indent}initial {} = {value}", decl.name),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::{compile_text, errors_in_file};
+
+    /// The number of `state-without-reset` warnings for a state `s` with the given `body`. Asserts nothing else is reported
+    fn state_reset_warnings(body: &str) -> usize {
+        state_reset_warnings_with_port("rst", body)
+    }
+
+    /// Like [state_reset_warnings], with `rst` named `reset_port` instead
+    fn state_reset_warnings_with_port(reset_port: &str, body: &str) -> usize {
+        let body = body.replace("rst", reset_port);
+        let text = format!(
+            "
+module m {{
+    input bool x'0
+    input bool {reset_port}'0
+    output bool y'0
+    state bool s
+{body}
+}}
+"
+        );
+        let (linker, file) = compile_text(&text);
+        let errors = errors_in_file(&linker, file);
+        for err in &errors {
+            assert_eq!(
+                err.code,
+                ErrorCode::StateWithoutReset,
+                "unexpected error: {}",
+                err.reason
+            );
+        }
+        errors.len()
+    }
+
+    #[test]
+    fn test_state_controlling_output() {
+        assert_eq!(state_reset_warnings("    s = x | rst\n    y = s"), 1);
+    }
+
+    #[test]
+    fn test_state_controlling_condition() {
+        let body = "    s = x | rst\n    y = false\n    when s {\n        y = true\n    }";
+        assert_eq!(state_reset_warnings(body), 1);
+    }
+
+    #[test]
+    fn test_state_with_initial_value() {
+        let body = "    initial s = false\n    s = x | rst\n    y = s";
+        assert_eq!(state_reset_warnings(body), 0);
+    }
+
+    #[test]
+    fn test_state_with_reset() {
+        let body = "    s = x\n    when rst {\n        s = false\n    }\n    y = s";
+        assert_eq!(state_reset_warnings(body), 0);
+    }
+
+    #[test]
+    fn test_reset_names_are_whole_segments() {
+        let body = "    s = x\n    when rst {\n        s = false\n    }\n    y = s";
+        assert_eq!(state_reset_warnings_with_port("sys_rst_n", body), 0);
+        assert_eq!(state_reset_warnings_with_port("Reset", body), 0);
+        // Only contains `rst`
+        assert_eq!(state_reset_warnings_with_port("first", body), 1);
+    }
+}
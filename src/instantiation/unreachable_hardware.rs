@@ -0,0 +1,260 @@
+//! Lints for hardware that only turns out to be useless after execution, usually because of template arguments.
+//! - `when` conditions that are a constant in this instantiation
+//! - Declarations that don't reach an output, a `state` or a submodule in this instantiation.
+//!   Declarations that don't reach an output in any instantiation are already reported by [ErrorCode::UnusedVariable]
+
+use std::collections::HashSet;
+
+use crate::flattening::Instruction;
+use crate::linker::IsExtern;
+use crate::value::Value;
+
+use super::{
+    ForEachContainedWire, IsPort, ModuleTypingContext, MultiplexerSource, RealWireDataSource,
+};
+
+use crate::flattening::Direction;
+use crate::prelude::*;
+
+impl ModuleTypingContext<'_> {
+    /// The value of a condition that was fully known at execution, possibly copied through some wires
+    fn constant_condition(&self, condition_wire: WireID) -> Option<bool> {
+        match &self.wires[self.follow_plain_copies(condition_wire)].source {
+            RealWireDataSource::Constant {
+                value: Value::Bool(value),
+            } => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Sources under a constant false condition are never written
+    fn is_source_reachable(&self, source: &MultiplexerSource) -> bool {
+        source
+            .condition
+            .iter()
+            .all(|cond| self.constant_condition(cond.condition_wire) != Some(cond.inverse))
+    }
+
+    fn check_constant_conditions(&self) {
+        let mut reported = HashSet::new();
+        for (_, w) in &self.wires {
+            let RealWireDataSource::Multiplexer { sources, .. } = &w.source else {
+                continue;
+            };
+            for cond in sources.iter().flat_map(|s| s.condition.iter()) {
+                let Some(value) = self.constant_condition(cond.condition_wire) else {
+                    continue;
+                };
+                if !reported.insert(cond.condition_wire) {
+                    continue;
+                }
+                let consequence = if value {
+                    "Its else branch never runs"
+                } else {
+                    "The code under it never runs"
+                };
+                let cond_wire = &self.wires[cond.condition_wire];
+                self.errors.warn(
+                    self.link_info
+                        .get_instruction_span(cond_wire.original_instruction),
+                    ErrorCode::ConstantCondition,
+                    format!(
+                        "This condition is always {value} in {}. {consequence}",
+                        self.name
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_unreachable_wires(&self) {
+        let mut is_reachable = self.wires.map(|_| false);
+        let mut to_explore = Vec::new();
+        for (id, w) in &self.wires {
+            let is_terminal = match w.is_port {
+                IsPort::Port(_, Direction::Output)
+                | IsPort::SubmodulePort(_, _, Direction::Input) => true,
+                IsPort::Port(_, Direction::Input)
+                | IsPort::SubmodulePort(_, _, Direction::Output) => false,
+                IsPort::PlainWire => {
+                    matches!(
+                        w.source,
+                        RealWireDataSource::Multiplexer {
+                            is_state: Some(_),
+                            ..
+                        }
+                    ) || matches!(
+                        &self.link_info.instructions[w.original_instruction],
+                        Instruction::Declaration(decl) if decl.name.starts_with('_')
+                    )
+                }
+            };
+            if is_terminal {
+                is_reachable[id] = true;
+                to_explore.push(id);
+            }
+        }
+        while let Some(id) = to_explore.pop() {
+            let mut explore = |from: WireID| {
+                if !is_reachable[from] {
+                    is_reachable[from] = true;
+                    to_explore.push(from);
+                }
+            };
+            match &self.wires[id].source {
+                RealWireDataSource::Multiplexer { sources, .. } => {
+                    for s in sources {
+                        // The condition is still evaluated, even if it is constant false
+                        s.condition.for_each_wire(&mut explore);
+                        if self.is_source_reachable(s) {
+                            s.to_path.for_each_wire(&mut explore);
+                            explore(s.from);
+                        }
+                    }
+                }
+                other => other.for_each_wire(&mut explore),
+            }
+        }
+
+        let unused_in_all_instances: HashSet<Span> = (&self.md.link_info.errors)
+            .into_iter()
+            .filter(|err| err.code == ErrorCode::UnusedVariable)
+            .map(|err| err.position)
+            .collect();
+        let mut reported = HashSet::new();
+        for (id, w) in &self.wires {
+            if is_reachable[id] || w.is_port != IsPort::PlainWire {
+                continue;
+            }
+            if let Instruction::Declaration(decl) =
+                &self.link_info.instructions[w.original_instruction]
+                && !unused_in_all_instances.contains(&decl.name_span)
+                && reported.insert(w.original_instruction)
+            {
+                self.errors.warn(
+                    decl.name_span,
+                    ErrorCode::UnreachableHardware,
+                    format!(
+                        "'{}' does not reach an output, a state or a submodule in {}",
+                        decl.name, self.name
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Lints for hardware that is dead in this instantiation. Std modules are skipped, users can't act on them
+    pub fn check_unreachable_hardware(&self) {
+        if self.linker.files[self.link_info.file].is_std
+            || self.link_info.is_extern != IsExtern::Normal
+        {
+            return;
+        }
+        self.check_constant_conditions();
+        self.check_unreachable_wires();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_util::{compile_text, errors_with_code};
+
+    /// The number of `constant-condition` and `unreachable-hardware` warnings
+    fn dead_hardware_warnings(text: &str) -> (usize, usize) {
+        let (linker, file) = compile_text(text);
+        (
+            errors_with_code(&linker, file, ErrorCode::ConstantCondition).len(),
+            errors_with_code(&linker, file, ErrorCode::UnreachableHardware).len(),
+        )
+    }
+
+    /// `m` with the given `condition`, instantiated with `N: 5`
+    fn conditional_module(condition: &str) -> String {
+        format!(
+            "
+module m #(int N) {{
+    input bool x
+    output bool y
+    bool is_three = N == 3
+    y = x
+    {condition} {{
+        y = false
+    }}
+}}
+module top {{
+    input bool x
+    output bool y
+    m #(N: 5) sub
+    sub.x = x
+    y = sub.y
+}}
+"
+        )
+    }
+
+    #[test]
+    fn test_condition_made_constant_by_template_argument() {
+        assert_eq!(
+            dead_hardware_warnings(&conditional_module("when is_three")),
+            (1, 0)
+        );
+    }
+
+    #[test]
+    fn test_runtime_and_generative_conditions_are_not_reported() {
+        assert_eq!(
+            dead_hardware_warnings(&conditional_module("when x")),
+            (0, 0)
+        );
+        assert_eq!(
+            dead_hardware_warnings(&conditional_module("if N == 3")),
+            (0, 0)
+        );
+    }
+
+    /// `m` only uses `inverted` with `INVERT: true`
+    fn instantiated_with(invert: &[bool]) -> String {
+        let submodules: String = invert
+            .iter()
+            .enumerate()
+            .map(|(idx, invert)| {
+                format!("    m #(INVERT: {invert}) sub{idx}\n    sub{idx}.a = a\n    ys[{idx}] = sub{idx}.y\n")
+            })
+            .collect();
+        format!(
+            "
+module m #(bool INVERT) {{
+    input bool a
+    output bool y
+    bool inverted = !a
+    if INVERT {{
+        y = inverted
+    }} else {{
+        y = a
+    }}
+}}
+module top {{
+    input bool a
+    output bool[{}] ys
+{submodules}}}
+",
+            invert.len()
+        )
+    }
+
+    #[test]
+    fn test_declaration_left_unused_by_template_argument() {
+        assert_eq!(dead_hardware_warnings(&instantiated_with(&[false])), (0, 1));
+        // Reported per instantiation that doesn't use it
+        assert_eq!(
+            dead_hardware_warnings(&instantiated_with(&[true, false])),
+            (0, 1)
+        );
+    }
+
+    #[test]
+    fn test_declaration_used_by_all_instantiations() {
+        assert_eq!(dead_hardware_warnings(&instantiated_with(&[true])), (0, 0));
+    }
+}
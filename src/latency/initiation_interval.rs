@@ -0,0 +1,727 @@
+//! Initiation interval analysis of state feedback loops.
+//!
+//! Latency counting can't see the registers of `state` wires, nor the cycles hidden by a negative [LatencyOffset](../../../std/core.sus).
+//! Because all other latencies around a cycle cancel out after latency counting, these hidden cycles are exactly what it takes
+//! for a value to go around a feedback loop. That is the minimum number of cycles between two iterations: the initiation interval.
+
+use crate::flattening::{DeclarationKind, Direction, Instruction, Module};
+use crate::linker::{IsExtern, Linker};
+use crate::prelude::*;
+
+use crate::instantiation::*;
+
+/// Maximum number of steps spent enumerating cycles of a single strongly connected component. Prevents exponential blowup on pathological designs.
+///
+/// A search that hits this limit only knows a lower bound, see [InitiationInterval::is_lower_bound]
+const MAX_CYCLE_SEARCH_STEPS: usize = 10000;
+
+/// The minimum number of cycles between two iterations of a feedback loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitiationInterval {
+    pub cycles: i64,
+    /// The cycle search hit [MAX_CYCLE_SEARCH_STEPS], here or in a submodule. The real initiation interval may be larger
+    pub is_lower_bound: bool,
+}
+
+impl InitiationInterval {
+    fn max(self, other: Self) -> Self {
+        Self {
+            cycles: self.cycles.max(other.cycles),
+            is_lower_bound: self.is_lower_bound || other.is_lower_bound,
+        }
+    }
+}
+
+impl std::fmt::Display for InitiationInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_lower_bound {
+            write!(f, "at least {}", self.cycles)
+        } else {
+            write!(f, "{}", self.cycles)
+        }
+    }
+}
+
+/// A group of wires that feed back into themselves through `state` or negative [LatencyOffset](../../../std/core.sus)s
+#[derive(Debug, Clone)]
+pub struct FeedbackLoop {
+    /// All wires that are part of the loop (The strongly connected component)
+    pub wires: Vec<WireID>,
+    /// The `state` wires that carry values from one iteration to the next
+    pub state_wires: Vec<WireID>,
+    /// Ports (of this module or of submodules) that feed into the loop, or read from it. An interface is limited by all loops its ports touch
+    pub connected_ports: Vec<WireID>,
+    /// The minimum number of cycles between two iterations of the worst cycle in this loop
+    pub initiation_interval: InitiationInterval,
+}
+
+/// The hidden cycles between an input port and an output port of a module. Used to follow feedback loops through submodules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortHiddenLatency {
+    pub from: PortID,
+    pub to: PortID,
+    pub hidden_latency: i64,
+    /// The path search hit [MAX_CYCLE_SEARCH_STEPS], so a longer path may exist
+    pub is_lower_bound: bool,
+}
+
+/// Result of [ModuleTypingContext::analyze_feedback_loops]
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackAnalysis {
+    pub loops: Vec<FeedbackLoop>,
+    pub port_hidden_latencies: Vec<PortHiddenLatency>,
+}
+
+impl FeedbackAnalysis {
+    /// Worst initiation interval of all loops that contain one of the given wires
+    pub fn initiation_interval_of(
+        &self,
+        mut is_involved: impl FnMut(WireID) -> bool,
+    ) -> Option<InitiationInterval> {
+        self.loops
+            .iter()
+            .filter(|l| {
+                l.wires
+                    .iter()
+                    .chain(&l.connected_ports)
+                    .any(|w| is_involved(*w))
+            })
+            .map(|l| l.initiation_interval)
+            .reduce(InitiationInterval::max)
+    }
+}
+
+/// "to" comes first, like in [super::LatencyCountingProblem::edges]
+struct HiddenLatencyGraph {
+    fanins: Vec<Vec<(WireID, i64)>>,
+    /// (from, to) of the submodule paths whose hidden latency is only a lower bound
+    lower_bound_edges: Vec<(WireID, WireID)>,
+}
+
+impl HiddenLatencyGraph {
+    fn new(ctx: &ModuleTypingContext) -> Self {
+        let mut fanins: Vec<Vec<(WireID, i64)>> = ctx.wires.map(|_| Vec::new()).into_vec();
+        let mut lower_bound_edges = Vec::new();
+
+        for (w_id, w) in &ctx.wires {
+            // Writes to a state are only read back the next cycle
+            let hidden = match &w.source {
+                RealWireDataSource::Multiplexer {
+                    is_state: Some(_), ..
+                } => 1,
+                _ => 0,
+            };
+            w.source.iter_sources_with_min_latency(|from, _| {
+                fanins[w_id.get_hidden_value()].push((from, hidden));
+            });
+        }
+
+        for (_, sm) in &ctx.submodules {
+            let Some(instance) = sm.instance.get() else {
+                continue;
+            };
+            let sm_md = &ctx.linker.modules[sm.refers_to.id];
+            if sm_md.link_info.is_extern == IsExtern::Builtin {
+                // Builtins pass values straight through. Only what a negative LatencyOffset takes back is hidden, the latency of other builtins is counted normally
+                let hidden = sm.negative_latency_offset(ctx.linker).unwrap_or(0);
+                for (_, from_port, from) in
+                    crate::alloc::zip_eq(&instance.interface_ports, &sm.port_map)
+                {
+                    let (Some(from_port), Some(from)) = (from_port, from) else {
+                        continue;
+                    };
+                    if from_port.direction != Direction::Input {
+                        continue;
+                    }
+                    for (_, to_port, to) in
+                        crate::alloc::zip_eq(&instance.interface_ports, &sm.port_map)
+                    {
+                        if let (Some(to_port), Some(to)) = (to_port, to)
+                            && to_port.direction == Direction::Output
+                            && to_port.domain == from_port.domain
+                        {
+                            fanins[to.maps_to_wire.get_hidden_value()]
+                                .push((from.maps_to_wire, hidden));
+                        }
+                    }
+                }
+            } else {
+                for path in &instance.feedback.port_hidden_latencies {
+                    if let (Some(from), Some(to)) = (&sm.port_map[path.from], &sm.port_map[path.to])
+                    {
+                        fanins[to.maps_to_wire.get_hidden_value()]
+                            .push((from.maps_to_wire, path.hidden_latency));
+                        if path.is_lower_bound {
+                            lower_bound_edges.push((from.maps_to_wire, to.maps_to_wire));
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            fanins,
+            lower_bound_edges,
+        }
+    }
+
+    /// Whether any lower bound submodule path runs between the given nodes
+    fn has_lower_bound_edge(&self, mut is_included: impl FnMut(usize) -> bool) -> bool {
+        self.lower_bound_edges.iter().any(|(from, to)| {
+            is_included(from.get_hidden_value()) && is_included(to.get_hidden_value())
+        })
+    }
+
+    /// All nodes that can reach the component, or can be reached from it
+    fn connected_to(&self, component: &[usize], fanouts: &[Vec<(usize, i64)>]) -> Vec<usize> {
+        let mut seen = vec![false; self.fanins.len()];
+        for &n in component {
+            seen[n] = true;
+        }
+        let mut result = Vec::new();
+        for forward in [true, false] {
+            let mut visited = seen.clone();
+            let mut stack = component.to_vec();
+            while let Some(node) = stack.pop() {
+                let mut visit = |next: usize| {
+                    if !visited[next] {
+                        visited[next] = true;
+                        result.push(next);
+                        stack.push(next);
+                    }
+                };
+                if forward {
+                    fanouts[node].iter().for_each(|&(to, _)| visit(to));
+                } else {
+                    self.fanins[node]
+                        .iter()
+                        .for_each(|&(from, _)| visit(from.get_hidden_value()));
+                }
+            }
+        }
+        result
+    }
+
+    fn fanouts(&self) -> Vec<Vec<(usize, i64)>> {
+        let mut fanouts = vec![Vec::new(); self.fanins.len()];
+        for (to, fanin) in self.fanins.iter().enumerate() {
+            for &(from, hidden) in fanin {
+                fanouts[from.get_hidden_value()].push((to, hidden));
+            }
+        }
+        fanouts
+    }
+
+    /// Kosaraju's algorithm. Only returns components that contain a cycle
+    fn cyclic_components(&self, fanouts: &[Vec<(usize, i64)>]) -> Vec<Vec<usize>> {
+        let num_nodes = self.fanins.len();
+        let mut order = Vec::with_capacity(num_nodes);
+        let mut visited = vec![false; num_nodes];
+        for start in 0..num_nodes {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![(start, 0)];
+            while let Some((node, next_edge)) = stack.last_mut() {
+                if let Some(&(to, _)) = fanouts[*node].get(*next_edge) {
+                    *next_edge += 1;
+                    if !visited[to] {
+                        visited[to] = true;
+                        stack.push((to, 0));
+                    }
+                } else {
+                    order.push(*node);
+                    stack.pop();
+                }
+            }
+        }
+
+        let mut component_of = vec![usize::MAX; num_nodes];
+        let mut components = Vec::new();
+        for &start in order.iter().rev() {
+            if component_of[start] != usize::MAX {
+                continue;
+            }
+            let component_id = components.len();
+            component_of[start] = component_id;
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                component.push(node);
+                for &(from, _) in &self.fanins[node] {
+                    let from = from.get_hidden_value();
+                    if component_of[from] == usize::MAX {
+                        component_of[from] = component_id;
+                        stack.push(from);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        components.retain(|c| {
+            c.len() > 1
+                || self.fanins[c[0]]
+                    .iter()
+                    .any(|(from, _)| from.get_hidden_value() == c[0])
+        });
+        components
+    }
+
+    /// The largest total hidden latency of any simple cycle in the component
+    ///
+    /// Only edges with hidden latency are interesting, so we first compress the component down to those edges
+    fn worst_cycle(
+        &self,
+        component: &[usize],
+        fanouts: &[Vec<(usize, i64)>],
+    ) -> InitiationInterval {
+        let mut in_component = vec![false; self.fanins.len()];
+        for &n in component {
+            in_component[n] = true;
+        }
+        // Nodes that have a hidden latency edge leading into them
+        let mut anchors: Vec<usize> = component
+            .iter()
+            .copied()
+            .filter(|&n| {
+                self.fanins[n]
+                    .iter()
+                    .any(|&(from, h)| h != 0 && in_component[from.get_hidden_value()])
+            })
+            .collect();
+        anchors.sort_unstable();
+
+        // Anchor to anchor edges, by walking zero-latency edges
+        let mut anchor_edges: Vec<Vec<(usize, i64)>> = vec![Vec::new(); anchors.len()];
+        for (anchor_idx, &anchor) in anchors.iter().enumerate() {
+            let mut seen = vec![false; self.fanins.len()];
+            seen[anchor] = true;
+            let mut stack = vec![anchor];
+            while let Some(node) = stack.pop() {
+                for &(to, hidden) in &fanouts[node] {
+                    if !in_component[to] {
+                        continue;
+                    }
+                    if hidden != 0 {
+                        let to_anchor = anchors.binary_search(&to).unwrap();
+                        anchor_edges[anchor_idx].push((to_anchor, hidden));
+                    } else if !seen[to] {
+                        seen[to] = true;
+                        stack.push(to);
+                    }
+                }
+            }
+        }
+
+        // Enumerate simple cycles, each starting at their smallest anchor
+        let mut worst = InitiationInterval {
+            cycles: 0,
+            is_lower_bound: self.has_lower_bound_edge(|n| in_component[n]),
+        };
+        let mut steps = 0;
+        let mut on_path = vec![false; anchors.len()];
+        for start in 0..anchors.len() {
+            let mut stack: Vec<(usize, usize, i64)> = vec![(start, 0, 0)];
+            on_path[start] = true;
+            while let Some((node, next_edge, total)) = stack.last_mut() {
+                steps += 1;
+                if steps > MAX_CYCLE_SEARCH_STEPS {
+                    worst.is_lower_bound = true;
+                    return worst;
+                }
+                let (node, total) = (*node, *total);
+                if let Some(&(to, hidden)) = anchor_edges[node].get(*next_edge) {
+                    *next_edge += 1;
+                    if to == start {
+                        worst.cycles = worst.cycles.max(total + hidden);
+                    } else if to > start && !on_path[to] {
+                        on_path[to] = true;
+                        stack.push((to, 0, total + hidden));
+                    }
+                } else {
+                    on_path[node] = false;
+                    stack.pop();
+                }
+            }
+        }
+        worst
+    }
+
+    /// Largest hidden latency from every input port to every output port, ignoring paths that go around a loop
+    fn port_hidden_latencies(
+        &self,
+        fanouts: &[Vec<(usize, i64)>],
+        ctx: &ModuleTypingContext,
+    ) -> Vec<PortHiddenLatency> {
+        let mut result = Vec::new();
+        for (from_port, port) in &ctx.md.ports {
+            if port.direction != Direction::Input {
+                continue;
+            }
+            let SubModuleOrWire::Wire(from_wire) =
+                ctx.generation_state[port.declaration_instruction]
+            else {
+                continue;
+            };
+            // Longest path by depth first search, not revisiting nodes on the current path
+            let mut best: Vec<Option<i64>> = vec![None; self.fanins.len()];
+            let mut on_path = vec![false; self.fanins.len()];
+            let mut stack: Vec<(usize, usize, i64)> = vec![(from_wire.get_hidden_value(), 0, 0)];
+            on_path[from_wire.get_hidden_value()] = true;
+            let mut steps = 0;
+            let mut is_lower_bound = false;
+            while let Some((node, next_edge, total)) = stack.last_mut() {
+                steps += 1;
+                if steps > MAX_CYCLE_SEARCH_STEPS {
+                    is_lower_bound = true;
+                    break;
+                }
+                let (node, total) = (*node, *total);
+                if let Some(&(to, hidden)) = fanouts[node].get(*next_edge) {
+                    *next_edge += 1;
+                    let new_total = total + hidden;
+                    if !on_path[to] && best[to].is_none_or(|b| b < new_total) {
+                        best[to] = Some(new_total);
+                        on_path[to] = true;
+                        stack.push((to, 0, new_total));
+                    }
+                } else {
+                    on_path[node] = false;
+                    stack.pop();
+                }
+            }
+
+            is_lower_bound |= self
+                .has_lower_bound_edge(|n| n == from_wire.get_hidden_value() || best[n].is_some());
+
+            for (to_port, port) in &ctx.md.ports {
+                if port.direction != Direction::Output {
+                    continue;
+                }
+                if let SubModuleOrWire::Wire(to_wire) =
+                    ctx.generation_state[port.declaration_instruction]
+                    && let Some(hidden_latency) = best[to_wire.get_hidden_value()]
+                {
+                    result.push(PortHiddenLatency {
+                        from: from_port,
+                        to: to_port,
+                        hidden_latency,
+                        is_lower_bound,
+                    });
+                }
+            }
+        }
+        result
+    }
+}
+
+impl ModuleTypingContext<'_> {
+    /// Finds all state feedback loops, and their initiation intervals. Requires latencies to have been computed
+    pub fn analyze_feedback_loops(&self) -> FeedbackAnalysis {
+        let graph = HiddenLatencyGraph::new(self);
+        let fanouts = graph.fanouts();
+
+        let mut loops = Vec::new();
+        for component in graph.cyclic_components(&fanouts) {
+            let initiation_interval = graph.worst_cycle(&component, &fanouts);
+            // Cycles without hidden latency are combinational loops, not feedback loops
+            if initiation_interval.cycles <= 0 {
+                continue;
+            }
+            let mut connected_ports: Vec<WireID> = graph
+                .connected_to(&component, &fanouts)
+                .into_iter()
+                .map(WireID::from_hidden_value)
+                .filter(|w| !matches!(self.wires[*w].is_port, IsPort::PlainWire))
+                .collect();
+            connected_ports.sort_unstable();
+            connected_ports.dedup();
+            let mut wires: Vec<WireID> = component
+                .into_iter()
+                .map(WireID::from_hidden_value)
+                .collect();
+            wires.sort_unstable();
+            let state_wires = wires
+                .iter()
+                .copied()
+                .filter(|w| {
+                    matches!(
+                        self.wires[*w].source,
+                        RealWireDataSource::Multiplexer {
+                            is_state: Some(_),
+                            ..
+                        }
+                    )
+                })
+                .collect();
+            loops.push(FeedbackLoop {
+                wires,
+                state_wires,
+                connected_ports,
+                initiation_interval,
+            });
+        }
+
+        FeedbackAnalysis {
+            loops,
+            port_hidden_latencies: graph.port_hidden_latencies(&fanouts, self),
+        }
+    }
+}
+
+impl SubModule {
+    /// How many cycles the output of a builtin `LatencyOffset` with a negative `OFFSET` runs ahead of its input. Latency counting can't see these cycles
+    pub fn negative_latency_offset(&self, linker: &Linker) -> Option<i64> {
+        let sm_md = &linker.modules[self.refers_to.id];
+        if sm_md.link_info.is_extern != IsExtern::Builtin || sm_md.link_info.name != "LatencyOffset"
+        {
+            return None;
+        }
+        let instance = self.instance.get()?;
+        let latency_of = |direction| {
+            instance
+                .interface_ports
+                .iter()
+                .find_map(|(_, p)| p.as_ref().filter(|p| p.direction == direction))
+                .map(|p| p.absolute_latency.unwrap())
+        };
+        let claimed = latency_of(Direction::Output)? - latency_of(Direction::Input)?;
+        (claimed < 0).then_some(-claimed)
+    }
+}
+
+impl InstantiatedModule {
+    /// The worst initiation interval of the feedback loops that run through the ports of this interface
+    pub fn interface_initiation_interval(
+        &self,
+        md: &Module,
+        interface: InterfaceID,
+    ) -> Option<InitiationInterval> {
+        self.feedback
+            .initiation_interval_of(|w| match self.wires[w].is_port {
+                IsPort::Port(port, _) => port_is_in_interface(md, port, interface),
+                IsPort::PlainWire | IsPort::SubmodulePort(..) => false,
+            })
+    }
+
+    /// The worst initiation interval of the feedback loops in this module that run through the given interface of a submodule
+    pub fn submodule_interface_initiation_interval(
+        &self,
+        sm_md: &Module,
+        submodule: SubModuleID,
+        interface: InterfaceID,
+    ) -> Option<InitiationInterval> {
+        self.feedback
+            .initiation_interval_of(|w| match self.wires[w].is_port {
+                IsPort::SubmodulePort(sm, port, _) => {
+                    sm == submodule && port_is_in_interface(sm_md, port, interface)
+                }
+                IsPort::PlainWire | IsPort::Port(..) => false,
+            })
+    }
+}
+
+fn port_is_in_interface(md: &Module, port: PortID, interface: InterfaceID) -> bool {
+    match &md.link_info.instructions[md.ports[port].declaration_instruction] {
+        Instruction::Declaration(decl) => {
+            matches!(decl.decl_kind, DeclarationKind::Port { parent_interface, .. } if parent_interface == interface)
+        }
+        // The trigger port of an action or trigger is declared by the interface itself
+        Instruction::Interface(interface_decl) => interface_decl.interface_id == interface,
+        _ => unreachable!("Ports are declared by a Declaration or an Interface"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{compile_text, errors_in_file, errors_with_code, instances_of};
+
+    fn feedback_of(module_body: &str) -> FeedbackAnalysis {
+        let (linker, file) = compile_text(&format!("module feedback {{\n{module_body}\n}}"));
+        let errs = errors_in_file(&linker, file);
+        assert!(errs.is_empty(), "{errs:?}");
+        instances_of(&linker, "feedback")
+            .pop()
+            .unwrap()
+            .feedback
+            .clone()
+    }
+
+    fn initiation_intervals(loop_body: &str) -> Vec<i64> {
+        feedback_of(&format!(
+            "
+    input bool x'0
+    output bool y'2
+    state bool acc
+    initial acc = false
+    {loop_body}
+    y = acc
+"
+        ))
+        .loops
+        .iter()
+        .map(|l| l.initiation_interval.cycles)
+        .collect()
+    }
+
+    #[test]
+    fn test_state_loop_initiation_interval() {
+        assert_eq!(initiation_intervals("acc = acc ^ x"), [1]);
+    }
+
+    #[test]
+    fn test_negative_offset_initiation_interval() {
+        // The state takes one cycle, the LatencyOffset gives back the cycles the registers need
+        assert_eq!(
+            initiation_intervals(
+                "LatencyOffset #(T: type bool, OFFSET: -2) lo\n    reg reg acc = lo(acc) ^ x"
+            ),
+            [3]
+        );
+        assert_eq!(
+            initiation_intervals(
+                "LatencyOffset #(T: type bool, OFFSET: -3) lo\n    reg reg acc = lo(acc) ^ x"
+            ),
+            [4]
+        );
+    }
+
+    /// Every node feeds every node with one hidden cycle
+    fn complete_graph(num_nodes: usize) -> HiddenLatencyGraph {
+        HiddenLatencyGraph {
+            fanins: (0..num_nodes)
+                .map(|_| {
+                    (0..num_nodes)
+                        .map(|from| (WireID::from_hidden_value(from), 1))
+                        .collect()
+                })
+                .collect(),
+            lower_bound_edges: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_worst_cycle_is_exact_on_small_graphs() {
+        let graph = complete_graph(3);
+        let fanouts = graph.fanouts();
+        let ii = graph.worst_cycle(&[0, 1, 2], &fanouts);
+        assert_eq!(
+            ii,
+            InitiationInterval {
+                cycles: 3,
+                is_lower_bound: false
+            }
+        );
+        assert_eq!(ii.to_string(), "3");
+    }
+
+    #[test]
+    fn test_truncated_cycle_search_is_a_lower_bound() {
+        // Far more simple cycles than MAX_CYCLE_SEARCH_STEPS allows to enumerate
+        let graph = complete_graph(12);
+        let fanouts = graph.fanouts();
+        let component: Vec<usize> = (0..12).collect();
+        let ii = graph.worst_cycle(&component, &fanouts);
+        assert!(ii.is_lower_bound);
+        assert!(ii.cycles <= 12);
+        assert_eq!(ii.to_string(), format!("at least {}", ii.cycles));
+    }
+
+    #[test]
+    fn test_no_feedback_loop() {
+        let feedback = feedback_of(
+            "
+    input bool x'0
+    output bool y'1
+    reg y = x
+",
+        );
+        assert!(feedback.loops.is_empty());
+    }
+
+    #[test]
+    fn test_only_negative_offsets_are_hidden() {
+        let hidden_latency = |offset: i64, out_latency: i64| {
+            feedback_of(&format!(
+                "
+    input bool x'0
+    output bool y'{out_latency}
+    LatencyOffset #(T: type bool, OFFSET: {offset}) lo
+    y = lo(x)
+"
+            ))
+            .port_hidden_latencies
+            .iter()
+            .map(|p| p.hidden_latency)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(hidden_latency(2, 2), [0]);
+        assert_eq!(hidden_latency(0, 0), [0]);
+        assert_eq!(hidden_latency(-2, -2), [2]);
+    }
+
+    #[test]
+    fn test_impossible_initiation_interval() {
+        // Two registers in the loop, but the LatencyOffset only gives back one cycle
+        let (linker, file) = compile_text(
+            "
+module feedback {
+    input bool x'0
+    output bool y'2
+    state bool acc
+    initial acc = false
+    LatencyOffset #(T: type bool, OFFSET: -1) lo
+    reg reg acc = lo(acc) ^ x
+    y = acc
+}
+",
+        );
+        assert!(
+            !errors_with_code(&linker, file, ErrorCode::ImpossibleInitiationInterval).is_empty()
+        );
+        assert!(errors_with_code(&linker, file, ErrorCode::NetPositiveLatencyCycle).is_empty());
+    }
+
+    #[test]
+    fn test_state_loop_without_offset_is_not_an_initiation_interval() {
+        // Without a negative LatencyOffset the loop never claimed to meet an initiation interval
+        let (linker, file) = compile_text(
+            "
+module feedback {
+    input bool x'0
+    output bool y'1
+    state bool acc
+    initial acc = false
+    reg acc = acc ^ x
+    y = acc
+}
+",
+        );
+        assert!(!errors_with_code(&linker, file, ErrorCode::NetPositiveLatencyCycle).is_empty());
+        assert!(
+            errors_with_code(&linker, file, ErrorCode::ImpossibleInitiationInterval).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_register_loop_is_not_an_initiation_interval() {
+        let (linker, file) = compile_text(
+            "
+module feedback {
+    input bool x'0
+    output bool y'1
+    bool w
+    reg w = w ^ x
+    y = w
+}
+",
+        );
+        assert!(!errors_with_code(&linker, file, ErrorCode::NetPositiveLatencyCycle).is_empty());
+        assert!(
+            errors_with_code(&linker, file, ErrorCode::ImpossibleInitiationInterval).is_empty()
+        );
+    }
+}
@@ -34,7 +34,7 @@ const SEPARATE_SEED_OFFSET: i64 = 1000;
 /// A wire for which a latency has been specified.
 ///
 /// Provided as a list to [solve_latencies].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SpecifiedLatency {
     pub node: usize,
     pub latency: i64,
@@ -62,7 +62,7 @@ pub enum LatencyCountingError {
 }
 
 /// A graph connection from (respectively to) another wire, which specifies the minimal (respectively maximal) difference in latency between them.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FanInOut {
     pub to_node: usize,
     /// If None, then this is a poisoned edge
@@ -527,7 +527,7 @@ fn find_positive_latency_cycle(
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct LatencyCountingPorts {
     /// All inputs come first, then all outputs
     port_nodes: Vec<usize>,
@@ -958,7 +958,7 @@ mod tests {
 
     fn normalize_specified_latency_lists(list: &mut [Vec<SpecifiedLatency>]) {
         for l in list.iter_mut() {
-            l.sort_by(|a, b| a.node.cmp(&b.node));
+            l.sort_by_key(|a| a.node);
             let offset = l[0].latency;
             for v in l {
                 v.latency -= offset;
@@ -4,7 +4,7 @@ use std::{
 };
 
 /// Basically `Vec<Vec<T>>`, but reduces pointer chasing by laying the nested vectors all out sequentially. Read-only.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ListOfLists<T> {
     buf: Vec<T>,
     // A list of #groups+1 offsets in buf array. The end of each one is the start of the next one. They are laid out sequentially
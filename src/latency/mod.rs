@@ -1,7 +1,10 @@
 mod latency_algorithm;
 mod list_of_lists;
 
+pub mod initiation_interval;
 pub mod port_latency_inference;
+pub mod problem_json;
+pub mod solve_cache;
 
 use std::fmt::{Debug, Display, Write};
 use std::{cmp::max, iter::zip};
@@ -9,15 +12,22 @@ use std::{cmp::max, iter::zip};
 use crate::alloc::zip_eq;
 use crate::dev_aid::dot_graphs::display_latency_count_graph;
 use crate::errors::ErrorInfoObject;
+use crate::flattening::DomainInfo;
+use crate::linker::IsExtern;
 use crate::prelude::*;
 use crate::to_string::display_join;
+use crate::util::lcm;
 
 use latency_algorithm::{
     FanInOut, LatencyCountingError, LatencyCountingPorts, SpecifiedLatency,
-    add_cycle_to_extra_fanin, is_valid, solve_latencies,
+    add_cycle_to_extra_fanin, is_valid,
 };
 
 use self::list_of_lists::ListOfLists;
+use self::problem_json::{
+    LatencyProblemKind, LatencyProblemOutcome, NOT_AN_INFERENCE_PROBLEM, SerializedLatencyProblem,
+    describe_error,
+};
 
 use crate::instantiation::*;
 
@@ -123,10 +133,101 @@ fn make_path_info_string(
     result
 }
 
+/// How a domain's cycles map onto the common time base that latency counting works in.
+///
+/// Domains related by [crate::flattening::ClockRatio]s share a `group`, and are counted in ticks, such that one cycle of every domain in the group is a whole number of ticks.
+/// Unrelated domains are their own group, with one tick per cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainTimeBase {
+    pub group: DomainID,
+    pub ticks_per_cycle: i64,
+}
+
+impl DomainTimeBase {
+    /// Wires may also be on leftover domains that aren't associated with any port. These are unrelated to all others
+    pub fn of(time_bases: &FlatAlloc<DomainTimeBase, DomainIDMarker>, domain: DomainID) -> Self {
+        time_bases.get(domain).copied().unwrap_or(DomainTimeBase {
+            group: domain,
+            ticks_per_cycle: 1,
+        })
+    }
+    /// The one place ticks are converted back to cycles of this domain. Only whole cycles exist, so `rounding` picks the clock edge.
+    pub fn ticks_to_cycles(&self, ticks: i64, rounding: Rounding) -> i64 {
+        match rounding {
+            Rounding::Up => -(-ticks).div_euclid(self.ticks_per_cycle),
+            Rounding::Down => ticks.div_euclid(self.ticks_per_cycle),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// For the moment a value exists: it is only available at the first clock edge of its domain after it was produced
+    Up,
+    /// For the most a latency may be: an inferred latency must not exceed the number of ticks the rest of the module allows
+    Down,
+}
+
+/// Domains must only refer to earlier domains in their [crate::flattening::ClockRatio], which [crate::flattening] guarantees.
+///
+/// Fails with the first domain whose number of ticks per cycle doesn't fit in an [i64]. [crate::flattening] reports this as [ErrorCode::InvalidClockRatio] and drops that ratio, see [compute_domain_time_bases].
+pub fn try_compute_domain_time_bases(
+    domains: &FlatAlloc<DomainInfo, DomainIDMarker>,
+) -> Result<FlatAlloc<DomainTimeBase, DomainIDMarker>, DomainID> {
+    // Period of every domain, in cycles of its group's root domain, as (numerator, denominator)
+    let mut periods: FlatAlloc<(DomainID, u64, u64), DomainIDMarker> = FlatAlloc::new();
+    for (id, d) in domains {
+        let period = if let Some(ratio) = &d.clock_ratio {
+            let (group, base_num, base_den) = periods[ratio.base];
+            let (Some(num), Some(den)) = (
+                base_num.checked_mul(ratio.denominator),
+                base_den.checked_mul(ratio.numerator),
+            ) else {
+                return Err(id);
+            };
+            let divisor = crate::util::gcd(num, den);
+            (group, num / divisor, den / divisor)
+        } else {
+            (periods.get_next_alloc_id(), 1, 1)
+        };
+        periods.alloc(period);
+    }
+
+    let mut ticks_per_group_root = domains.map(|_| 1);
+    for (id, &(group, _, den)) in &periods {
+        ticks_per_group_root[group] = lcm(ticks_per_group_root[group], den).ok_or(id)?;
+    }
+
+    let mut time_bases = FlatAlloc::new();
+    for (id, &(group, num, den)) in &periods {
+        // ticks_per_group_root is a multiple of den, dividing first keeps the product small
+        let ticks_per_cycle = (ticks_per_group_root[group] / den)
+            .checked_mul(num)
+            .and_then(|ticks| i64::try_from(ticks).ok())
+            .ok_or(id)?;
+        time_bases.alloc(DomainTimeBase {
+            group,
+            ticks_per_cycle,
+        });
+    }
+    Ok(time_bases)
+}
+
+/// For the domains of a flattened module, whose clock ratios were already checked with [try_compute_domain_time_bases]
+pub fn compute_domain_time_bases(
+    domains: &FlatAlloc<DomainInfo, DomainIDMarker>,
+) -> FlatAlloc<DomainTimeBase, DomainIDMarker> {
+    try_compute_domain_time_bases(domains)
+        .expect("Clock ratios that overflow the time base are removed during flattening")
+}
+
 /// We do all Domains together, as this simplifies the code.
+///
+/// All latencies in this problem are expressed in ticks of the [DomainTimeBase] of their wire
 pub struct LatencyCountingProblem {
     pub map_wire_to_latency_node: FlatAlloc<usize, WireIDMarker>,
     pub map_latency_node_to_wire: Vec<WireID>,
+    pub domain_time_bases: FlatAlloc<DomainTimeBase, DomainIDMarker>,
 
     pub ports: LatencyCountingPorts,
     pub specified_latencies: Vec<SpecifiedLatency>,
@@ -140,6 +241,11 @@ impl LatencyCountingProblem {
         let mut map_latency_node_to_wire = Vec::new();
         let mut specified_latencies = Vec::new();
 
+        let domain_time_bases = compute_domain_time_bases(&ctx.md.domains);
+        let time_base_of =
+            |wire: WireID| DomainTimeBase::of(&domain_time_bases, ctx.wires[wire].domain);
+        let ticks_of = |wire: WireID| time_base_of(wire).ticks_per_cycle;
+
         let map_wire_to_latency_node = ctx.wires.map(|(w_id, w)| {
             // Create mappings
             let node = map_latency_node_to_wire.len();
@@ -147,6 +253,7 @@ impl LatencyCountingProblem {
 
             // Add specifieds
             if let Some(latency) = w.specified_latency.get() {
+                let latency = latency * ticks_of(w_id);
                 specified_latencies.push(SpecifiedLatency { node, latency });
             }
 
@@ -164,15 +271,16 @@ impl LatencyCountingProblem {
 
         // Basic wire-based edges
         let mut edges: Vec<(usize, FanInOut)> = Vec::new();
-        for (_id, w, wire_lat_node) in zip_eq(ctx.wires.iter(), map_wire_to_latency_node.iter()) {
-            // Wire to wire Fanin
+        for (w_id, w, wire_lat_node) in zip_eq(ctx.wires.iter(), map_wire_to_latency_node.iter()) {
+            // Wire to wire Fanin. Registers are clocked by the domain of the wire they write to
+            let ticks = ticks_of(w_id);
             w.source
                 .iter_sources_with_min_latency(|from, delta_latency| {
                     edges.push((
                         *wire_lat_node,
                         FanInOut {
                             to_node: map_wire_to_latency_node[from],
-                            delta_latency: Some(delta_latency),
+                            delta_latency: Some(delta_latency * ticks),
                         },
                     ));
                 });
@@ -185,17 +293,13 @@ impl LatencyCountingProblem {
             let sm_md = &ctx.linker.modules[sm.refers_to.id];
 
             if let Some(instance) = sm.instance.get() {
-                // The module has already been instantiated, so we know all local absolute latencies
+                // The module has already been instantiated, so its summary holds all latencies between its ports
                 // No inference edges, No poison edges
 
-                for d in sm_md.domains.id_range() {
-                    for (_, port, wire) in
-                        crate::alloc::zip_eq(&instance.interface_ports, &sm.port_map)
-                    {
-                        if let (Some(port), Some(wire)) = (port, wire)
-                            && port.domain == d
-                        {
-                            let latency = port.absolute_latency.unwrap();
+                for (_, domain_ports) in &instance.latency_summary.port_latencies_per_domain {
+                    for &(port_id, latency) in domain_ports {
+                        if let Some(wire) = &sm.port_map[port_id] {
+                            let latency = latency * ticks_of(wire.maps_to_wire);
                             let node = map_wire_to_latency_node[wire.maps_to_wire];
                             cur_cycle.push(SpecifiedLatency { latency, node });
                         }
@@ -208,6 +312,7 @@ impl LatencyCountingProblem {
                     for &(port_id, latency) in pg {
                         if let Some(port) = &sm.port_map[port_id] {
                             let node = map_wire_to_latency_node[port.maps_to_wire];
+                            let latency = latency * ticks_of(port.maps_to_wire);
                             cur_cycle.push(SpecifiedLatency { node, latency });
                         }
                     }
@@ -215,6 +320,30 @@ impl LatencyCountingProblem {
                     cur_cycle.clear();
                 }
             }
+
+            // Between synchronous domains, a CrossDomain can't produce its output before its input
+            if sm_md.link_info.is_extern == IsExtern::Builtin
+                && sm_md.link_info.name == "CrossDomain"
+            {
+                let [in_port, out_port] = ["in", "out"].map(|name| {
+                    sm_md
+                        .ports
+                        .find(|_, p| p.name == name)
+                        .and_then(|port_id| sm.port_map[port_id].as_ref())
+                });
+                if let (Some(in_port), Some(out_port)) = (in_port, out_port)
+                    && time_base_of(in_port.maps_to_wire).group
+                        == time_base_of(out_port.maps_to_wire).group
+                {
+                    edges.push((
+                        map_wire_to_latency_node[out_port.maps_to_wire],
+                        FanInOut {
+                            to_node: map_wire_to_latency_node[in_port.maps_to_wire],
+                            delta_latency: Some(0),
+                        },
+                    ));
+                }
+            }
         }
 
         // Finish up by adding a Specified Latencies cycle, because solve_latencies and inferernce expects that
@@ -223,19 +352,22 @@ impl LatencyCountingProblem {
         Self {
             map_wire_to_latency_node,
             map_latency_node_to_wire,
+            domain_time_bases,
             ports,
             specified_latencies,
             edges,
         }
     }
 
+    /// Synchronous domains are related through their common time base, so their ports are grouped together
     fn make_ports_per_domain(&self, ctx: &ModuleTypingContext) -> Vec<Vec<usize>> {
         let mut ports_per_domain_flat = ctx.md.domains.map(|_| Vec::new());
         for (_id, port) in &ctx.md.ports {
             if let SubModuleOrWire::Wire(port_w) =
                 ctx.generation_state[port.declaration_instruction]
             {
-                ports_per_domain_flat[port.domain].push(self.map_wire_to_latency_node[port_w]);
+                let group = self.domain_time_bases[port.domain].group;
+                ports_per_domain_flat[group].push(self.map_wire_to_latency_node[port_w]);
             }
         }
         let mut ports_per_domain = ports_per_domain_flat.into_vec();
@@ -267,6 +399,8 @@ pub enum InferenceFailure {
 pub struct LatencyInferenceProblem {
     pub latency_count_problem: LatencyCountingProblem,
     pub algo_inference_problem: Option<latency_algorithm::LatencyInferenceProblem>,
+    /// For `--debug dump-latency-problem-json`: the module name and the problem. The queries are only known once [Self::infer] is called, see [Self::dump_json]
+    json_dump: Option<(String, SerializedLatencyProblem)>,
 }
 impl LatencyInferenceProblem {
     pub fn new(ctx: &ModuleTypingContext) -> Self {
@@ -286,23 +420,65 @@ impl LatencyInferenceProblem {
             }
         }
 
+        let fanins = lc.make_fanins();
+        let json_dump = crate::debug::is_enabled("dump-latency-problem-json").then(|| {
+            let problem = SerializedLatencyProblem::new(
+                LatencyProblemKind::Inference,
+                &fanins,
+                &lc.ports,
+                &lc.specified_latencies,
+                &lc.make_ports_per_domain(ctx),
+            );
+            (ctx.name.clone(), problem)
+        });
+
         let algo_inference_problem = latency_algorithm::LatencyInferenceProblem::new(
-            lc.make_fanins(),
+            fanins,
             &lc.ports,
             &lc.specified_latencies,
         );
+        let json_dump = json_dump.map(|(module_name, mut problem)| {
+            if algo_inference_problem.is_none() {
+                problem.expected = Some(LatencyProblemOutcome::Failed {
+                    error: NOT_AN_INFERENCE_PROBLEM.to_string(),
+                });
+            }
+            (module_name, problem)
+        });
         LatencyInferenceProblem {
             algo_inference_problem,
             latency_count_problem: lc,
+            json_dump,
         }
     }
-    pub fn infer(&mut self, from: WireID, to: WireID) -> Result<i64, InferenceFailure> {
-        let from = self.latency_count_problem.map_wire_to_latency_node[from];
-        let to = self.latency_count_problem.map_wire_to_latency_node[to];
-        if let Some(inf_prob) = &mut self.algo_inference_problem {
+    /// The inferred latency is expressed in cycles of the domain of `from`, rounded down such that it still fits in the allowed ticks
+    pub fn infer(
+        &mut self,
+        from: WireID,
+        to: WireID,
+        from_domain: DomainID,
+    ) -> Result<i64, InferenceFailure> {
+        let lc = &self.latency_count_problem;
+        let time_base = DomainTimeBase::of(&lc.domain_time_bases, from_domain);
+        let from = lc.map_wire_to_latency_node[from];
+        let to = lc.map_wire_to_latency_node[to];
+        let ticks = if let Some(inf_prob) = &mut self.algo_inference_problem {
             inf_prob.infer_max_edge_latency(from, to)
         } else {
             Err(InferenceFailure::BadProblem)
+        };
+        if let Some((_, problem)) = &mut self.json_dump {
+            problem.add_inference_query(from, to, ticks.ok());
+        }
+        ticks.map(|ticks| time_base.ticks_to_cycles(ticks, Rounding::Down))
+    }
+    /// For `--debug dump-latency-problem-json`, call once all queries are made.
+    /// Inference is retried until nothing is left to infer, so attempts without queries are skipped. They would overwrite the one that did the work
+    pub fn dump_json(&self) {
+        if let Some((module_name, problem)) = &self.json_dump
+            && !problem.inference_queries.is_empty()
+        {
+            problem.dump(module_name);
         }
     }
 }
@@ -386,12 +562,39 @@ impl ModuleTypingContext<'_> {
 
         let ports_per_domain = problem.make_ports_per_domain(self);
 
-        match solve_latencies(
-            fanins,
-            &problem.ports,
-            &problem.specified_latencies,
-            &ports_per_domain,
-        ) {
+        let mut json_dump = crate::debug::is_enabled("dump-latency-problem-json").then(|| {
+            SerializedLatencyProblem::new(
+                LatencyProblemKind::Counting,
+                &fanins,
+                &problem.ports,
+                &problem.specified_latencies,
+                &ports_per_domain,
+            )
+        });
+
+        let solution = self
+            .linker
+            .instantiator
+            .borrow_mut()
+            .latency_solve_cache
+            .solve(
+                fanins,
+                &problem.ports,
+                &problem.specified_latencies,
+                &ports_per_domain,
+            );
+        if let Some(json_dump) = &mut json_dump {
+            json_dump.expected = Some(match &solution {
+                Ok(latencies) => LatencyProblemOutcome::Solved {
+                    latencies: latencies.clone(),
+                },
+                Err(err) => LatencyProblemOutcome::Failed {
+                    error: describe_error(err),
+                },
+            });
+            json_dump.dump(&self.name);
+        }
+        match solution {
             Ok(latencies) => {
                 if crate::debug::is_enabled("dot-latency-solution") {
                     display_latency_count_graph(
@@ -407,7 +610,9 @@ impl ModuleTypingContext<'_> {
                 for (node, lat) in zip(problem.map_latency_node_to_wire.iter(), latencies.iter()) {
                     let wire = &mut self.wires[*node];
                     if is_valid(*lat) {
-                        wire.absolute_latency = AbsLat::new(*lat);
+                        let time_base = DomainTimeBase::of(&problem.domain_time_bases, wire.domain);
+                        wire.absolute_latency =
+                            AbsLat::new(time_base.ticks_to_cycles(*lat, Rounding::Up));
                     } else {
                         let source_location = self
                             .md
@@ -415,6 +620,7 @@ impl ModuleTypingContext<'_> {
                             .get_instruction_span(wire.original_instruction);
                         self.errors.error(
                             source_location,
+                            ErrorCode::UnreachableLatencyNode,
                             "Latency Counting couldn't reach this node".to_string(),
                         );
                         wire.absolute_latency = AbsLat::UNKNOWN;
@@ -466,11 +672,28 @@ impl ModuleTypingContext<'_> {
         connection_list
     }
 
+    /// Only a negative LatencyOffset lets a cycle claim fewer cycles than it takes, so only such a cycle is a feedback loop throttled by its initiation interval. Any other cycle, even one through a `state`, is a plain net-positive latency cycle
+    fn has_negative_latency_offset(
+        &self,
+        latency_node_meanings: &[WireID],
+        conflict_path: &[SpecifiedLatency],
+    ) -> bool {
+        conflict_path.iter().any(|elem| {
+            let wire = &self.wires[latency_node_meanings[elem.node]];
+            match wire.is_port {
+                IsPort::SubmodulePort(sm_id, _, _) => self.submodules[sm_id]
+                    .negative_latency_offset(self.linker)
+                    .is_some(),
+                _ => false,
+            }
+        })
+    }
+
     fn report_error(&self, latency_node_meanings: &[WireID], err: LatencyCountingError) {
         let mut error_placed_successfully = false;
-        let mut error = |span, msg: String| {
+        let mut error = |span, code, msg: String| {
             error_placed_successfully = true;
-            self.errors.error(span, msg)
+            self.errors.error(span, code, msg)
         };
 
         match err {
@@ -494,9 +717,23 @@ impl ModuleTypingContext<'_> {
                     first_write_desired_latency,
                     writes_involved.last().unwrap().to_latency,
                 );
-                let rest_of_message = format!(
-                    " part of a net-positive latency cycle of +{net_roundtrip_latency}\n\n{path_message}\nWhich conflicts with the starting latency"
-                );
+                let (code, rest_of_message) = if self
+                    .has_negative_latency_offset(latency_node_meanings, &conflict_path)
+                {
+                    (
+                        ErrorCode::ImpossibleInitiationInterval,
+                        format!(
+                            " part of a state feedback loop that can't meet its requested initiation interval. An iteration needs {net_roundtrip_latency} more cycle(s) than the loop allows\n\n{path_message}\nWhich conflicts with the starting latency. Increase the negative LatencyOffset, or remove registers from the loop"
+                        ),
+                    )
+                } else {
+                    (
+                        ErrorCode::NetPositiveLatencyCycle,
+                        format!(
+                            " part of a net-positive latency cycle of +{net_roundtrip_latency}\n\n{path_message}\nWhich conflicts with the starting latency"
+                        ),
+                    )
+                };
 
                 /*let unique_write_instructions =
                     filter_unique_write_flats(&writes_involved, &self.md.link_info.instructions);
@@ -531,6 +768,7 @@ impl ModuleTypingContext<'_> {
                     let to_instr = &self.md.link_info.instructions[wr.to_wire.original_instruction];
                     error(
                         to_instr.get_span(),
+                        code,
                         format!("This instruction is{rest_of_message}"),
                     );
                 }
@@ -542,6 +780,7 @@ impl ModuleTypingContext<'_> {
                     let port_name_span = self.md.link_info.instructions[port_instr].get_span();
                     error(
                         port_name_span,
+                        ErrorCode::IndeterminablePortLatency,
                         format!(
                             "Cannot determine port latency. Options are {a} and {b}\nTry specifying an explicit latency or rework the module to remove this ambiguity"
                         ),
@@ -574,7 +813,7 @@ impl ModuleTypingContext<'_> {
                     for non_hit in ports_not_in_group {
                         let node_instr_span = self.md.link_info.instructions[*non_hit].get_span();
 
-                        error(node_instr_span, format!("This port is not strongly connected to the strongly connected port cluster {strongly_connected_port_list}.\nAn input and output port are strongly connected if there is a direct dependency path from the input port to the output port.\nStrongly connected ports are also transitive.\nIf you do not wish to change your design, then 'virtually' connect this port to the strongly connected cluster by explicitly annotating its absolute latency."))
+                        error(node_instr_span, ErrorCode::PortsNotStronglyConnected, format!("This port is not strongly connected to the strongly connected port cluster {strongly_connected_port_list}.\nAn input and output port are strongly connected if there is a direct dependency path from the input port to the output port.\nStrongly connected ports are also transitive.\nIf you do not wish to change your design, then 'virtually' connect this port to the strongly connected cluster by explicitly annotating its absolute latency."))
                             .add_info_list(hit_names_error_infos.clone());
                     }
                 }
@@ -601,10 +840,187 @@ impl ModuleTypingContext<'_> {
 
                 let end_name = &end_wire.name;
                 let specified_end_latency = end_wire.specified_latency.unwrap();
-                error(end_latency_decl.span, format!("Conflicting specified latency\n\n{path_message}\nBut this was specified as {end_name}'{specified_end_latency}"))
+                let err = error(end_latency_decl.span, ErrorCode::ConflictingSpecifiedLatency, format!("Conflicting specified latency\n\n{path_message}\nBut this was specified as {end_name}'{specified_end_latency}"))
                     .info_obj_same_file(start_decl);
+                // The path is counted in ticks, only suggest a fix if it lands on a whole cycle
+                let time_bases = compute_domain_time_bases(&self.md.domains);
+                let ticks_per_cycle =
+                    DomainTimeBase::of(&time_bases, end_wire.domain).ticks_per_cycle;
+                let path_end_latency = conflict_path.last().unwrap().latency;
+                if path_end_latency % ticks_per_cycle == 0 {
+                    err.suggest_replace(
+                        end_latency_decl.span,
+                        (path_end_latency / ticks_per_cycle).to_string(),
+                    );
+                }
             }
         }
         assert!(error_placed_successfully);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flattening::ClockRatio;
+    use crate::test_util::{compile_text, errors_in_file, errors_with_code, instance_names};
+
+    fn mk_domains(ratios: &[Option<(usize, u64, u64)>]) -> FlatAlloc<DomainInfo, DomainIDMarker> {
+        let mut domains = FlatAlloc::new();
+        for (idx, r) in ratios.iter().enumerate() {
+            domains.alloc(DomainInfo {
+                name: format!("d{idx}"),
+                name_span: None,
+                clock_ratio: r.map(|(base, numerator, denominator)| ClockRatio {
+                    base: DomainID::from_hidden_value(base),
+                    numerator,
+                    denominator,
+                    span: Span::MAX_POSSIBLE_SPAN,
+                }),
+            });
+        }
+        domains
+    }
+
+    fn ticks_and_groups(domains: &FlatAlloc<DomainInfo, DomainIDMarker>) -> Vec<(usize, i64)> {
+        compute_domain_time_bases(domains)
+            .iter()
+            .map(|(_, tb)| (tb.group.get_hidden_value(), tb.ticks_per_cycle))
+            .collect()
+    }
+
+    #[test]
+    fn test_unrelated_domains() {
+        let domains = mk_domains(&[None, None]);
+        assert_eq!(ticks_and_groups(&domains), [(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_clock_ratio_time_base() {
+        // clk, fast = 2 * clk, slow = clk / 3, other, other_fast = 3/2 * other
+        let domains = mk_domains(&[
+            None,
+            Some((0, 2, 1)),
+            Some((0, 1, 3)),
+            None,
+            Some((3, 3, 2)),
+        ]);
+        assert_eq!(
+            ticks_and_groups(&domains),
+            [(0, 2), (0, 1), (0, 6), (3, 3), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn test_chained_clock_ratio() {
+        // clk, fast = 2 * clk, faster = 3 * fast
+        let domains = mk_domains(&[None, Some((0, 2, 1)), Some((1, 3, 1))]);
+        assert_eq!(ticks_and_groups(&domains), [(0, 6), (0, 3), (0, 1)]);
+    }
+
+    #[test]
+    fn test_clock_ratio_overflow() {
+        // clk, fast = 2^40 * clk, faster = 2^40 * fast
+        let domains = mk_domains(&[None, Some((0, 1 << 40, 1)), Some((1, 1 << 40, 1))]);
+        assert_eq!(
+            try_compute_domain_time_bases(&domains).unwrap_err(),
+            DomainID::from_hidden_value(2)
+        );
+        // slow = clk / 2^40, other = clk * 3^40 don't share a common tick that fits
+        let domains = mk_domains(&[None, Some((0, 1, 1 << 40)), Some((0, 3u64.pow(40), 1))]);
+        assert!(try_compute_domain_time_bases(&domains).is_err());
+    }
+
+    #[test]
+    fn test_ticks_to_cycles_rounding() {
+        let tb = DomainTimeBase {
+            group: DomainID::MAIN_DOMAIN,
+            ticks_per_cycle: 3,
+        };
+        for (ticks, up, down) in [(0, 0, 0), (3, 1, 1), (4, 2, 1), (-3, -1, -1), (-4, -1, -2)] {
+            assert_eq!(tb.ticks_to_cycles(ticks, Rounding::Up), up);
+            assert_eq!(tb.ticks_to_cycles(ticks, Rounding::Down), down);
+        }
+    }
+
+    #[test]
+    fn test_ratio_domain_end_to_end() {
+        let (linker, file) = compile_text(
+            "
+module ratio_cross {
+    domain clk
+    input bool a'0
+    domain fast_clk = 2 * clk
+    output bool b'3
+
+    CrossDomain #(T: type bool) cr
+    cr.in = a
+    reg reg b = cr.out
+}
+",
+        );
+        assert!(errors_in_file(&linker, file).is_empty());
+
+        // One cycle of clk is two ticks, b is registered twice, at fast_clk ticks 1 and 2, and so can't be at fast_clk'1
+        let (linker, file) = compile_text(
+            "
+module ratio_cross {
+    domain clk
+    input bool a'0
+    domain fast_clk = 2 * clk
+    output bool b'1
+
+    CrossDomain #(T: type bool) cr
+    cr.in = a
+    reg reg b = cr.out
+}
+",
+        );
+        assert_eq!(
+            errors_with_code(&linker, file, ErrorCode::ConflictingSpecifiedLatency).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_ratio_domain_inference_rounds_down() {
+        // b'3 allows 3 ticks of fast_clk after a, lo is on clk with 2 ticks per cycle, so it may only take 1 cycle
+        let (linker, file) = compile_text(
+            "
+module ratio_infer {
+    domain clk
+    input bool a'0
+    domain fast_clk = 2 * clk
+    output bool b'3
+
+    LatencyOffset #(T: type bool) lo
+    CrossDomain #(T: type bool) cr
+    cr.in = lo(a)
+    b = cr.out
+}
+",
+        );
+        assert!(errors_in_file(&linker, file).is_empty());
+        assert_eq!(
+            instance_names(&linker, "LatencyOffset"),
+            ["LatencyOffset #(T: type bool #(), OFFSET: 1)"]
+        );
+    }
+
+    #[test]
+    fn test_overflowing_clock_ratio() {
+        let (linker, file) = compile_text(
+            "
+module ratio_overflow {
+    domain clk
+    domain fast_clk = 4294967296 * clk
+    domain faster_clk = 4294967296 * fast_clk
+}
+",
+        );
+        assert_eq!(
+            errors_with_code(&linker, file, ErrorCode::InvalidClockRatio).len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! A stable JSON format for latency counting and latency inference problems, and a standalone solver for them.
+//!
+//! Problems are dumped with `--debug dump-latency-problem-json`, and solved with `sus_compiler latency-solve <file.json>`.
+//! Only the graph is stored, no wire names or source locations. That way a corpus of problems can be shared without sharing the designs.
+//! Along with the graph, the compiler stores the outcome it got, and `latency-solve` fails when it gets a different one.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::flattening::Direction;
+use crate::prelude::*;
+use crate::to_string::join_shorten_filename;
+
+use super::latency_algorithm::{
+    FanInOut, LatencyCountingError, LatencyCountingPorts, LatencyInferenceProblem,
+    SpecifiedLatency, solve_latencies,
+};
+use super::list_of_lists::ListOfLists;
+
+/// Bump this whenever the format changes in an incompatible way
+pub const LATENCY_PROBLEM_FORMAT_VERSION: u32 = 1;
+
+/// Folder that `--debug dump-latency-problem-json` writes into
+const DUMP_FOLDER: &str = "latency_problems";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyProblemKind {
+    /// Solved by [solve_latencies]
+    Counting,
+    /// Solved by [LatencyInferenceProblem], with [SerializedLatencyProblem::inference_queries]
+    Inference,
+}
+
+/// `to` is the node whose fanin list this edge is in. `delta_latency: null` marks a poison edge
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SerializedEdge {
+    pub from: usize,
+    pub delta_latency: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SerializedSpecifiedLatency {
+    pub node: usize,
+    pub latency: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SerializedInferenceQuery {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// What the compiler got out of a problem. Latencies are in ticks, see [super::DomainTimeBase]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyProblemOutcome {
+    /// A [LatencyProblemKind::Counting] problem, with the latency of every node
+    Solved { latencies: Vec<i64> },
+    /// A [LatencyProblemKind::Inference] problem, with the inferred latency of every query, or `null` where inference failed
+    Inferred { latencies: Vec<Option<i64>> },
+    /// As described by [describe_error]
+    Failed { error: String },
+}
+
+impl LatencyProblemOutcome {
+    fn summary(&self) -> String {
+        match self {
+            LatencyProblemOutcome::Solved { .. } => "solved".to_string(),
+            LatencyProblemOutcome::Inferred { latencies } => format!(
+                "inferred {}/{} edges",
+                latencies.iter().flatten().count(),
+                latencies.len()
+            ),
+            LatencyProblemOutcome::Failed { error } => format!("error: {error}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedLatencyProblem {
+    pub format_version: u32,
+    pub kind: LatencyProblemKind,
+    /// One fanin list per node
+    pub fanins: Vec<Vec<SerializedEdge>>,
+    pub inputs: Vec<usize>,
+    pub outputs: Vec<usize>,
+    pub specified_latencies: Vec<SerializedSpecifiedLatency>,
+    pub ports_per_domain: Vec<Vec<usize>>,
+    /// For [LatencyProblemKind::Inference]: the maximal latency from-to is inferred for each of these.
+    /// These are the queries the compiler actually made, in order, see [super::LatencyInferenceProblem::infer]
+    #[serde(default)]
+    pub inference_queries: Vec<SerializedInferenceQuery>,
+    /// What the compiler got when it solved this problem. Hand-written problems may leave it out
+    #[serde(default)]
+    pub expected: Option<LatencyProblemOutcome>,
+}
+
+impl SerializedLatencyProblem {
+    pub fn new(
+        kind: LatencyProblemKind,
+        fanins: &ListOfLists<FanInOut>,
+        ports: &LatencyCountingPorts,
+        specified_latencies: &[SpecifiedLatency],
+        ports_per_domain: &[Vec<usize>],
+    ) -> Self {
+        let fanins: Vec<Vec<SerializedEdge>> = fanins
+            .iter()
+            .map(|fanin| {
+                fanin
+                    .iter()
+                    .map(|f| SerializedEdge {
+                        from: f.to_node,
+                        delta_latency: f.delta_latency,
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            format_version: LATENCY_PROBLEM_FORMAT_VERSION,
+            kind,
+            fanins,
+            inputs: ports.inputs().to_vec(),
+            outputs: ports.outputs().to_vec(),
+            specified_latencies: specified_latencies
+                .iter()
+                .map(|s| SerializedSpecifiedLatency {
+                    node: s.node,
+                    latency: s.latency,
+                })
+                .collect(),
+            ports_per_domain: ports_per_domain.to_vec(),
+            inference_queries: Vec::new(),
+            expected: None,
+        }
+    }
+
+    /// Records a query made on a [LatencyProblemKind::Inference] problem, and the latency it inferred. Queries made more than once are stored once
+    pub fn add_inference_query(&mut self, from: usize, to: usize, inferred: Option<i64>) {
+        if self
+            .inference_queries
+            .iter()
+            .any(|q| q.from == from && q.to == to)
+        {
+            return;
+        }
+        self.inference_queries
+            .push(SerializedInferenceQuery { from, to });
+        match &mut self.expected {
+            Some(LatencyProblemOutcome::Inferred { latencies }) => latencies.push(inferred),
+            _ => {
+                self.expected = Some(LatencyProblemOutcome::Inferred {
+                    latencies: vec![inferred],
+                })
+            }
+        }
+    }
+
+    /// Solves this problem the way the compiler does
+    fn solve(&self) -> LatencyProblemOutcome {
+        let fanins = self.make_fanins();
+        let ports = self.make_ports();
+        let specified_latencies = self.make_specified_latencies();
+        match self.kind {
+            LatencyProblemKind::Counting => {
+                match solve_latencies(fanins, &ports, &specified_latencies, &self.ports_per_domain)
+                {
+                    Ok(latencies) => LatencyProblemOutcome::Solved { latencies },
+                    Err(err) => LatencyProblemOutcome::Failed {
+                        error: describe_error(&err),
+                    },
+                }
+            }
+            LatencyProblemKind::Inference => {
+                match LatencyInferenceProblem::new(fanins, &ports, &specified_latencies) {
+                    Some(mut inference) => LatencyProblemOutcome::Inferred {
+                        latencies: self
+                            .inference_queries
+                            .iter()
+                            .map(|q| inference.infer_max_edge_latency(q.from, q.to).ok())
+                            .collect(),
+                    },
+                    None => LatencyProblemOutcome::Failed {
+                        error: NOT_AN_INFERENCE_PROBLEM.to_string(),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Checks all node indices, such that a hand-edited file can't crash the solver
+    fn validate(&self) -> Result<(), String> {
+        if self.format_version != LATENCY_PROBLEM_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported format_version {}, expected {LATENCY_PROBLEM_FORMAT_VERSION}",
+                self.format_version
+            ));
+        }
+        let num_nodes = self.fanins.len();
+        let check = |node: usize, what: &str| {
+            if node < num_nodes {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{what} refers to node {node}, but there are only {num_nodes} nodes"
+                ))
+            }
+        };
+        for fanin in &self.fanins {
+            for e in fanin {
+                check(e.from, "An edge")?;
+            }
+        }
+        for p in self.inputs.iter().chain(&self.outputs) {
+            check(*p, "A port")?;
+        }
+        for s in &self.specified_latencies {
+            check(s.node, "A specified latency")?;
+        }
+        for p in self.ports_per_domain.iter().flatten() {
+            check(*p, "ports_per_domain")?;
+        }
+        for q in &self.inference_queries {
+            check(q.from, "An inference query")?;
+            check(q.to, "An inference query")?;
+        }
+        Ok(())
+    }
+
+    fn make_fanins(&self) -> ListOfLists<FanInOut> {
+        self.fanins
+            .iter()
+            .map(|fanin| {
+                fanin.iter().map(|e| FanInOut {
+                    to_node: e.from,
+                    delta_latency: e.delta_latency,
+                })
+            })
+            .collect()
+    }
+
+    fn make_ports(&self) -> LatencyCountingPorts {
+        let mut ports = LatencyCountingPorts::default();
+        for i in &self.inputs {
+            ports.push(*i, Direction::Input);
+        }
+        for o in &self.outputs {
+            ports.push(*o, Direction::Output);
+        }
+        ports
+    }
+
+    fn make_specified_latencies(&self) -> Vec<SpecifiedLatency> {
+        self.specified_latencies
+            .iter()
+            .map(|s| SpecifiedLatency {
+                node: s.node,
+                latency: s.latency,
+            })
+            .collect()
+    }
+
+    /// Writes this problem to `latency_problems/{module_name}_{kind}.json`
+    pub fn dump(&self, module_name: &str) {
+        let kind = match self.kind {
+            LatencyProblemKind::Counting => "counting",
+            LatencyProblemKind::Inference => "inference",
+        };
+        let folder = PathBuf::from(DUMP_FOLDER);
+        let path = folder.join(join_shorten_filename(module_name, &format!("_{kind}.json")));
+        let result = std::fs::create_dir_all(&folder).and_then(|_| {
+            let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+            std::fs::write(&path, json)
+        });
+        match result {
+            Ok(()) => info!("Dumped latency problem to {}", path.to_string_lossy()),
+            Err(e) => error!(
+                "Could not dump latency problem to {}: {e}",
+                path.to_string_lossy()
+            ),
+        }
+    }
+}
+
+/// [LatencyProblemOutcome::Failed] of an inference problem that [LatencyInferenceProblem::new] rejects
+pub const NOT_AN_INFERENCE_PROBLEM: &str = "not a valid inference problem";
+
+pub fn describe_error(err: &LatencyCountingError) -> String {
+    match err {
+        LatencyCountingError::ConflictingSpecifiedLatencies { conflict_path } => {
+            format!(
+                "conflicting specified latencies along {} nodes",
+                conflict_path.len()
+            )
+        }
+        LatencyCountingError::NetPositiveLatencyCycle {
+            conflict_path,
+            net_roundtrip_latency,
+        } => format!(
+            "net positive latency cycle of +{net_roundtrip_latency} along {} nodes",
+            conflict_path.len()
+        ),
+        LatencyCountingError::IndeterminablePortLatency { bad_ports } => {
+            format!("{} ports with indeterminable latency", bad_ports.len())
+        }
+        LatencyCountingError::PortsNotStronglyConnected { port_partitions } => {
+            format!(
+                "{} domains with ports that aren't strongly connected",
+                port_partitions.len()
+            )
+        }
+    }
+}
+
+/// Loads, solves and times a single problem file. Prints a single line summary. Fails when the outcome isn't the expected one
+fn solve_problem_file(path: &Path) -> Result<(), String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Could not load: {e}"))?;
+    let problem: SerializedLatencyProblem =
+        serde_json::from_str(&text).map_err(|e| format!("Could not load: {e}"))?;
+    problem
+        .validate()
+        .map_err(|e| format!("Could not load: {e}"))?;
+
+    let num_nodes = problem.fanins.len();
+    let num_edges: usize = problem.fanins.iter().map(|f| f.len()).sum();
+
+    let start = Instant::now();
+    let outcome = problem.solve();
+    let elapsed = start.elapsed();
+
+    println!(
+        "{}: {num_nodes} nodes, {num_edges} edges, {} in {:.3}ms",
+        path.to_string_lossy(),
+        outcome.summary(),
+        elapsed.as_secs_f64() * 1000.0
+    );
+    match &problem.expected {
+        Some(expected) if *expected != outcome => Err(format!(
+            "Expected {}, but got {}. Expected: {expected:?}, got: {outcome:?}",
+            expected.summary(),
+            outcome.summary()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Entry point of `sus_compiler latency-solve`
+pub fn latency_solve_main(problem_files: &[PathBuf]) -> ExitCode {
+    let mut any_failed = false;
+    for path in problem_files {
+        if let Err(e) = solve_problem_file(path) {
+            error!("{}: {e}", path.to_string_lossy());
+            any_failed = true;
+        }
+    }
+    if any_failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latency::latency_algorithm::mk_fan;
+
+    #[test]
+    fn test_roundtrip() {
+        let fanins: [&[FanInOut]; 3] = [
+            &[],
+            &[mk_fan(0, 1)],
+            &[mk_fan(1, 2), FanInOut::mk_poison(0)],
+        ];
+        let fanins = ListOfLists::from_slice_slice(&fanins);
+        let ports = LatencyCountingPorts::from_inputs_outputs(&[0], &[2]);
+        let specified = [SpecifiedLatency {
+            node: 1,
+            latency: 5,
+        }];
+
+        let mut problem = SerializedLatencyProblem::new(
+            LatencyProblemKind::Inference,
+            &fanins,
+            &ports,
+            &specified,
+            &[vec![0, 2]],
+        );
+        assert!(problem.inference_queries.is_empty());
+        problem.add_inference_query(0, 2, Some(3));
+        problem.add_inference_query(0, 2, Some(3));
+        let json = serde_json::to_string(&problem).unwrap();
+        let loaded: SerializedLatencyProblem = serde_json::from_str(&json).unwrap();
+        loaded.validate().unwrap();
+
+        assert_eq!(loaded.make_fanins(), fanins);
+        assert_eq!(loaded.make_ports(), ports);
+        assert_eq!(loaded.make_specified_latencies(), specified);
+        assert_eq!(loaded.ports_per_domain, vec![vec![0, 2]]);
+        assert_eq!(loaded.inference_queries.len(), 1);
+        assert_eq!(
+            (
+                loaded.inference_queries[0].from,
+                loaded.inference_queries[0].to
+            ),
+            (0, 2)
+        );
+        assert_eq!(
+            loaded.expected,
+            Some(LatencyProblemOutcome::Inferred {
+                latencies: vec![Some(3)]
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_nodes() {
+        let json = r#"{"format_version":1,"kind":"counting","fanins":[[],[{"from":7,"delta_latency":1}]],"inputs":[],"outputs":[],"specified_latencies":[],"ports_per_domain":[]}"#;
+        let loaded: SerializedLatencyProblem = serde_json::from_str(json).unwrap();
+        assert!(loaded.validate().is_err());
+    }
+
+    #[test]
+    fn test_solve_checks_expected_outcome() {
+        let fanins: [&[FanInOut]; 3] = [&[], &[mk_fan(0, 1)], &[mk_fan(1, 2)]];
+        let fanins = ListOfLists::from_slice_slice(&fanins);
+        let ports = LatencyCountingPorts::from_inputs_outputs(&[0], &[2]);
+        let mut problem = SerializedLatencyProblem::new(
+            LatencyProblemKind::Counting,
+            &fanins,
+            &ports,
+            &[],
+            &[vec![0, 2]],
+        );
+        assert_eq!(
+            problem.solve(),
+            LatencyProblemOutcome::Solved {
+                latencies: vec![0, 1, 3]
+            }
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("sus_latency_problem_{}.json", std::process::id()));
+        let mut check_with = |expected| {
+            problem.expected = Some(expected);
+            std::fs::write(&path, serde_json::to_string(&problem).unwrap()).unwrap();
+            solve_problem_file(&path)
+        };
+        assert!(
+            check_with(LatencyProblemOutcome::Solved {
+                latencies: vec![0, 1, 3]
+            })
+            .is_ok()
+        );
+        assert!(
+            check_with(LatencyProblemOutcome::Solved {
+                latencies: vec![0, 1, 4]
+            })
+            .is_err()
+        );
+        assert!(
+            check_with(LatencyProblemOutcome::Failed {
+                error: "net positive latency cycle".to_string()
+            })
+            .is_err()
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,292 @@
+//! Reuse of latency counting work across instantiations.
+//!
+//! Every instantiated submodule is solved once, and enters the problem of its parent only through its [LatencySummary]:
+//! the latencies of its ports relative to each other. What is inside the submodule doesn't matter to the parent.
+//! So instantiations whose submodules differ inside, but not in their summaries, produce the same problem.
+//! The branches of a `PopCount #(WIDTH)` tree for instance only differ in their depth, so `PopCount #(WIDTH: 48)` and `PopCount #(WIDTH: 64)`,
+//! which both build four levels, pose the same problem.
+//!
+//! Template arguments that only change types, like the `T` of a `FIFO #(T, DEPTH)`, leave the problem untouched as well.
+//! The solution only depends on the graph, so we key it by the whole problem and reuse it.
+
+use std::collections::HashMap;
+
+use super::latency_algorithm::{
+    FanInOut, LatencyCountingError, LatencyCountingPorts, SpecifiedLatency, solve_latencies,
+};
+use super::list_of_lists::ListOfLists;
+use crate::flattening::DomainInfo;
+use crate::instantiation::InstantiatedPort;
+use crate::prelude::*;
+
+/// Problems are kept in full, so this bounds the memory the cache can take. Once full, the least recently used solution makes room
+const MAX_CACHED_SOLUTIONS: usize = 1024;
+
+/// What the parent of an instantiated submodule needs to know about its latencies: per domain of the submodule, the latencies of its ports relative to the earliest one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencySummary {
+    pub port_latencies_per_domain: FlatAlloc<Vec<(PortID, i64)>, DomainIDMarker>,
+}
+
+impl LatencySummary {
+    /// Ports without a latency, like those of an instance that errored, are left out
+    pub fn new(
+        interface_ports: &FlatAlloc<Option<InstantiatedPort>, PortIDMarker>,
+        domains: &FlatAlloc<DomainInfo, DomainIDMarker>,
+    ) -> Self {
+        let mut port_latencies_per_domain = domains.map(|_| Vec::new());
+        for (port_id, port) in interface_ports {
+            if let Some(port) = port
+                && let Some(latency) = port.absolute_latency.get()
+            {
+                port_latencies_per_domain[port.domain].push((port_id, latency));
+            }
+        }
+        for (_, ports) in &mut port_latencies_per_domain {
+            let earliest = ports.iter().map(|(_, lat)| *lat).min().unwrap_or(0);
+            for (_, lat) in ports {
+                *lat -= earliest;
+            }
+        }
+        Self {
+            port_latencies_per_domain,
+        }
+    }
+}
+
+/// Everything [solve_latencies] looks at. Two equal keys always produce the same solution
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct LatencyProblemKey {
+    fanins: ListOfLists<FanInOut>,
+    ports: LatencyCountingPorts,
+    specified_latencies: Vec<SpecifiedLatency>,
+    ports_per_domain: Vec<Vec<usize>>,
+}
+
+/// Stored in the [crate::instantiation::instantiation_cache::InstantiationCache]. Cleared with it on a full recompile, incremental recompiles keep it
+#[derive(Debug, Default)]
+pub struct LatencySolveCache {
+    /// Solutions, and when they were last used
+    solutions: HashMap<LatencyProblemKey, (Vec<i64>, usize)>,
+    /// Counts [Self::solve] calls, the clock for least recently used eviction
+    time: usize,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl LatencySolveCache {
+    /// Same as [solve_latencies], but reuses the solution of an earlier identical problem.
+    ///
+    /// Errors are not cached, they carry paths that are reported once per instance anyway
+    pub fn solve(
+        &mut self,
+        fanins: ListOfLists<FanInOut>,
+        ports: &LatencyCountingPorts,
+        specified_latencies: &[SpecifiedLatency],
+        ports_per_domain: &[Vec<usize>],
+    ) -> Result<Vec<i64>, LatencyCountingError> {
+        let key = LatencyProblemKey {
+            fanins,
+            ports: ports.clone(),
+            specified_latencies: specified_latencies.to_vec(),
+            ports_per_domain: ports_per_domain.to_vec(),
+        };
+        self.time += 1;
+        if let Some((found, last_used)) = self.solutions.get_mut(&key) {
+            self.hits += 1;
+            *last_used = self.time;
+            return Ok(found.clone());
+        }
+        self.misses += 1;
+
+        let solution = solve_latencies(
+            key.fanins.clone(),
+            ports,
+            specified_latencies,
+            ports_per_domain,
+        )?;
+        if self.solutions.len() >= MAX_CACHED_SOLUTIONS {
+            self.evict_least_recently_used();
+        }
+        self.solutions.insert(key, (solution.clone(), self.time));
+        Ok(solution)
+    }
+
+    /// Only runs when the cache is full, so the linear search costs no more than the solve that caused it. Every use gets its own time, so exactly one solution is dropped
+    fn evict_least_recently_used(&mut self) {
+        if let Some(oldest) = self.solutions.values().map(|(_, t)| *t).min() {
+            self.solutions
+                .retain(|_, (_, last_used)| *last_used != oldest);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.solutions.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latency::latency_algorithm::mk_fan;
+    use crate::test_util::{compile_text, errors_in_file, instances_of};
+
+    #[test]
+    fn test_identical_problems_are_solved_once() {
+        let fanins: [&[FanInOut]; 3] = [&[], &[mk_fan(0, 1)], &[mk_fan(1, 2)]];
+        let fanins = ListOfLists::from_slice_slice(&fanins);
+        let ports = LatencyCountingPorts::from_inputs_outputs(&[0], &[2]);
+
+        let mut cache = LatencySolveCache::default();
+        let first = cache.solve(fanins.clone(), &ports, &[], &[]).unwrap();
+        let second = cache.solve(fanins, &ports, &[], &[]).unwrap();
+        assert_eq!(first, vec![0, 1, 3]);
+        assert_eq!(first, second);
+        assert_eq!((cache.hits, cache.misses), (1, 1));
+
+        let other_fanins: [&[FanInOut]; 3] = [&[], &[mk_fan(0, 2)], &[mk_fan(1, 2)]];
+        let other = cache
+            .solve(
+                ListOfLists::from_slice_slice(&other_fanins),
+                &ports,
+                &[],
+                &[],
+            )
+            .unwrap();
+        assert_eq!(other, vec![0, 2, 4]);
+        assert_eq!((cache.hits, cache.misses), (1, 2));
+    }
+
+    #[test]
+    fn test_cache_size_is_bounded() {
+        let ports = LatencyCountingPorts::from_inputs_outputs(&[0], &[1]);
+        let mut cache = LatencySolveCache::default();
+        for delta in 0..(MAX_CACHED_SOLUTIONS as i64 + 10) {
+            let fanins: [&[FanInOut]; 2] = [&[], &[mk_fan(0, delta)]];
+            cache
+                .solve(ListOfLists::from_slice_slice(&fanins), &ports, &[], &[])
+                .unwrap();
+            assert!(cache.solutions.len() <= MAX_CACHED_SOLUTIONS);
+        }
+        assert_eq!(cache.misses, MAX_CACHED_SOLUTIONS + 10);
+    }
+
+    #[test]
+    fn test_eviction_keeps_recently_used_solutions() {
+        let ports = LatencyCountingPorts::from_inputs_outputs(&[0], &[1]);
+        let problem = |delta| {
+            let fanins: [&[FanInOut]; 2] = [&[], &[mk_fan(0, delta)]];
+            ListOfLists::from_slice_slice(&fanins)
+        };
+        let mut cache = LatencySolveCache::default();
+        for delta in 0..(MAX_CACHED_SOLUTIONS as i64 + 10) {
+            cache.solve(problem(delta), &ports, &[], &[]).unwrap();
+            // Keep the first problem in use
+            cache.solve(problem(0), &ports, &[], &[]).unwrap();
+        }
+        assert_eq!(cache.misses, MAX_CACHED_SOLUTIONS + 10);
+        cache.solve(problem(1), &ports, &[], &[]).unwrap();
+        assert_eq!(cache.misses, MAX_CACHED_SOLUTIONS + 11);
+    }
+
+    #[test]
+    fn test_summary_is_relative_to_earliest_port() {
+        let (linker, file) = compile_text(
+            "
+module delay {
+    input bool a'3
+    output bool b'5
+    b = LatencyOffset #(T: type bool, OFFSET: 2)(a)
+}
+",
+        );
+        let errs = errors_in_file(&linker, file);
+        assert!(errs.is_empty(), "{errs:?}");
+        let instance = instances_of(&linker, "delay").pop().unwrap();
+        let summary: Vec<&Vec<(PortID, i64)>> = instance
+            .latency_summary
+            .port_latencies_per_domain
+            .iter()
+            .map(|(_, ports)| ports)
+            .collect();
+        assert_eq!(
+            summary,
+            [&vec![
+                (PortID::from_hidden_value(0), 0),
+                (PortID::from_hidden_value(1), 2)
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_submodules_with_equal_summaries_share_a_solution() {
+        let hits_for = |instances: &str| {
+            let (linker, file) = compile_text(&format!(
+                "
+module top {{
+    {instances}
+}}
+"
+            ));
+            let errs = errors_in_file(&linker, file);
+            assert!(errs.is_empty(), "{errs:?}");
+            linker.instantiator.borrow().latency_solve_cache.hits
+        };
+        let one = hits_for(
+            "input bool[48] a'0
+    output int a_count
+    a_count = PopCount(a)",
+        );
+        // Every level of the 64 wide tree has the same depth below it as the matching level of the 48 wide one
+        let two = hits_for(
+            "input bool[48] a'0
+    output int a_count
+    a_count = PopCount(a)
+    input bool[64] b'0
+    output int b_count
+    b_count = PopCount(b)",
+        );
+        assert!(two > one, "{two} > {one}");
+    }
+
+    #[test]
+    fn test_type_only_instantiations_share_a_solution() {
+        let hits_for = |instances: &str| {
+            let (linker, file) = compile_text(&format!(
+                "
+module delay #(T) {{
+    input T a'0
+    output T b'2
+    reg reg b = a
+}}
+module top {{
+    {instances}
+}}
+"
+            ));
+            let errs = errors_in_file(&linker, file);
+            assert!(errs.is_empty(), "{errs:?}");
+            linker.instantiator.borrow().latency_solve_cache.hits
+        };
+        let one = hits_for(
+            "input bool x'0
+    output bool y'2
+    delay #(T: type bool) d1
+    d1.a = x
+    y = d1.b",
+        );
+        let two = hits_for(
+            "input bool x'0
+    output bool y'2
+    delay #(T: type bool) d1
+    d1.a = x
+    y = d1.b
+    input bool[3] xs'0
+    output bool[3] ys'2
+    delay #(T: type bool[3]) d2
+    d2.a = xs
+    ys = d2.b",
+        );
+        assert_eq!(two, one + 1);
+    }
+}
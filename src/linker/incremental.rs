@@ -0,0 +1,243 @@
+//! Dependency tracking for incremental builds (#49)
+//!
+//! Every pass remembers the globals it looked at in [super::LinkInfo::resolved_globals].
+//! When a file changes, all of its globals are replaced, so everything that referenced one of them must be recompiled,
+//! and in turn everything that referenced those, see [Linker::globals_to_recompile].
+
+use std::collections::{HashMap, HashSet};
+
+use crate::prelude::*;
+
+use super::GlobalUUID;
+
+/// The globals a file had before it was changed. See [Linker::file_globals_before_change]. New files start from [Default]
+#[derive(Debug, Default)]
+pub struct FileGlobalsBeforeChange {
+    ids: HashSet<GlobalUUID>,
+    names: HashSet<String>,
+}
+
+impl Linker {
+    pub fn file_globals_before_change(&self, file_id: FileUUID) -> FileGlobalsBeforeChange {
+        let associated_values = &self.files[file_id].associated_values;
+        FileGlobalsBeforeChange {
+            ids: associated_values.iter().copied().collect(),
+            names: associated_values
+                .iter()
+                .map(|global| self.globals[*global].name.clone())
+                .collect(),
+        }
+    }
+
+    /// The globals that must be recompiled after the globals of `file_id` were replaced: its new globals,
+    /// and all globals that (transitively) depend on its old globals.
+    ///
+    /// Names also matter: a new name may resolve a previously unresolved reference,
+    /// or collide with a global that was referenced elsewhere.
+    ///
+    /// The old globals are gone, but their ids are included, such that their instances are removed too.
+    /// Some of those ids may already have been reused by the new globals
+    pub fn globals_to_recompile(
+        &self,
+        file_id: FileUUID,
+        before: &FileGlobalsBeforeChange,
+    ) -> HashSet<GlobalUUID> {
+        let all_globals = self.get_all_global_ids();
+        let new_globals = &self.files[file_id].associated_values;
+        let new_names: HashSet<&str> = new_globals
+            .iter()
+            .map(|global| self.globals[*global].name.as_str())
+            .collect();
+        let has_added_names = new_names.iter().any(|name| !before.names.contains(*name));
+
+        let mut dependents: HashMap<GlobalUUID, Vec<GlobalUUID>> = HashMap::new();
+        let mut to_visit: Vec<GlobalUUID> = before.ids.iter().copied().collect();
+        to_visit.extend(new_globals.iter().copied());
+        for global in &all_globals {
+            let link_info = &self.globals[*global];
+            let resolved = &link_info.resolved_globals;
+            for referenced in &resolved.referenced_globals {
+                dependents.entry(*referenced).or_default().push(*global);
+            }
+            let collides = resolved.referenced_globals.iter().any(|referenced| {
+                !before.ids.contains(referenced)
+                    && !new_globals.contains(referenced)
+                    && new_names.contains(self.globals[*referenced].name.as_str())
+            });
+            if collides || (has_added_names && !resolved.is_all_resolved()) {
+                to_visit.push(*global);
+            }
+        }
+
+        let mut to_recompile: HashSet<GlobalUUID> = HashSet::new();
+        while let Some(global) = to_visit.pop() {
+            if to_recompile.insert(global)
+                && let Some(deps) = dependents.get(&global)
+            {
+                to_visit.extend(deps.iter().copied());
+            }
+        }
+        to_recompile
+    }
+}
+
+#[cfg(all(test, feature = "lsp"))]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::dev_aid::lsp::LSPFileManager;
+    use crate::file_position::LineCol;
+    use crate::test_util::{compile_text_lsp, errors_in_file, instances_of};
+
+    /// Uses `leaf`, which is declared in [LEAF_FILE]
+    const USERS: &str = "
+module mid {
+    interface mid : bool a -> bool b
+    b = leaf(a)
+}
+module top {
+    output bool x
+    x = mid(true)
+}
+module unrelated {
+    interface unrelated : bool a -> bool b
+    b = !a
+}
+";
+    const LEAF_FILE: &str = "file:///leaf.sus";
+    const LEAF: &str = "
+module leaf {
+    interface leaf : bool a -> bool b
+    b = a
+}
+";
+
+    fn names(linker: &Linker, globals: &HashSet<GlobalUUID>) -> Vec<String> {
+        // Also contains the ids of removed globals
+        let mut names: Vec<String> = linker
+            .get_all_global_ids()
+            .into_iter()
+            .filter(|id| globals.contains(id))
+            .map(|id| linker.globals[id].name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// [USERS] and [LEAF], compiled in that order
+    fn compile_both() -> (Linker, FileUUID) {
+        let (mut linker, users) = compile_text_lsp(USERS);
+        let changed =
+            linker.add_or_update_file(LEAF_FILE, LEAF.to_string(), &mut LSPFileManager {});
+        linker.recompile_incremental(&changed);
+        (linker, users)
+    }
+
+    #[test]
+    fn test_new_name_resolves_unresolved_reference() {
+        let (mut linker, users) = compile_text_lsp(USERS);
+        assert!(!errors_in_file(&linker, users).is_empty());
+
+        let changed =
+            linker.add_or_update_file(LEAF_FILE, LEAF.to_string(), &mut LSPFileManager {});
+        assert_eq!(names(&linker, &changed), ["leaf", "mid", "top"]);
+        linker.recompile_incremental(&changed);
+        assert!(errors_in_file(&linker, users).is_empty());
+    }
+
+    #[test]
+    fn test_edit_recompiles_transitive_dependents_only() {
+        let (mut linker, users) = compile_both();
+        let unrelated_before = instances_of(&linker, "unrelated");
+        let top_before = instances_of(&linker, "top");
+        assert_eq!((unrelated_before.len(), top_before.len()), (1, 1));
+
+        // `b = a` becomes `b = !a`
+        let leaf_file = linker.find_file(LEAF_FILE).unwrap();
+        let at = LineCol { line: 3, col: 8 };
+        let changed = linker.edit_file(
+            leaf_file,
+            [(at..at, "!".to_string())],
+            &mut LSPFileManager {},
+        );
+        assert!(
+            linker.files[leaf_file]
+                .file_text
+                .file_text
+                .contains("b = !a")
+        );
+        assert_eq!(names(&linker, &changed), ["leaf", "mid", "top"]);
+
+        linker.recompile_incremental(&changed);
+        assert!(errors_in_file(&linker, users).is_empty());
+        // Instances of unrelated modules are kept, those using the edited module are redone
+        assert!(Rc::ptr_eq(
+            &instances_of(&linker, "unrelated")[0],
+            &unrelated_before[0]
+        ));
+        assert!(!Rc::ptr_eq(
+            &instances_of(&linker, "top")[0],
+            &top_before[0]
+        ));
+    }
+
+    #[test]
+    fn test_edit_columns_count_utf16_code_units() {
+        let (mut linker, _users) = compile_both();
+        let leaf_file = linker.find_file(LEAF_FILE).unwrap();
+        // '🦀' takes two UTF-16 code units, but is a single character and four bytes
+        let comment_at = LineCol { line: 3, col: 9 };
+        linker.edit_file(
+            leaf_file,
+            [(comment_at..comment_at, " // 🦀 a".to_string())],
+            &mut LSPFileManager {},
+        );
+        // Replace the `a` after the crab with `b`. In characters the `a` would be at column 15
+        let a_start = LineCol { line: 3, col: 16 };
+        let a_end = LineCol { line: 3, col: 17 };
+        linker.edit_file(
+            leaf_file,
+            [(a_start..a_end, "b".to_string())],
+            &mut LSPFileManager {},
+        );
+        assert!(
+            linker.files[leaf_file]
+                .file_text
+                .file_text
+                .contains("    b = a // 🦀 b\n")
+        );
+    }
+
+    #[test]
+    fn test_name_collision_recompiles_users() {
+        let (mut linker, _users) = compile_both();
+        let changed = linker.add_or_update_file(
+            "file:///another_leaf.sus",
+            LEAF.to_string(),
+            &mut LSPFileManager {},
+        );
+        // The new `leaf`, and everything that used the old one, which is now ambiguous
+        assert_eq!(names(&linker, &changed), ["leaf", "mid", "top"]);
+    }
+
+    #[test]
+    fn test_unchanged_names_dont_recompile_unresolved_globals() {
+        let (mut linker, users) = compile_text_lsp(USERS);
+        let changed = linker.add_or_update_file(
+            LEAF_FILE,
+            "module not_leaf {}".to_string(),
+            &mut LSPFileManager {},
+        );
+        linker.recompile_incremental(&changed);
+        let changed = linker.add_or_update_file(
+            LEAF_FILE,
+            "module not_leaf {\n}".to_string(),
+            &mut LSPFileManager {},
+        );
+        // `mid` still can't find `leaf`, but no new names were added that could change that
+        assert_eq!(names(&linker, &changed), ["not_leaf"]);
+        linker.recompile_incremental(&changed);
+        assert!(!errors_in_file(&linker, users).is_empty());
+    }
+}
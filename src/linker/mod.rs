@@ -10,6 +10,7 @@ use crate::{
 };
 
 pub mod checkpoint;
+pub mod incremental;
 pub mod passes;
 
 use std::{
@@ -98,11 +99,10 @@ pub struct LinkInfo {
     /// Created in Stage 2: Flattening. type data is filled out during Typechecking
     pub instructions: FlatAlloc<Instruction, FlatIDMarker>,
 
-    /// Reset checkpoints. These are to reset errors and resolved_globals for incremental compilation.
+    /// Reset checkpoints. These are to reset errors and resolved_globals for incremental compilation (#49).
     ///
-    /// TODO the system is there, just need to actually do incremental compilation (#49)
-    ///
-    /// Right now it already functions as a sanity check, to make sure no steps in building modules/types are skipped
+    /// Globals that must be recompiled are reset to [checkpoint::AFTER_INITIAL_PARSE_CP], see [Linker::recompile_incremental].
+    /// It also functions as a sanity check, to make sure no steps in building modules/types are skipped
     pub checkpoints: Vec<CheckPoint>,
 }
 
@@ -389,6 +389,12 @@ pub enum GetGlobalByNameError<'globals> {
 }
 
 impl<'globals> GetGlobalByNameError<'globals> {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            GetGlobalByNameError::NotFound { .. } => ErrorCode::UnknownName,
+            GetGlobalByNameError::Colission { .. } => ErrorCode::GlobalNameConflict,
+        }
+    }
     pub fn get_main_message(&self) -> String {
         match self {
             GetGlobalByNameError::NotFound { name, .. } => {
@@ -530,8 +536,10 @@ impl Linker {
 
                 all_errors[info.file].push(CompileError {
                     position: info.name_span,
+                    code: ErrorCode::GlobalNameConflict,
                     reason,
                     infos,
+                    suggestions: Vec::new(),
                     level: ErrorLevel::Error,
                 });
             }
@@ -559,7 +567,8 @@ impl Linker {
         self.collect_duplicate_declaration_errors(&mut result);
         self.collect_errors_after_compile(&mut result);
 
-        for (_, errs) in &mut result {
+        for (file_id, errs) in &mut result {
+            crate::lint_levels::apply_lint_levels(&self.files[file_id], errs);
             errs.sort();
         }
 
@@ -102,6 +102,10 @@ impl ResolvedGlobals {
     pub fn is_untouched(&self) -> bool {
         self.referenced_globals.is_empty() && self.all_resolved
     }
+    /// False if a name could not be resolved, such a global may need recompiling when globals are added
+    pub fn is_all_resolved(&self) -> bool {
+        self.all_resolved
+    }
     pub fn reset_to(&mut self, checkpoint: ResolvedGlobalsCheckpoint) {
         self.referenced_globals.truncate(checkpoint.0);
         self.all_resolved = checkpoint.1;
@@ -112,7 +116,7 @@ impl ResolvedGlobals {
 }
 
 /// This struct encapsulates the concept of name resolution. It reports name-not-found errors,
-/// and remembers all of the requested globals in preparation for #49
+/// and remembers all of the requested globals for incremental builds (#49), see [crate::linker::incremental]
 pub struct GlobalResolver<'linker, 'from> {
     pub globals: &'linker LinkerGlobals,
     global_namespace: &'linker HashMap<String, NamespaceElement>,
@@ -136,7 +140,7 @@ impl<'linker, 'from> GlobalResolver<'linker, 'from> {
                 resolved_globals.all_resolved = false;
 
                 errors
-                    .error(name_span, err.get_main_message())
+                    .error(name_span, err.code(), err.get_main_message())
                     .add_info_list(err.infos());
 
                 None
@@ -158,6 +162,7 @@ impl<'linker, 'from> GlobalResolver<'linker, 'from> {
         let global_type = global.get_kind_name();
         let err_ref = errors.error(
             global_ref.name_span,
+            ErrorCode::WrongKindOfGlobal,
             format!("{name} is not a {expected}, it is a {global_type} instead!"),
         );
         err_ref.info_obj(link_info);
@@ -0,0 +1,300 @@
+//! Levels of lints, the diagnostics with a [ErrorCode::lint_name]. Applied in [Linker::collect_all_errors].
+//!
+//! From weakest to strongest:
+//! - The level the compiler reports the lint at, usually a warning. [OPT_IN_LINTS] are allowed by default
+//! - The `"lints"` of the project file [PROJECT_FILE_NAME], in the working directory or one of its parents
+//! - `-W`, `-A` and `-D` on the command line. Later flags override earlier ones
+//! - `// sus:allow(...)`, `// sus:warn(...)` and `// sus:deny(...)` comments. These apply to the module or declaration
+//!   on the next line, or the one on their own line for trailing comments. Inner scopes override outer ones
+//!
+//! Finally, `--deny-warnings` turns all remaining warnings into errors.
+//!
+//! Lints can be named individually, or by a group from [LINT_GROUPS]
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Deserialize;
+use sus_proc_macro::kind;
+use tree_sitter::Node;
+
+use crate::prelude::*;
+
+use crate::config::config;
+use crate::errors::{CompileError, ErrorLevel, ErrorStore};
+use crate::file_position::FileText;
+use crate::linker::FileData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+pub const LINT_GROUPS: &[(&str, &[ErrorCode])] = &[
+    (
+        "unused",
+        &[
+            ErrorCode::UnusedVariable,
+            ErrorCode::UnusedPort,
+            ErrorCode::UnusedExpressionResult,
+            ErrorCode::UnreachableHardware,
+        ],
+    ),
+    (
+        "clock-domain-crossing",
+        &[
+            ErrorCode::UnsynchronizedCrossing,
+            ErrorCode::MultiBitCrossing,
+        ],
+    ),
+    (
+        "int-bounds",
+        &[ErrorCode::IntTruncation, ErrorCode::IntRangeGrowth],
+    ),
+];
+
+/// Lints that are allowed unless they are enabled explicitly, because they are noisy for most designs
+pub const OPT_IN_LINTS: &[ErrorCode] = &[ErrorCode::IntTruncation, ErrorCode::IntRangeGrowth];
+
+/// Searched for in the working directory and its parents. Contains for example `{"lints": {"unused-port": "allow"}}`
+pub const PROJECT_FILE_NAME: &str = "sus.json";
+
+/// The lints a lint or group name refers to
+pub fn lints_named(name: &str) -> Option<Vec<ErrorCode>> {
+    if let Some((_, group)) = LINT_GROUPS.iter().find(|(group, _)| *group == name) {
+        return Some(group.to_vec());
+    }
+    ErrorCode::ALL
+        .iter()
+        .find(|code| code.lint_name() == Some(name))
+        .map(|code| vec![*code])
+}
+
+/// All lint and group names, for `--help` and error messages
+pub fn all_lint_names() -> String {
+    let lints = ErrorCode::ALL.iter().filter_map(|code| code.lint_name());
+    let groups = LINT_GROUPS.iter().map(|(group, _)| *group);
+    lints.chain(groups).collect::<Vec<_>>().join(", ")
+}
+
+#[derive(Deserialize)]
+struct ProjectFile {
+    #[serde(default)]
+    lints: BTreeMap<String, LintLevel>,
+}
+
+fn find_project_file() -> Option<std::path::PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(PROJECT_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+fn read_project_file(path: &Path) -> Result<HashMap<ErrorCode, LintLevel>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let project: ProjectFile = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+    let mut levels = HashMap::new();
+    // Groups first, such that the lints in them can be overridden individually
+    let (groups, lints): (Vec<_>, Vec<_>) = project
+        .lints
+        .iter()
+        .partition(|(name, _)| LINT_GROUPS.iter().any(|(group, _)| group == name));
+    for (name, level) in groups.into_iter().chain(lints) {
+        let codes = lints_named(name)
+            .ok_or_else(|| format!("Unknown lint '{name}'. The lints are: {}", all_lint_names()))?;
+        for code in codes {
+            levels.insert(code, *level);
+        }
+    }
+    Ok(levels)
+}
+
+/// The levels from [PROJECT_FILE_NAME], if there is one
+pub fn load_project_lint_levels() -> HashMap<ErrorCode, LintLevel> {
+    let Some(path) = find_project_file() else {
+        return HashMap::new();
+    };
+    match read_project_file(&path) {
+        Ok(levels) => levels,
+        Err(e) => fatal_exit!("Invalid project file {}: {e}", path.to_string_lossy()),
+    }
+}
+
+/// Parses `sus:allow(unused-variable, unused-port)` in a comment
+fn parse_directive(comment: &str) -> Option<(LintLevel, Vec<&str>)> {
+    let rest = comment
+        .trim_start_matches('/')
+        .trim()
+        .strip_prefix("sus:")?;
+    let (level, rest) = rest.split_once('(')?;
+    let level = match level.trim() {
+        "allow" => LintLevel::Allow,
+        "warn" => LintLevel::Warn,
+        "deny" => LintLevel::Deny,
+        _ => return None,
+    };
+    let (names, _) = rest.split_once(')')?;
+    Some((level, names.split(',').map(str::trim).collect()))
+}
+
+struct SourceDirective {
+    scope: Span,
+    level: LintLevel,
+    lints: Vec<ErrorCode>,
+}
+
+fn next_non_comment(mut node: Node, forward: bool) -> Option<Node> {
+    loop {
+        node = if forward {
+            node.next_named_sibling()?
+        } else {
+            node.prev_named_sibling()?
+        };
+        if !node.is_extra() {
+            return Some(node);
+        }
+    }
+}
+
+/// The module or declaration a directive comment applies to
+fn directive_scope<'t>(comment: Node<'t>, file_text: &FileText) -> Option<Node<'t>> {
+    let line_of = |byte: usize| file_text.byte_to_linecol(byte).line;
+    let comment_line = line_of(comment.start_byte());
+    if let Some(mut before) = next_non_comment(comment, false)
+        && line_of(before.end_byte()) == comment_line
+    {
+        // Trailing comment, it applies to the outermost node that starts on its line
+        while let Some(parent) = before.parent()
+            && parent.parent().is_some()
+            && line_of(parent.start_byte()) == comment_line
+        {
+            before = parent;
+        }
+        return Some(before);
+    }
+    next_non_comment(comment, true)
+}
+
+/// Reports unknown lint names in directives to `errors`
+fn find_directives(file_data: &FileData, errors: &mut ErrorStore) -> Vec<SourceDirective> {
+    let mut directives = Vec::new();
+    let mut cursor = file_data.tree.walk();
+    loop {
+        let node = cursor.node();
+        if node.kind_id() == kind!("single_line_comment")
+            && let Some((level, names)) =
+                parse_directive(&file_data.file_text.file_text[node.byte_range()])
+        {
+            let mut lints = Vec::new();
+            for name in names {
+                match lints_named(name) {
+                    Some(codes) => lints.extend(codes),
+                    None => errors.push(CompileError {
+                        position: Span::from(node.byte_range()),
+                        code: ErrorCode::UnknownLint,
+                        reason: format!(
+                            "Unknown lint '{name}'. The lints are: {}",
+                            all_lint_names()
+                        ),
+                        infos: Vec::new(),
+                        suggestions: Vec::new(),
+                        level: ErrorLevel::Warning,
+                    }),
+                }
+            }
+            if let Some(scope) = directive_scope(node, &file_data.file_text) {
+                directives.push(SourceDirective {
+                    scope: Span::from(scope.byte_range()),
+                    level,
+                    lints,
+                });
+            }
+        }
+        if cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return directives;
+            }
+        }
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    let (outer, inner) = (outer.as_range(), inner.as_range());
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Applies the lint levels to the errors of `file_data`. Allowed lints are removed
+pub fn apply_lint_levels(file_data: &FileData, errors: &mut ErrorStore) {
+    let directives = find_directives(file_data, errors);
+    let config = config();
+    errors.set_levels(|err| {
+        let mut level = match err.level {
+            ErrorLevel::Error => LintLevel::Deny,
+            ErrorLevel::Warning if OPT_IN_LINTS.contains(&err.code) => LintLevel::Allow,
+            ErrorLevel::Warning => LintLevel::Warn,
+        };
+        if err.code.lint_name().is_some() {
+            if let Some(configured) = config.lint_levels.get(&err.code) {
+                level = *configured;
+            }
+            // Innermost scope wins, and of equal scopes the last one
+            if let Some(directive) = directives
+                .iter()
+                .filter(|d| d.lints.contains(&err.code) && contains(d.scope, err.position))
+                .min_by_key(|d| d.scope.size())
+            {
+                level = directive.level;
+            }
+        }
+        match level {
+            LintLevel::Allow => None,
+            LintLevel::Warn if !config.deny_warnings => Some(ErrorLevel::Warning),
+            LintLevel::Warn | LintLevel::Deny => Some(ErrorLevel::Error),
+        }
+    });
+}
+
+/// Lints that were raised to errors, by `-D`, `--deny-warnings` or `sus:deny`. These fail the build
+pub fn any_denied_lints(linker: &Linker) -> bool {
+    linker.collect_all_errors().iter().any(|(_, errs)| {
+        errs.into_iter()
+            .any(|err| err.level == ErrorLevel::Error && err.code.lint_name().is_some())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directive() {
+        assert_eq!(
+            parse_directive("// sus:allow(unused-variable, unused-port)"),
+            Some((LintLevel::Allow, vec!["unused-variable", "unused-port"]))
+        );
+        assert_eq!(
+            parse_directive("//sus:deny(unused)"),
+            Some((LintLevel::Deny, vec!["unused"]))
+        );
+        assert_eq!(parse_directive("// sus:forbid(unused)"), None);
+        assert_eq!(parse_directive("// allow(unused)"), None);
+    }
+
+    #[test]
+    fn test_lints_named() {
+        assert_eq!(
+            lints_named("unused-port"),
+            Some(vec![ErrorCode::UnusedPort])
+        );
+        assert_eq!(lints_named("unused").unwrap().len(), 4);
+        assert_eq!(lints_named("clock-domain-crossing").unwrap().len(), 2);
+        assert_eq!(lints_named("int-bounds").unwrap(), OPT_IN_LINTS);
+        assert_eq!(lints_named("type-mismatch"), None);
+    }
+}
@@ -7,11 +7,13 @@ mod append_only_vec;
 
 mod config;
 mod debug;
+mod error_codes;
 mod errors;
 mod file_position;
 mod flattening;
 mod instantiation;
 mod latency;
+mod lint_levels;
 mod prelude;
 mod to_string;
 mod typing;
@@ -25,9 +27,12 @@ mod linker;
 
 mod compiler_top;
 
+#[cfg(test)]
+mod test_util;
+
 use std::process::ExitCode;
 
-use config::config;
+use config::{MessageFormat, config};
 use dev_aid::ariadne_interface::*;
 use instantiation::InstantiatedModule;
 
@@ -40,6 +45,26 @@ fn main() -> ExitCode {
 
     let config = config();
 
+    match &config.subcommand {
+        Some(config::SubCommand::LatencySolve { problem_files }) => {
+            return latency::problem_json::latency_solve_main(problem_files);
+        }
+        Some(config::SubCommand::Explain { code }) => {
+            error_codes::print_explanation(*code);
+            return ExitCode::SUCCESS;
+        }
+        Some(config::SubCommand::Format { files, check }) => {
+            return dev_aid::format::format_files(files, *check);
+        }
+        Some(config::SubCommand::Doc { files, out }) => {
+            let (linker, mut paths_arena) = compile_all(files.clone());
+            print_all_errors(&linker, &mut paths_arena.file_sources);
+            dev_aid::doc::write_docs(&linker, out);
+            return ExitCode::SUCCESS;
+        }
+        None => {}
+    }
+
     let file_paths = config.files.clone();
 
     if config.lsp_settings.is_some() {
@@ -54,7 +79,24 @@ fn main() -> ExitCode {
     debug::setup_panic_handler();
 
     let (linker, mut paths_arena) = compile_all(file_paths);
-    print_all_errors(&linker, &mut paths_arena.file_sources);
+    match config.message_format {
+        MessageFormat::Human => print_all_errors(&linker, &mut paths_arena.file_sources),
+        MessageFormat::Json => dev_aid::message_format::print_all_errors_json(&linker),
+        MessageFormat::Sarif => dev_aid::message_format::print_all_errors_sarif(&linker),
+    }
 
-    crate::codegen::codegen(&linker)
+    if let Some(folder) = &config.latency_html_folder {
+        dev_aid::latency_html::write_latency_html(&linker, folder);
+    }
+
+    if let Some(fix_mode) = config.fix_mode {
+        return dev_aid::fix::fix_all_files(&linker, fix_mode);
+    }
+
+    let codegen_result = crate::codegen::codegen(&linker);
+    if lint_levels::any_denied_lints(&linker) {
+        ExitCode::FAILURE
+    } else {
+        codegen_result
+    }
 }
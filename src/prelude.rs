@@ -6,6 +6,7 @@ use std::marker::PhantomData;
 
 // public imports
 pub use crate::alloc::FlatAlloc;
+pub use crate::error_codes::ErrorCode;
 pub use crate::errors::ErrorCollector;
 pub use crate::file_position::{BracketSpan, Span, SpanFile};
 pub use crate::linker::Linker;
@@ -0,0 +1,123 @@
+//! Helpers for tests that compile small SUS snippets together with the standard library
+
+use crate::alloc::ArenaVector;
+use crate::compiler_top::LinkerExtraFileInfoManager;
+use crate::config::initialize_test_config;
+use crate::dev_aid::ariadne_interface::FileSourcesManager;
+use crate::errors::CompileError;
+use crate::instantiation::InstantiatedModule;
+use crate::linker::Linker;
+use crate::prelude::*;
+
+use std::rc::Rc;
+
+pub const TEST_FILE_NAME: &str = "test.sus";
+#[cfg(feature = "lsp")]
+pub const TEST_FILE_URL: &str = "file:///test.sus";
+
+/// A generic module, instantiated twice by `top`. The declaration of `b` is on line 3, the submodules on lines 9 and 14
+pub const TWO_DELAYS: &str = "
+module delay #(T) {
+    input T a'0
+    output T b
+    reg b = a
+}
+module top {
+    input bool x'0
+    output bool y
+    delay #(T: type bool) d1
+    d1.a = x
+    y = d1.b
+    input bool[2] xs'0
+    output bool[2] ys
+    delay #(T: type bool[2]) d2
+    d2.a = xs
+    ys = d2.b
+}
+";
+
+/// Compiles `text` as the file [TEST_FILE_NAME], with the standard library loaded
+pub fn compile_text(text: &str) -> (Linker, FileUUID) {
+    let mut file_source_manager = FileSourcesManager {
+        file_sources: ArenaVector::new(),
+    };
+    compile_text_with(text, TEST_FILE_NAME.to_string(), &mut file_source_manager)
+}
+
+/// Like [compile_text], but with `file://` URLs as file identifiers, the way the language server names its files
+#[cfg(feature = "lsp")]
+pub fn compile_text_lsp(text: &str) -> (Linker, FileUUID) {
+    compile_text_with(
+        text,
+        TEST_FILE_URL.to_string(),
+        &mut crate::dev_aid::lsp::LSPFileManager {},
+    )
+}
+
+/// Compiles several files together, given as `(file_identifier, text)`, with the standard library loaded
+pub fn compile_files(files: &[(&str, &str)]) -> (Linker, Vec<FileUUID>) {
+    initialize_test_config();
+    let mut manager = FileSourcesManager {
+        file_sources: ArenaVector::new(),
+    };
+    let mut linker = Linker::new();
+    linker.add_standard_library(&mut manager);
+    let file_ids = files
+        .iter()
+        .map(|(identifier, text)| {
+            linker.add_file_text(identifier.to_string(), text.to_string(), &mut manager)
+        })
+        .collect();
+    linker.recompile_all();
+    (linker, file_ids)
+}
+
+fn compile_text_with(
+    text: &str,
+    file_identifier: String,
+    manager: &mut impl LinkerExtraFileInfoManager,
+) -> (Linker, FileUUID) {
+    initialize_test_config();
+    let mut linker = Linker::new();
+    linker.add_standard_library(manager);
+    let file = linker.add_file_text(file_identifier, text.to_string(), manager);
+    linker.recompile_all();
+    (linker, file)
+}
+
+/// All errors and warnings reported in `file`, after lint levels are applied
+pub fn errors_in_file(linker: &Linker, file: FileUUID) -> Vec<CompileError> {
+    let mut all_errors = linker.collect_all_errors();
+    std::mem::take(&mut all_errors[file]).into_iter().collect()
+}
+
+/// The errors in `file` with the given `code`
+pub fn errors_with_code(linker: &Linker, file: FileUUID, code: ErrorCode) -> Vec<CompileError> {
+    errors_in_file(linker, file)
+        .into_iter()
+        .filter(|err| err.code == code)
+        .collect()
+}
+
+/// All instantiations of the module named `module_name`
+pub fn instances_of(linker: &Linker, module_name: &str) -> Vec<Rc<InstantiatedModule>> {
+    let (md_id, _) = linker
+        .modules
+        .iter()
+        .find(|(_, md)| md.link_info.name == module_name)
+        .unwrap();
+    linker
+        .instantiator
+        .borrow()
+        .iter_for_module(md_id)
+        .map(|(_, inst)| inst.clone())
+        .collect()
+}
+
+/// The names of all instantiations of the module named `module_name`
+pub fn instance_names(linker: &Linker, module_name: &str) -> Vec<String> {
+    instances_of(linker, module_name)
+        .iter()
+        .map(|inst| inst.name.clone())
+        .collect()
+}
@@ -212,10 +212,23 @@ impl<ID: Into<GlobalUUID> + Copy> AbstractGlobalReference<ID> {
 }
 impl<ID: Into<GlobalUUID> + Copy> ConcreteGlobalReference<ID> {
     pub fn display<'v>(&'v self, globals: &'v LinkerGlobals) -> impl Display + 'v {
+        let target_link_info: &LinkInfo = &globals[self.id.into()];
+        FmtWrapper(move |f| {
+            write!(
+                f,
+                "{} {}",
+                target_link_info.display_full_name(),
+                self.display_template_args(globals)
+            )
+        })
+    }
+    /// Only the `#(...)` part of [Self::display]
+    pub fn display_template_args<'v>(&'v self, globals: &'v LinkerGlobals) -> impl Display + 'v {
         let target_link_info: &LinkInfo = &globals[self.id.into()];
         assert!(self.template_args.len() == target_link_info.parameters.len());
 
-        target_link_info.display_with_template_args(
+        let template_args = display_join(
+            ", ",
             zip_eq(&self.template_args, &target_link_info.parameters),
             |f, (_id, arg, arg_in_target)| {
                 write!(f, "{}: ", &arg_in_target.name)?;
@@ -227,7 +240,8 @@ impl<ID: Into<GlobalUUID> + Copy> ConcreteGlobalReference<ID> {
                     TemplateKind::Value(Unifyable::Unknown(_)) => write!(f, "?"),
                 }
             },
-        )
+        );
+        FmtWrapper(move |f| write!(f, "#({template_args})"))
     }
 }
 
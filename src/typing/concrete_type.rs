@@ -29,6 +29,12 @@ impl ConcreteTemplateArg {
             TemplateKind::Value(v) => v.is_unknown(),
         }
     }
+    pub fn contains_type(&self, is_match: &impl Fn(TypeUUID) -> bool) -> bool {
+        match self {
+            TemplateKind::Type(t) => t.contains_type(is_match),
+            TemplateKind::Value(_) => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -126,6 +132,19 @@ impl ConcreteType {
             }
         }
     }
+    /// Whether `is_match` holds for this type or any type in its template arguments or array contents
+    pub fn contains_type(&self, is_match: &impl Fn(TypeUUID) -> bool) -> bool {
+        match self {
+            ConcreteType::Named(global_ref) => {
+                is_match(global_ref.id)
+                    || global_ref
+                        .template_args
+                        .iter()
+                        .any(|(_, arg)| arg.contains_type(is_match))
+            }
+            ConcreteType::Array(arr_box) => arr_box.0.contains_type(is_match),
+        }
+    }
     pub fn co_iterate_parameters<'a>(
         a: &'a Self,
         b: &'a Self,
@@ -294,7 +313,8 @@ impl ConcreteType {
 
                 if global_ref.id == get_builtin_type!("int") {
                     let bounds = global_ref.unwrap_int_bounds();
-                    if !bounds.is_non_empty() {
+                    // Also catches reversed bounds like `int #(FROM: 5, TO: 3)`, which `is_non_empty` asserts against
+                    if bounds.from >= bounds.to {
                         return false;
                     }
                 }
@@ -439,6 +459,7 @@ impl<ID: Into<GlobalUUID> + Copy> ConcreteGlobalReference<ID> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::value::Value;
 
     #[test]
     #[rustfmt::skip]
@@ -455,4 +476,21 @@ mod tests {
         assert_eq!(IntBounds{from: &IBig::from(20), to: &IBig::from(257)}.bitwidth(), 9);
         assert_eq!(IntBounds{from: &IBig::from(0), to: &IBig::from(1)}.bitwidth(), 0); // Zero sized wires are now possible
     }
+
+    fn int_type(from: i64, to: i64) -> ConcreteType {
+        ConcreteType::Named(ConcreteGlobalReference {
+            id: get_builtin_type!("int"),
+            template_args: FlatAlloc::from_vec(vec![
+                TemplateKind::Value(Value::Integer(IBig::from(from)).into()),
+                TemplateKind::Value(Value::Integer(IBig::from(to)).into()),
+            ]),
+        })
+    }
+
+    #[test]
+    fn test_int_bounds_validity() {
+        assert!(int_type(0, 16).is_valid());
+        assert!(!int_type(3, 3).is_valid());
+        assert!(!int_type(5, 3).is_valid());
+    }
 }
@@ -349,7 +349,7 @@ impl HindleyMilner for AbstractInnerType {
 
                 for (_, a, b) in zip_eq(&na.template_arg_types, &nb.template_arg_types) {
                     match a.and_by_ref(b) {
-                        TemplateKind::Type((a, b)) => {
+                        TemplateKind::Type((_a, _b)) => {
                             todo!("Abstract Type Type Parameter Unification")
                         }
                         TemplateKind::Value(((), ())) => {}
@@ -112,6 +112,25 @@ pub fn ceil_div(a: IBig, b: &IBig) -> IBig {
         div
     }
 }
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+/// [None] on overflow
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+#[test]
+fn test_gcd_and_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(7, 1), 1);
+    assert_eq!(gcd(0, 5), 5);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(3, 1), Some(3));
+    assert_eq!(lcm(u64::MAX, 2), None);
+}
 #[test]
 fn test_floor_and_ceil_div() {
     for ai in -10..=10 {
@@ -166,6 +166,11 @@ module.exports = grammar({
         domain_statement: $ => seq(
             'domain',
             field('name', $.identifier),
+            // Synchronous clock ratio: domain fast_clk = 2 * clk
+            optional(seq(
+                '=',
+                field('assign_value', $._expression)
+            ))
         ),
 
         interface_statement: $ => seq(
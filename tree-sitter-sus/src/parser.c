@@ -7,7 +7,7 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 356
+#define STATE_COUNT 358
 #define LARGE_STATE_COUNT 11
 #define SYMBOL_COUNT 116
 #define ALIAS_COUNT 0
@@ -16,7 +16,7 @@
 #define FIELD_COUNT 44
 #define MAX_ALIAS_SEQUENCE_LENGTH 7
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 70
+#define PRODUCTION_ID_COUNT 71
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
@@ -983,30 +983,31 @@ static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [43] = {.index = 92, .length = 1},
   [44] = {.index = 93, .length = 2},
   [45] = {.index = 95, .length = 5},
-  [46] = {.index = 100, .length = 5},
-  [47] = {.index = 105, .length = 4},
-  [48] = {.index = 109, .length = 4},
-  [49] = {.index = 113, .length = 5},
-  [50] = {.index = 118, .length = 5},
-  [51] = {.index = 123, .length = 4},
-  [52] = {.index = 127, .length = 2},
+  [46] = {.index = 100, .length = 2},
+  [47] = {.index = 102, .length = 5},
+  [48] = {.index = 107, .length = 4},
+  [49] = {.index = 111, .length = 4},
+  [50] = {.index = 115, .length = 5},
+  [51] = {.index = 120, .length = 5},
+  [52] = {.index = 125, .length = 4},
   [53] = {.index = 129, .length = 2},
-  [54] = {.index = 131, .length = 1},
-  [55] = {.index = 132, .length = 1},
-  [56] = {.index = 133, .length = 2},
-  [57] = {.index = 135, .length = 1},
-  [58] = {.index = 136, .length = 2},
-  [59] = {.index = 138, .length = 1},
-  [60] = {.index = 139, .length = 1},
-  [61] = {.index = 140, .length = 6},
-  [62] = {.index = 146, .length = 6},
-  [63] = {.index = 152, .length = 5},
-  [64] = {.index = 157, .length = 6},
-  [65] = {.index = 163, .length = 3},
-  [66] = {.index = 166, .length = 1},
-  [67] = {.index = 167, .length = 2},
-  [68] = {.index = 169, .length = 7},
-  [69] = {.index = 176, .length = 5},
+  [54] = {.index = 131, .length = 2},
+  [55] = {.index = 133, .length = 1},
+  [56] = {.index = 134, .length = 1},
+  [57] = {.index = 135, .length = 2},
+  [58] = {.index = 137, .length = 1},
+  [59] = {.index = 138, .length = 2},
+  [60] = {.index = 140, .length = 1},
+  [61] = {.index = 141, .length = 1},
+  [62] = {.index = 142, .length = 6},
+  [63] = {.index = 148, .length = 6},
+  [64] = {.index = 154, .length = 5},
+  [65] = {.index = 159, .length = 6},
+  [66] = {.index = 165, .length = 3},
+  [67] = {.index = 168, .length = 1},
+  [68] = {.index = 169, .length = 2},
+  [69] = {.index = 171, .length = 7},
+  [70] = {.index = 178, .length = 5},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -1156,97 +1157,100 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_statement_type, 0},
     {field_then_block, 3, .inherited = true},
   [100] =
+    {field_assign_value, 3},
+    {field_name, 1},
+  [102] =
     {field_else_block, 3, .inherited = true},
     {field_interface_kind, 1},
     {field_local, 0},
     {field_name, 2},
     {field_then_block, 3, .inherited = true},
-  [105] =
+  [107] =
     {field_interface_kind, 1},
     {field_interface_ports, 3},
     {field_local, 0},
     {field_name, 2},
-  [109] =
+  [111] =
     {field_interface_kind, 1},
     {field_latency_specifier, 3},
     {field_local, 0},
     {field_name, 2},
-  [113] =
+  [115] =
     {field_else_block, 3, .inherited = true},
     {field_interface_kind, 0},
     {field_interface_ports, 2},
     {field_name, 1},
     {field_then_block, 3, .inherited = true},
-  [118] =
+  [120] =
     {field_else_block, 3, .inherited = true},
     {field_interface_kind, 0},
     {field_latency_specifier, 2},
     {field_name, 1},
     {field_then_block, 3, .inherited = true},
-  [123] =
+  [125] =
     {field_interface_kind, 0},
     {field_interface_ports, 3},
     {field_latency_specifier, 2},
     {field_name, 1},
-  [127] =
+  [129] =
     {field_index_b, 1},
     {field_type, 0},
-  [129] =
+  [131] =
     {field_index_a, 0},
     {field_type, 1},
-  [131] =
+  [133] =
     {field_index, 1},
-  [132] =
+  [134] =
     {field_slice, 1},
-  [133] =
+  [135] =
     {field_name, 0},
     {field_type_arg, 3},
-  [135] =
+  [137] =
     {field_outputs, 1},
-  [136] =
+  [138] =
     {field_inputs, 1},
     {field_outputs, 2, .inherited = true},
-  [138] =
+  [140] =
     {field_outputs, 2, .inherited = true},
-  [139] =
+  [141] =
     {field_inputs, 2},
-  [140] =
+  [142] =
     {field_else_block, 4, .inherited = true},
     {field_interface_kind, 1},
     {field_interface_ports, 3},
     {field_local, 0},
     {field_name, 2},
     {field_then_block, 4, .inherited = true},
-  [146] =
+  [148] =
     {field_else_block, 4, .inherited = true},
     {field_interface_kind, 1},
     {field_latency_specifier, 3},
     {field_local, 0},
     {field_name, 2},
     {field_then_block, 4, .inherited = true},
-  [152] =
+  [154] =
     {field_interface_kind, 1},
     {field_interface_ports, 4},
     {field_latency_specifier, 3},
     {field_local, 0},
     {field_name, 2},
-  [157] =
+  [159] =
     {field_else_block, 4, .inherited = true},
     {field_interface_kind, 0},
     {field_interface_ports, 3},
     {field_latency_specifier, 2},
     {field_name, 1},
     {field_then_block, 4, .inherited = true},
-  [163] =
+  [165] =
     {field_index_a, 0},
     {field_index_b, 2},
     {field_type, 1},
-  [166] =
+  [168] =
     {field_outputs, 2},
-  [167] =
+  [169] =
     {field_inputs, 2},
     {field_outputs, 3, .inherited = true},
-  [169] =
+  [171] =
     {field_else_block, 5, .inherited = true},
     {field_interface_kind, 1},
     {field_interface_ports, 4},
@@ -1254,7 +1258,7 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_local, 0},
     {field_name, 2},
     {field_then_block, 5, .inherited = true},
-  [176] =
+  [178] =
     {field_block, 6},
     {field_for_decl, 1},
     {field_for_kw, 0},
@@ -1302,7 +1306,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [28] = 28,
   [29] = 29,
   [30] = 30,
-  [31] = 28,
+  [31] = 31,
   [32] = 32,
   [33] = 33,
   [34] = 34,
@@ -1311,7 +1315,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [37] = 37,
   [38] = 38,
   [39] = 39,
-  [40] = 40,
+  [40] = 28,
   [41] = 41,
   [42] = 42,
   [43] = 43,
@@ -1325,7 +1329,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [51] = 51,
   [52] = 52,
   [53] = 53,
-  [54] = 54,
+  [54] = 45,
   [55] = 55,
   [56] = 56,
   [57] = 57,
@@ -1333,14 +1337,14 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [59] = 59,
   [60] = 60,
   [61] = 61,
-  [62] = 55,
-  [63] = 54,
-  [64] = 64,
+  [62] = 62,
+  [63] = 63,
+  [64] = 48,
   [65] = 65,
   [66] = 66,
-  [67] = 67,
+  [67] = 66,
   [68] = 68,
-  [69] = 67,
+  [69] = 69,
   [70] = 70,
   [71] = 71,
   [72] = 72,
@@ -1354,89 +1358,89 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [80] = 80,
   [81] = 81,
   [82] = 82,
-  [83] = 82,
-  [84] = 78,
-  [85] = 79,
-  [86] = 71,
-  [87] = 87,
-  [88] = 88,
-  [89] = 89,
-  [90] = 87,
-  [91] = 81,
-  [92] = 88,
-  [93] = 93,
-  [94] = 73,
-  [95] = 77,
-  [96] = 89,
-  [97] = 14,
+  [83] = 83,
+  [84] = 84,
+  [85] = 75,
+  [86] = 86,
+  [87] = 72,
+  [88] = 77,
+  [89] = 86,
+  [90] = 90,
+  [91] = 73,
+  [92] = 79,
+  [93] = 78,
+  [94] = 90,
+  [95] = 95,
+  [96] = 83,
+  [97] = 71,
   [98] = 98,
-  [99] = 16,
-  [100] = 15,
-  [101] = 101,
-  [102] = 102,
+  [99] = 15,
+  [100] = 14,
+  [101] = 16,
+  [102] = 18,
   [103] = 103,
   [104] = 104,
-  [105] = 101,
-  [106] = 106,
-  [107] = 107,
-  [108] = 108,
-  [109] = 17,
-  [110] = 19,
-  [111] = 18,
-  [112] = 26,
-  [113] = 20,
-  [114] = 23,
-  [115] = 22,
-  [116] = 21,
-  [117] = 24,
-  [118] = 25,
-  [119] = 104,
-  [120] = 120,
+  [105] = 105,
+  [106] = 24,
+  [107] = 20,
+  [108] = 21,
+  [109] = 22,
+  [110] = 23,
+  [111] = 25,
+  [112] = 112,
+  [113] = 17,
+  [114] = 114,
+  [115] = 26,
+  [116] = 116,
+  [117] = 117,
+  [118] = 117,
+  [119] = 112,
+  [120] = 19,
   [121] = 121,
-  [122] = 52,
-  [123] = 60,
-  [124] = 124,
+  [122] = 122,
+  [123] = 49,
+  [124] = 61,
   [125] = 125,
-  [126] = 32,
-  [127] = 30,
-  [128] = 36,
-  [129] = 64,
-  [130] = 46,
-  [131] = 47,
-  [132] = 27,
-  [133] = 61,
-  [134] = 42,
-  [135] = 43,
-  [136] = 136,
-  [137] = 44,
-  [138] = 45,
-  [139] = 33,
-  [140] = 34,
-  [141] = 35,
-  [142] = 48,
-  [143] = 49,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 39,
+  [130] = 27,
+  [131] = 33,
+  [132] = 47,
+  [133] = 62,
+  [134] = 63,
+  [135] = 32,
+  [136] = 38,
+  [137] = 43,
+  [138] = 44,
+  [139] = 46,
+  [140] = 41,
+  [141] = 29,
+  [142] = 30,
+  [143] = 31,
   [144] = 50,
-  [145] = 51,
-  [146] = 146,
+  [145] = 52,
+  [146] = 42,
   [147] = 53,
-  [148] = 37,
-  [149] = 38,
-  [150] = 39,
-  [151] = 56,
-  [152] = 57,
+  [148] = 55,
+  [149] = 56,
+  [150] = 34,
+  [151] = 35,
+  [152] = 36,
   [153] = 58,
   [154] = 59,
-  [155] = 40,
-  [156] = 29,
-  [157] = 157,
+  [155] = 60,
+  [156] = 57,
+  [157] = 37,
   [158] = 158,
   [159] = 159,
   [160] = 160,
   [161] = 161,
   [162] = 162,
-  [163] = 158,
+  [163] = 163,
   [164] = 164,
-  [165] = 165,
+  [165] = 163,
   [166] = 166,
   [167] = 167,
   [168] = 168,
@@ -1447,8 +1451,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [173] = 173,
   [174] = 174,
   [175] = 175,
-  [176] = 176,
-  [177] = 12,
+  [176] = 12,
+  [177] = 177,
   [178] = 178,
   [179] = 179,
   [180] = 180,
@@ -1482,16 +1486,16 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [208] = 208,
   [209] = 209,
   [210] = 210,
-  [211] = 200,
+  [211] = 211,
   [212] = 212,
   [213] = 213,
-  [214] = 212,
-  [215] = 196,
-  [216] = 205,
-  [217] = 210,
-  [218] = 198,
-  [219] = 219,
-  [220] = 220,
+  [214] = 210,
+  [215] = 215,
+  [216] = 201,
+  [217] = 200,
+  [218] = 211,
+  [219] = 204,
+  [220] = 197,
   [221] = 221,
   [222] = 222,
   [223] = 223,
@@ -1504,11 +1508,11 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [230] = 230,
   [231] = 231,
   [232] = 232,
-  [233] = 229,
+  [233] = 222,
   [234] = 234,
-  [235] = 235,
+  [235] = 232,
   [236] = 236,
-  [237] = 234,
+  [237] = 237,
   [238] = 238,
   [239] = 239,
   [240] = 240,
@@ -1542,10 +1546,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [268] = 268,
   [269] = 269,
   [270] = 270,
-  [271] = 258,
+  [271] = 271,
   [272] = 272,
   [273] = 273,
-  [274] = 274,
+  [274] = 242,
   [275] = 275,
   [276] = 276,
   [277] = 277,
@@ -1555,7 +1559,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [281] = 281,
   [282] = 282,
   [283] = 283,
-  [284] = 284,
+  [284] = 276,
   [285] = 285,
   [286] = 286,
   [287] = 287,
@@ -1563,25 +1567,25 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [289] = 289,
   [290] = 290,
   [291] = 291,
-  [292] = 274,
+  [292] = 292,
   [293] = 293,
   [294] = 294,
   [295] = 295,
   [296] = 296,
   [297] = 297,
-  [298] = 278,
+  [298] = 298,
   [299] = 299,
   [300] = 300,
-  [301] = 293,
-  [302] = 302,
-  [303] = 277,
-  [304] = 279,
-  [305] = 282,
-  [306] = 291,
-  [307] = 300,
-  [308] = 308,
+  [301] = 279,
+  [302] = 285,
+  [303] = 303,
+  [304] = 282,
+  [305] = 305,
+  [306] = 281,
+  [307] = 291,
+  [308] = 299,
   [309] = 309,
-  [310] = 310,
+  [310] = 305,
   [311] = 311,
   [312] = 312,
   [313] = 313,
@@ -1596,8 +1600,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [322] = 322,
   [323] = 323,
   [324] = 324,
-  [325] = 325,
-  [326] = 317,
+  [325] = 321,
+  [326] = 326,
   [327] = 327,
   [328] = 328,
   [329] = 329,
@@ -1605,7 +1609,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [331] = 331,
   [332] = 332,
   [333] = 333,
-  [334] = 333,
+  [334] = 334,
   [335] = 335,
   [336] = 336,
   [337] = 337,
@@ -1614,19 +1618,21 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [340] = 340,
   [341] = 341,
   [342] = 342,
-  [343] = 341,
+  [343] = 343,
   [344] = 344,
   [345] = 345,
   [346] = 346,
-  [347] = 347,
-  [348] = 344,
+  [347] = 336,
+  [348] = 348,
   [349] = 349,
   [350] = 350,
   [351] = 351,
-  [352] = 352,
-  [353] = 353,
+  [352] = 335,
+  [353] = 342,
   [354] = 354,
   [355] = 355,
+  [356] = 356,
+  [357] = 357,
 };
 
 static const TSCharacterRange sym_identifier_character_set_1[] = {
@@ -2649,8 +2655,8 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [14] = {.lex_state = 3},
   [15] = {.lex_state = 3},
   [16] = {.lex_state = 3},
-  [17] = {.lex_state = 2},
-  [18] = {.lex_state = 3},
+  [17] = {.lex_state = 3},
+  [18] = {.lex_state = 2},
   [19] = {.lex_state = 2},
   [20] = {.lex_state = 2},
   [21] = {.lex_state = 2},
@@ -2663,7 +2669,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [28] = {.lex_state = 4},
   [29] = {.lex_state = 2},
   [30] = {.lex_state = 2},
-  [31] = {.lex_state = 4},
+  [31] = {.lex_state = 2},
   [32] = {.lex_state = 2},
   [33] = {.lex_state = 2},
   [34] = {.lex_state = 2},
@@ -2672,31 +2678,31 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [37] = {.lex_state = 2},
   [38] = {.lex_state = 2},
   [39] = {.lex_state = 2},
-  [40] = {.lex_state = 2},
+  [40] = {.lex_state = 4},
   [41] = {.lex_state = 2},
   [42] = {.lex_state = 2},
   [43] = {.lex_state = 2},
   [44] = {.lex_state = 2},
-  [45] = {.lex_state = 2},
+  [45] = {.lex_state = 1},
   [46] = {.lex_state = 2},
   [47] = {.lex_state = 2},
-  [48] = {.lex_state = 2},
+  [48] = {.lex_state = 1},
   [49] = {.lex_state = 2},
   [50] = {.lex_state = 2},
   [51] = {.lex_state = 2},
   [52] = {.lex_state = 2},
   [53] = {.lex_state = 2},
   [54] = {.lex_state = 1},
-  [55] = {.lex_state = 1},
+  [55] = {.lex_state = 2},
   [56] = {.lex_state = 2},
   [57] = {.lex_state = 2},
   [58] = {.lex_state = 2},
   [59] = {.lex_state = 2},
   [60] = {.lex_state = 2},
   [61] = {.lex_state = 2},
-  [62] = {.lex_state = 1},
-  [63] = {.lex_state = 1},
-  [64] = {.lex_state = 2},
+  [62] = {.lex_state = 2},
+  [63] = {.lex_state = 2},
+  [64] = {.lex_state = 1},
   [65] = {.lex_state = 1},
   [66] = {.lex_state = 1},
   [67] = {.lex_state = 1},
@@ -2705,62 +2711,62 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [70] = {.lex_state = 1},
   [71] = {.lex_state = 1},
   [72] = {.lex_state = 1},
-  [73] = {.lex_state = 3},
+  [73] = {.lex_state = 1},
   [74] = {.lex_state = 1},
-  [75] = {.lex_state = 1},
+  [75] = {.lex_state = 3},
   [76] = {.lex_state = 1},
   [77] = {.lex_state = 1},
   [78] = {.lex_state = 1},
   [79] = {.lex_state = 1},
   [80] = {.lex_state = 1},
-  [81] = {.lex_state = 3},
+  [81] = {.lex_state = 1},
   [82] = {.lex_state = 1},
-  [83] = {.lex_state = 1},
+  [83] = {.lex_state = 3},
   [84] = {.lex_state = 1},
-  [85] = {.lex_state = 1},
+  [85] = {.lex_state = 3},
   [86] = {.lex_state = 1},
   [87] = {.lex_state = 1},
   [88] = {.lex_state = 1},
   [89] = {.lex_state = 1},
   [90] = {.lex_state = 1},
-  [91] = {.lex_state = 3},
+  [91] = {.lex_state = 1},
   [92] = {.lex_state = 1},
   [93] = {.lex_state = 1},
-  [94] = {.lex_state = 3},
+  [94] = {.lex_state = 1},
   [95] = {.lex_state = 1},
-  [96] = {.lex_state = 1},
-  [97] = {.lex_state = 5},
+  [96] = {.lex_state = 3},
+  [97] = {.lex_state = 1},
   [98] = {.lex_state = 2},
   [99] = {.lex_state = 5},
   [100] = {.lex_state = 5},
-  [101] = {.lex_state = 6},
-  [102] = {.lex_state = 3},
+  [101] = {.lex_state = 5},
+  [102] = {.lex_state = 6},
   [103] = {.lex_state = 1},
   [104] = {.lex_state = 3},
-  [105] = {.lex_state = 6},
-  [106] = {.lex_state = 1},
-  [107] = {.lex_state = 3},
-  [108] = {.lex_state = 3},
+  [105] = {.lex_state = 1},
+  [106] = {.lex_state = 6},
+  [107] = {.lex_state = 6},
+  [108] = {.lex_state = 6},
   [109] = {.lex_state = 6},
   [110] = {.lex_state = 6},
-  [111] = {.lex_state = 5},
+  [111] = {.lex_state = 6},
   [112] = {.lex_state = 6},
-  [113] = {.lex_state = 6},
-  [114] = {.lex_state = 6},
+  [113] = {.lex_state = 5},
+  [114] = {.lex_state = 3},
   [115] = {.lex_state = 6},
-  [116] = {.lex_state = 6},
-  [117] = {.lex_state = 6},
-  [118] = {.lex_state = 6},
-  [119] = {.lex_state = 3},
-  [120] = {.lex_state = 3},
+  [116] = {.lex_state = 3},
+  [117] = {.lex_state = 3},
+  [118] = {.lex_state = 3},
+  [119] = {.lex_state = 6},
+  [120] = {.lex_state = 6},
   [121] = {.lex_state = 3},
-  [122] = {.lex_state = 6},
+  [122] = {.lex_state = 3},
   [123] = {.lex_state = 6},
-  [124] = {.lex_state = 1},
+  [124] = {.lex_state = 6},
   [125] = {.lex_state = 3},
-  [126] = {.lex_state = 6},
-  [127] = {.lex_state = 6},
-  [128] = {.lex_state = 6},
+  [126] = {.lex_state = 1},
+  [127] = {.lex_state = 3},
+  [128] = {.lex_state = 3},
   [129] = {.lex_state = 6},
   [130] = {.lex_state = 6},
   [131] = {.lex_state = 6},
@@ -2768,7 +2774,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [133] = {.lex_state = 6},
   [134] = {.lex_state = 6},
   [135] = {.lex_state = 6},
-  [136] = {.lex_state = 3},
+  [136] = {.lex_state = 6},
   [137] = {.lex_state = 6},
   [138] = {.lex_state = 6},
   [139] = {.lex_state = 6},
@@ -2778,7 +2784,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [143] = {.lex_state = 6},
   [144] = {.lex_state = 6},
   [145] = {.lex_state = 6},
-  [146] = {.lex_state = 1},
+  [146] = {.lex_state = 6},
   [147] = {.lex_state = 6},
   [148] = {.lex_state = 6},
   [149] = {.lex_state = 6},
@@ -2789,16 +2795,16 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [154] = {.lex_state = 6},
   [155] = {.lex_state = 6},
   [156] = {.lex_state = 6},
-  [157] = {.lex_state = 3},
-  [158] = {.lex_state = 3},
+  [157] = {.lex_state = 6},
+  [158] = {.lex_state = 1},
   [159] = {.lex_state = 3},
-  [160] = {.lex_state = 1},
+  [160] = {.lex_state = 3},
   [161] = {.lex_state = 3},
-  [162] = {.lex_state = 3},
+  [162] = {.lex_state = 1},
   [163] = {.lex_state = 3},
-  [164] = {.lex_state = 1},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
+  [164] = {.lex_state = 3},
+  [165] = {.lex_state = 3},
+  [166] = {.lex_state = 1},
   [167] = {.lex_state = 0},
   [168] = {.lex_state = 0},
   [169] = {.lex_state = 0},
@@ -2814,21 +2820,21 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [179] = {.lex_state = 0},
   [180] = {.lex_state = 0},
   [181] = {.lex_state = 0},
-  [182] = {.lex_state = 2},
-  [183] = {.lex_state = 2},
+  [182] = {.lex_state = 0},
+  [183] = {.lex_state = 0},
   [184] = {.lex_state = 0},
-  [185] = {.lex_state = 0},
-  [186] = {.lex_state = 0},
+  [185] = {.lex_state = 2},
+  [186] = {.lex_state = 2},
   [187] = {.lex_state = 0},
   [188] = {.lex_state = 0},
   [189] = {.lex_state = 0},
   [190] = {.lex_state = 0},
   [191] = {.lex_state = 0},
-  [192] = {.lex_state = 2},
-  [193] = {.lex_state = 2},
+  [192] = {.lex_state = 0},
+  [193] = {.lex_state = 0},
   [194] = {.lex_state = 0},
-  [195] = {.lex_state = 0},
-  [196] = {.lex_state = 0},
+  [195] = {.lex_state = 2},
+  [196] = {.lex_state = 2},
   [197] = {.lex_state = 0},
   [198] = {.lex_state = 0},
   [199] = {.lex_state = 0},
@@ -2875,7 +2881,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [240] = {.lex_state = 0},
   [241] = {.lex_state = 0},
   [242] = {.lex_state = 0},
-  [243] = {.lex_state = 0},
+  [243] = {.lex_state = 2},
   [244] = {.lex_state = 0},
   [245] = {.lex_state = 0},
   [246] = {.lex_state = 0},
@@ -2898,7 +2904,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [263] = {.lex_state = 0},
   [264] = {.lex_state = 0},
   [265] = {.lex_state = 0},
-  [266] = {.lex_state = 2},
+  [266] = {.lex_state = 0},
   [267] = {.lex_state = 0},
   [268] = {.lex_state = 0},
   [269] = {.lex_state = 0},
@@ -2988,6 +2994,8 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [353] = {.lex_state = 0},
   [354] = {.lex_state = 0},
   [355] = {.lex_state = 0},
+  [356] = {.lex_state = 0},
+  [357] = {.lex_state = 0},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -3058,10 +3066,10 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_multi_line_comment] = ACTIONS(3),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(353),
-    [sym_global_object] = STATE(272),
-    [sym_const_and_type] = STATE(337),
-    [aux_sym__linebreak] = STATE(181),
+    [sym_source_file] = STATE(345),
+    [sym_global_object] = STATE(260),
+    [sym_const_and_type] = STATE(343),
+    [aux_sym__linebreak] = STATE(180),
     [ts_builtin_sym_end] = ACTIONS(7),
     [anon_sym___builtin__] = ACTIONS(9),
     [anon_sym_extern] = ACTIONS(9),
@@ -3074,32 +3082,32 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_multi_line_comment] = ACTIONS(3),
   },
   [STATE(2)] = {
-    [sym_block] = STATE(249),
-    [sym_decl_assign_statement] = STATE(249),
-    [sym_assign_left_side] = STATE(231),
-    [sym_assign_to] = STATE(208),
+    [sym_block] = STATE(265),
+    [sym_decl_assign_statement] = STATE(265),
+    [sym_assign_left_side] = STATE(237),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
-    [sym_if_statement] = STATE(249),
-    [sym_for_statement] = STATE(249),
-    [sym_domain_statement] = STATE(249),
-    [sym_interface_statement] = STATE(249),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
-    [aux_sym__linebreak] = STATE(3),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [sym_if_statement] = STATE(265),
+    [sym_for_statement] = STATE(265),
+    [sym_domain_statement] = STATE(265),
+    [sym_interface_statement] = STATE(265),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
+    [aux_sym__linebreak] = STATE(12),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(21),
@@ -3137,32 +3145,32 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_multi_line_comment] = ACTIONS(3),
   },
   [STATE(3)] = {
-    [sym_block] = STATE(270),
-    [sym_decl_assign_statement] = STATE(270),
-    [sym_assign_left_side] = STATE(228),
-    [sym_assign_to] = STATE(208),
+    [sym_block] = STATE(257),
+    [sym_decl_assign_statement] = STATE(257),
+    [sym_assign_left_side] = STATE(234),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
-    [sym_if_statement] = STATE(270),
-    [sym_for_statement] = STATE(270),
-    [sym_domain_statement] = STATE(270),
-    [sym_interface_statement] = STATE(270),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
-    [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [sym_if_statement] = STATE(257),
+    [sym_for_statement] = STATE(257),
+    [sym_domain_statement] = STATE(257),
+    [sym_interface_statement] = STATE(257),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
+    [aux_sym__linebreak] = STATE(2),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(53),
@@ -3202,30 +3210,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(4)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(57),
@@ -3257,7 +3265,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3265,30 +3273,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(5)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(59),
@@ -3320,7 +3328,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3328,30 +3336,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(6)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(61),
@@ -3383,7 +3391,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3391,30 +3399,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(7)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(63),
@@ -3446,7 +3454,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3454,30 +3462,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(8)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(65),
@@ -3509,7 +3517,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3517,30 +3525,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(9)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_RBRACE] = ACTIONS(67),
@@ -3572,7 +3580,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3580,30 +3588,30 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [STATE(10)] = {
     [sym_block] = STATE(314),
     [sym_decl_assign_statement] = STATE(314),
-    [sym_assign_left_side] = STATE(281),
-    [sym_assign_to] = STATE(208),
+    [sym_assign_left_side] = STATE(297),
+    [sym_assign_to] = STATE(207),
     [sym_write_modifiers] = STATE(13),
     [sym_if_statement] = STATE(314),
     [sym_for_statement] = STATE(314),
     [sym_domain_statement] = STATE(314),
     [sym_interface_statement] = STATE(314),
-    [sym_declaration] = STATE(256),
-    [sym_declaration_modifiers] = STATE(202),
-    [sym__type] = STATE(275),
-    [sym_array_type] = STATE(275),
-    [sym__expression] = STATE(107),
-    [sym_unary_op] = STATE(107),
-    [sym_binary_op] = STATE(107),
-    [sym_array_op] = STATE(107),
-    [sym_func_call] = STATE(107),
-    [sym_field_access] = STATE(107),
-    [sym_parenthesis_expression] = STATE(107),
-    [sym_array_list_expression] = STATE(107),
-    [sym_namespace_list] = STATE(17),
-    [sym_template_global] = STATE(120),
+    [sym_declaration] = STATE(267),
+    [sym_declaration_modifiers] = STATE(208),
+    [sym__type] = STATE(277),
+    [sym_array_type] = STATE(277),
+    [sym__expression] = STATE(114),
+    [sym_unary_op] = STATE(114),
+    [sym_binary_op] = STATE(114),
+    [sym_array_op] = STATE(114),
+    [sym_func_call] = STATE(114),
+    [sym_field_access] = STATE(114),
+    [sym_parenthesis_expression] = STATE(114),
+    [sym_array_list_expression] = STATE(114),
+    [sym_namespace_list] = STATE(19),
+    [sym_template_global] = STATE(121),
     [aux_sym__linebreak] = STATE(12),
-    [aux_sym_write_modifiers_repeat1] = STATE(124),
-    [aux_sym_declaration_modifiers_repeat1] = STATE(188),
+    [aux_sym_write_modifiers_repeat1] = STATE(126),
+    [aux_sym_declaration_modifiers_repeat1] = STATE(192),
     [sym_identifier] = ACTIONS(17),
     [anon_sym_LBRACE] = ACTIONS(19),
     [anon_sym_reg] = ACTIONS(23),
@@ -3634,7 +3642,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_float] = ACTIONS(49),
     [sym_string] = ACTIONS(49),
     [sym_bool_array_literal] = ACTIONS(49),
-    [anon_sym_LF] = ACTIONS(55),
+    [anon_sym_LF] = ACTIONS(51),
     [sym_doc_comment] = ACTIONS(3),
     [sym_single_line_comment] = ACTIONS(5),
     [sym_multi_line_comment] = ACTIONS(3),
@@ -3661,24 +3669,24 @@ static const uint16_t ts_small_parse_table[] = {
       sym_number,
     STATE(13), 1,
       sym_write_modifiers,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(120), 1,
+    STATE(121), 1,
       sym_template_global,
-    STATE(124), 1,
+    STATE(126), 1,
       aux_sym_write_modifiers_repeat1,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(245), 1,
+    STATE(246), 1,
       sym_assign_to,
-    STATE(256), 1,
+    STATE(267), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 2,
+    STATE(277), 2,
       sym__type,
       sym_array_type,
     ACTIONS(49), 3,
@@ -3698,7 +3706,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(107), 8,
+    STATE(114), 8,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -3766,20 +3774,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_COLON_COLON,
     ACTIONS(76), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(120), 1,
+    STATE(121), 1,
       sym_template_global,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(261), 1,
+    STATE(244), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 2,
+    STATE(277), 2,
       sym__type,
       sym_array_type,
     ACTIONS(78), 3,
@@ -3799,7 +3807,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(102), 8,
+    STATE(104), 8,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -3813,7 +3821,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_single_line_comment,
     ACTIONS(84), 1,
       anon_sym_COLON_COLON,
-    STATE(16), 1,
+    STATE(15), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -3897,7 +3905,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_single_line_comment,
     ACTIONS(84), 1,
       anon_sym_COLON_COLON,
-    STATE(15), 1,
+    STATE(14), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -3934,31 +3942,28 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [355] = 6,
+  [355] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(99), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(32), 1,
-      sym_template_args,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(97), 8,
+    ACTIONS(97), 9,
       anon_sym_EQ,
       anon_sym_in,
+      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(101), 21,
+    ACTIONS(99), 22,
+      anon_sym_POUND_LPAREN,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
-      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -3973,30 +3978,34 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
+      anon_sym_COLON_COLON,
       anon_sym_COMMA,
       anon_sym_LF,
-  [402] = 4,
+  [398] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(103), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(27), 1,
+      sym_template_args,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(103), 9,
+    ACTIONS(101), 8,
       anon_sym_EQ,
       anon_sym_in,
-      anon_sym_COLON,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(105), 22,
-      anon_sym_POUND_LPAREN,
+    ACTIONS(105), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DOT_DOT,
+      anon_sym_COLON,
       anon_sym_DASH_GT,
       anon_sym_PLUS,
       anon_sym_STAR,
@@ -4011,15 +4020,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LPAREN,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
-      anon_sym_COLON_COLON,
       anon_sym_COMMA,
       anon_sym_LF,
   [445] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(99), 1,
+    ACTIONS(103), 1,
       anon_sym_POUND_LPAREN,
-    STATE(30), 1,
+    STATE(39), 1,
       sym_template_args,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4066,9 +4074,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LPAREN,
     ACTIONS(123), 1,
       anon_sym_LBRACK,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4100,36 +4108,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [548] = 13,
+  [548] = 9,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
     ACTIONS(119), 1,
       anon_sym_DOT,
     ACTIONS(121), 1,
       anon_sym_LPAREN,
     ACTIONS(123), 1,
       anon_sym_LBRACK,
-    ACTIONS(125), 1,
-      anon_sym_PLUS,
-    ACTIONS(127), 1,
-      anon_sym_DASH,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(113), 3,
+    ACTIONS(113), 5,
       anon_sym_EQ,
+      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(111), 17,
+      anon_sym_SLASH,
+    ACTIONS(111), 20,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4137,6 +4138,8 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_DOT,
       anon_sym_COLON,
       anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
@@ -4144,10 +4147,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_PERCENT,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [608] = 14,
+  [600] = 14,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -4164,9 +4168,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
     ACTIONS(129), 1,
       anon_sym_AMP,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4195,29 +4199,36 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [670] = 9,
+  [662] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
     ACTIONS(119), 1,
       anon_sym_DOT,
     ACTIONS(121), 1,
       anon_sym_LPAREN,
     ACTIONS(123), 1,
       anon_sym_LBRACK,
-    STATE(46), 1,
+    ACTIONS(125), 1,
+      anon_sym_PLUS,
+    ACTIONS(127), 1,
+      anon_sym_DASH,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(113), 5,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(113), 3,
       anon_sym_EQ,
-      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(111), 20,
+    ACTIONS(111), 17,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4225,8 +4236,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_DOT,
       anon_sym_COLON,
       anon_sym_DASH_GT,
-      anon_sym_PLUS,
-      anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
@@ -4234,7 +4243,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
@@ -4257,9 +4265,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_AMP,
     ACTIONS(131), 1,
       anon_sym_PIPE,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4308,9 +4316,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4346,9 +4354,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LPAREN,
     ACTIONS(123), 1,
       anon_sym_LBRACK,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4432,9 +4440,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_COLON_COLON,
     ACTIONS(159), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
-    STATE(344), 1,
+    STATE(352), 1,
       sym_slice,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -4455,7 +4463,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(101), 9,
+    STATE(112), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -4539,61 +4547,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1090] = 15,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(143), 1,
-      sym_identifier,
-    ACTIONS(145), 1,
-      anon_sym_COLON,
-    ACTIONS(151), 1,
-      anon_sym_LPAREN,
-    ACTIONS(153), 1,
-      anon_sym_LBRACK,
-    ACTIONS(157), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(171), 1,
-      sym_number,
-    STATE(109), 1,
-      sym_namespace_list,
-    STATE(348), 1,
-      sym_slice,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(147), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(155), 2,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-    ACTIONS(173), 3,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-    ACTIONS(149), 5,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-    STATE(105), 9,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_array_list_expression,
-      sym_template_global,
-  [1153] = 4,
+  [1090] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(175), 8,
+    ACTIONS(171), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4602,7 +4562,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(177), 21,
+    ACTIONS(173), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4624,13 +4584,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1194] = 4,
+  [1131] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(179), 8,
+    ACTIONS(175), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4639,7 +4599,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(181), 21,
+    ACTIONS(177), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4661,13 +4621,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1235] = 4,
+  [1172] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(183), 8,
+    ACTIONS(179), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4676,7 +4636,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(185), 21,
+    ACTIONS(181), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4698,13 +4658,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1276] = 4,
+  [1213] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(187), 8,
+    ACTIONS(183), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4713,7 +4673,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(189), 21,
+    ACTIONS(185), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4735,13 +4695,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1317] = 4,
+  [1254] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(191), 8,
+    ACTIONS(187), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4750,7 +4710,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(193), 21,
+    ACTIONS(189), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4772,13 +4732,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1358] = 4,
+  [1295] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(195), 8,
+    ACTIONS(191), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4787,7 +4747,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(197), 21,
+    ACTIONS(193), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4809,13 +4769,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1399] = 4,
+  [1336] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(199), 8,
+    ACTIONS(195), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4824,7 +4784,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(201), 21,
+    ACTIONS(197), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4846,13 +4806,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1440] = 4,
+  [1377] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(203), 8,
+    ACTIONS(199), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4861,7 +4821,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(205), 21,
+    ACTIONS(201), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4883,13 +4843,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1481] = 4,
+  [1418] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(207), 8,
+    ACTIONS(203), 8,
       anon_sym_EQ,
       anon_sym_in,
       anon_sym_DASH,
@@ -4898,7 +4858,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       sym_identifier,
-    ACTIONS(209), 21,
+    ACTIONS(205), 21,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -4920,70 +4880,68 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1522] = 18,
+  [1459] = 15,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
+    ACTIONS(143), 1,
+      sym_identifier,
+    ACTIONS(145), 1,
+      anon_sym_COLON,
+    ACTIONS(151), 1,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
+    ACTIONS(153), 1,
       anon_sym_LBRACK,
-    ACTIONS(125), 1,
-      anon_sym_PLUS,
-    ACTIONS(127), 1,
-      anon_sym_DASH,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(213), 1,
-      anon_sym_EQ,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
+    ACTIONS(157), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(120), 1,
+      sym_namespace_list,
+    STATE(335), 1,
+      sym_slice,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
+    ACTIONS(147), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(155), 2,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+    ACTIONS(209), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(149), 5,
       anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-    ACTIONS(211), 8,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_in,
-      anon_sym_COLON,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [1590] = 4,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(119), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [1522] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(223), 6,
+    ACTIONS(213), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(221), 22,
+    ACTIONS(211), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5006,20 +4964,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1630] = 4,
+  [1562] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(227), 6,
+    ACTIONS(217), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(225), 22,
+    ACTIONS(215), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5042,20 +5000,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1670] = 4,
+  [1602] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(231), 6,
+    ACTIONS(221), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(229), 22,
+    ACTIONS(219), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5078,20 +5036,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1710] = 4,
+  [1642] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(235), 6,
+    ACTIONS(225), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(233), 22,
+    ACTIONS(223), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5114,56 +5072,66 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1750] = 4,
+  [1682] = 14,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(239), 6,
-      anon_sym_EQ,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-      anon_sym_DOT,
-    ACTIONS(237), 22,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_in,
-      anon_sym_DOT_DOT,
-      anon_sym_COLON,
-      anon_sym_DASH_GT,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_LBRACK,
+    ACTIONS(45), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(227), 1,
+      sym_identifier,
+    ACTIONS(229), 1,
+      anon_sym_RBRACK,
+    ACTIONS(231), 1,
+      sym_number,
+    ACTIONS(235), 1,
+      anon_sym_LF,
+    STATE(19), 1,
+      sym_namespace_list,
+    STATE(48), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(233), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_STAR,
+      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [1790] = 4,
+    STATE(75), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [1742] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(243), 6,
+    ACTIONS(239), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(241), 22,
+    ACTIONS(237), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5186,20 +5154,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1830] = 4,
+  [1782] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(247), 6,
+    ACTIONS(243), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(245), 22,
+    ACTIONS(241), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5222,20 +5190,66 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1870] = 4,
+  [1822] = 14,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_LBRACK,
+    ACTIONS(45), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(51), 1,
+      anon_sym_LF,
+    ACTIONS(227), 1,
+      sym_identifier,
+    ACTIONS(245), 1,
+      anon_sym_RBRACK,
+    ACTIONS(247), 1,
+      sym_number,
+    STATE(12), 1,
+      aux_sym__linebreak,
+    STATE(19), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(249), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(83), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [1882] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(251), 6,
+    ACTIONS(253), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(249), 22,
+    ACTIONS(251), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5258,20 +5272,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1910] = 4,
+  [1922] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(255), 6,
+    ACTIONS(257), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(253), 22,
+    ACTIONS(255), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5294,56 +5308,70 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1950] = 4,
+  [1962] = 18,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(125), 1,
+      anon_sym_PLUS,
+    ACTIONS(127), 1,
+      anon_sym_DASH,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(261), 1,
+      anon_sym_EQ,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(259), 6,
-      anon_sym_EQ,
-      anon_sym_DASH,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-      anon_sym_DOT,
-    ACTIONS(257), 22,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(259), 8,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_in,
-      anon_sym_DOT_DOT,
       anon_sym_COLON,
       anon_sym_DASH_GT,
-      anon_sym_PLUS,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [1990] = 4,
+  [2030] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(263), 6,
+    ACTIONS(271), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(261), 22,
+    ACTIONS(269), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5366,20 +5394,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2030] = 4,
+  [2070] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(267), 6,
+    ACTIONS(275), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(265), 22,
+    ACTIONS(273), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5402,53 +5430,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2070] = 14,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(41), 1,
-      anon_sym_LPAREN,
-    ACTIONS(43), 1,
-      anon_sym_LBRACK,
-    ACTIONS(45), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(55), 1,
-      anon_sym_LF,
-    ACTIONS(269), 1,
-      sym_identifier,
-    ACTIONS(271), 1,
-      anon_sym_RBRACK,
-    ACTIONS(273), 1,
-      sym_number,
-    STATE(12), 1,
-      aux_sym__linebreak,
-    STATE(17), 1,
-      sym_namespace_list,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(275), 3,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-    ACTIONS(39), 7,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-    STATE(73), 9,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_array_list_expression,
-      sym_template_global,
-  [2130] = 14,
+  [2110] = 14,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -5457,7 +5439,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(277), 1,
       anon_sym_RBRACK,
@@ -5465,9 +5447,9 @@ static const uint16_t ts_small_parse_table[] = {
       sym_number,
     ACTIONS(283), 1,
       anon_sym_LF,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(54), 1,
+    STATE(64), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -5484,7 +5466,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(81), 9,
+    STATE(85), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -5494,7 +5476,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [2190] = 4,
+  [2170] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5530,7 +5512,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2230] = 4,
+  [2210] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5566,7 +5548,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2270] = 4,
+  [2250] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5602,7 +5584,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2310] = 4,
+  [2290] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5638,7 +5620,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2350] = 4,
+  [2330] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5674,7 +5656,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2390] = 4,
+  [2370] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -5710,112 +5692,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2430] = 14,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(41), 1,
-      anon_sym_LPAREN,
-    ACTIONS(43), 1,
-      anon_sym_LBRACK,
-    ACTIONS(45), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
-      sym_identifier,
-    ACTIONS(309), 1,
-      anon_sym_RBRACK,
-    ACTIONS(311), 1,
-      sym_number,
-    ACTIONS(315), 1,
-      anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(63), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(313), 3,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-    ACTIONS(39), 7,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-    STATE(91), 9,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_array_list_expression,
-      sym_template_global,
-  [2490] = 14,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(41), 1,
-      anon_sym_LPAREN,
-    ACTIONS(43), 1,
-      anon_sym_LBRACK,
-    ACTIONS(45), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(55), 1,
-      anon_sym_LF,
-    ACTIONS(269), 1,
-      sym_identifier,
-    ACTIONS(317), 1,
-      anon_sym_RBRACK,
-    ACTIONS(319), 1,
-      sym_number,
-    STATE(12), 1,
-      aux_sym__linebreak,
-    STATE(17), 1,
-      sym_namespace_list,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(321), 3,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-    ACTIONS(39), 7,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-    STATE(94), 9,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_array_list_expression,
-      sym_template_global,
-  [2550] = 4,
+  [2410] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(325), 6,
+    ACTIONS(311), 6,
       anon_sym_EQ,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(323), 22,
+    ACTIONS(309), 22,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -5838,22 +5728,140 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_COMMA,
       anon_sym_LF,
-  [2590] = 12,
+  [2450] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(17), 1,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(315), 6,
+      anon_sym_EQ,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(313), 22,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [2490] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(319), 6,
+      anon_sym_EQ,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+    ACTIONS(317), 22,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_in,
+      anon_sym_DOT_DOT,
+      anon_sym_COLON,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [2530] = 14,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_LBRACK,
+    ACTIONS(45), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(51), 1,
+      anon_sym_LF,
+    ACTIONS(227), 1,
       sym_identifier,
+    ACTIONS(321), 1,
+      anon_sym_RBRACK,
+    ACTIONS(323), 1,
+      sym_number,
+    STATE(12), 1,
+      aux_sym__linebreak,
+    STATE(19), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(325), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(96), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [2590] = 12,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
     ACTIONS(41), 1,
       anon_sym_LPAREN,
     ACTIONS(43), 1,
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
+    ACTIONS(227), 1,
+      sym_identifier,
     ACTIONS(327), 1,
-      anon_sym_type,
+      anon_sym_RBRACK,
     ACTIONS(329), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -5870,7 +5878,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(121), 9,
+    STATE(159), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -5889,13 +5897,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(333), 1,
-      anon_sym_RBRACK,
+      anon_sym_RPAREN,
     ACTIONS(335), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -5912,7 +5920,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(161), 9,
+    STATE(117), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -5931,13 +5939,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(339), 1,
       anon_sym_RPAREN,
     ACTIONS(341), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -5954,7 +5962,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(104), 9,
+    STATE(118), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -5967,19 +5975,19 @@ static const uint16_t ts_small_parse_table[] = {
   [2752] = 12,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
     ACTIONS(41), 1,
       anon_sym_LPAREN,
     ACTIONS(43), 1,
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
-      sym_identifier,
     ACTIONS(345), 1,
-      anon_sym_RBRACK,
+      anon_sym_type,
     ACTIONS(347), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -5996,7 +6004,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(159), 9,
+    STATE(122), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6015,13 +6023,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(351), 1,
-      anon_sym_RPAREN,
+      anon_sym_RBRACK,
     ACTIONS(353), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6038,7 +6046,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(119), 9,
+    STATE(164), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6057,11 +6065,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(357), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6078,7 +6086,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(108), 9,
+    STATE(116), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6097,11 +6105,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(361), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6118,7 +6126,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(22), 9,
+    STATE(163), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6137,11 +6145,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(365), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6158,7 +6166,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(136), 9,
+    STATE(26), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6168,57 +6176,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3013] = 21,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
-      anon_sym_LPAREN,
-    ACTIONS(123), 1,
-      anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(369), 1,
-      anon_sym_RBRACK,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(373), 1,
-      anon_sym_LF,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
-    STATE(70), 1,
-      sym__comma,
-    STATE(198), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
-    STATE(279), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [3084] = 11,
+  [3013] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -6227,16 +6185,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(375), 1,
+    ACTIONS(369), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(377), 3,
+    ACTIONS(371), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6248,7 +6206,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(41), 9,
+    STATE(22), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6258,7 +6216,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3135] = 11,
+  [3064] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -6267,16 +6225,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(379), 1,
+    ACTIONS(373), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(381), 3,
+    ACTIONS(375), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6288,7 +6246,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(125), 9,
+    STATE(160), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6298,6 +6256,56 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
+  [3115] = 21,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    ACTIONS(377), 1,
+      anon_sym_RBRACK,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(381), 1,
+      anon_sym_LF,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    STATE(70), 1,
+      sym__comma,
+    STATE(211), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(285), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(265), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
   [3186] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
@@ -6307,11 +6315,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(383), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6328,7 +6336,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(98), 9,
+    STATE(127), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6347,11 +6355,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(387), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6368,7 +6376,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(158), 9,
+    STATE(23), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6387,11 +6395,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(391), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6408,7 +6416,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(20), 9,
+    STATE(24), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6427,11 +6435,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(395), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6448,7 +6456,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(23), 9,
+    STATE(25), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6467,11 +6475,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
     ACTIONS(399), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6488,7 +6496,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(157), 9,
+    STATE(125), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6498,38 +6506,118 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3441] = 21,
+  [3441] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
+    ACTIONS(43), 1,
       anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
+    ACTIONS(45), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(227), 1,
+      sym_identifier,
     ACTIONS(403), 1,
+      sym_number,
+    STATE(19), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(405), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(51), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [3492] = 11,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(41), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_LBRACK,
+    ACTIONS(45), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(227), 1,
+      sym_identifier,
+    ACTIONS(407), 1,
+      sym_number,
+    STATE(19), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(409), 3,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+    ACTIONS(39), 7,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+    STATE(128), 9,
+      sym__expression,
+      sym_unary_op,
+      sym_binary_op,
+      sym_array_op,
+      sym_func_call,
+      sym_field_access,
+      sym_parenthesis_expression,
+      sym_array_list_expression,
+      sym_template_global,
+  [3543] = 21,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(411), 1,
       anon_sym_RBRACK,
-    ACTIONS(405), 1,
+    ACTIONS(413), 1,
       anon_sym_LF,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     STATE(70), 1,
       sym__comma,
-    STATE(205), 1,
+    STATE(219), 1,
       aux_sym_parenthesis_expression_list_repeat1,
-    STATE(278), 1,
+    STATE(281), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -6540,37 +6628,37 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [3512] = 11,
+  [3614] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(143), 1,
-      sym_identifier,
-    ACTIONS(151), 1,
+    ACTIONS(41), 1,
       anon_sym_LPAREN,
-    ACTIONS(153), 1,
+    ACTIONS(43), 1,
       anon_sym_LBRACK,
-    ACTIONS(157), 1,
+    ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(407), 1,
+    ACTIONS(227), 1,
+      sym_identifier,
+    ACTIONS(415), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(409), 3,
+    ACTIONS(417), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
-    ACTIONS(149), 7,
+    ACTIONS(39), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -6578,7 +6666,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(112), 9,
+    STATE(98), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6588,7 +6676,57 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3563] = 11,
+  [3665] = 21,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(419), 1,
+      anon_sym_RBRACK,
+    ACTIONS(421), 1,
+      anon_sym_LF,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    STATE(70), 1,
+      sym__comma,
+    STATE(218), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(302), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(265), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3736] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -6597,16 +6735,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(423), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(413), 3,
+    ACTIONS(425), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6618,7 +6756,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(26), 9,
+    STATE(20), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6628,7 +6766,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3614] = 11,
+  [3787] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6639,14 +6777,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(415), 1,
+    ACTIONS(427), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(417), 3,
+    ACTIONS(429), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6658,7 +6796,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(113), 9,
+    STATE(115), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6668,7 +6806,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3665] = 11,
+  [3838] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6679,14 +6817,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(419), 1,
+    ACTIONS(431), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(421), 3,
+    ACTIONS(433), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6698,7 +6836,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(114), 9,
+    STATE(110), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6708,7 +6846,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3716] = 11,
+  [3889] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6719,14 +6857,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(423), 1,
+    ACTIONS(435), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(425), 3,
+    ACTIONS(437), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6738,7 +6876,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(115), 9,
+    STATE(107), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6748,7 +6886,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3767] = 11,
+  [3940] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6759,14 +6897,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(427), 1,
+    ACTIONS(439), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(429), 3,
+    ACTIONS(441), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6778,7 +6916,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(116), 9,
+    STATE(108), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6788,7 +6926,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3818] = 11,
+  [3991] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6799,14 +6937,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(431), 1,
+    ACTIONS(443), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(433), 3,
+    ACTIONS(445), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6818,7 +6956,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(117), 9,
+    STATE(109), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6828,7 +6966,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3869] = 11,
+  [4042] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(143), 1,
@@ -6839,14 +6977,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(435), 1,
+    ACTIONS(447), 1,
       sym_number,
-    STATE(109), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(437), 3,
+    ACTIONS(449), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6858,7 +6996,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(118), 9,
+    STATE(111), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6868,29 +7006,29 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3920] = 11,
+  [4093] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(41), 1,
+    ACTIONS(143), 1,
+      sym_identifier,
+    ACTIONS(151), 1,
       anon_sym_LPAREN,
-    ACTIONS(43), 1,
+    ACTIONS(153), 1,
       anon_sym_LBRACK,
-    ACTIONS(45), 1,
+    ACTIONS(157), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
-      sym_identifier,
-    ACTIONS(439), 1,
+    ACTIONS(451), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(120), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(441), 3,
+    ACTIONS(453), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
-    ACTIONS(39), 7,
+    ACTIONS(149), 7,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -6898,7 +7036,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(21), 9,
+    STATE(106), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6908,57 +7046,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [3971] = 21,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
-      anon_sym_LPAREN,
-    ACTIONS(123), 1,
-      anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(443), 1,
-      anon_sym_RBRACK,
-    ACTIONS(445), 1,
-      anon_sym_LF,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
-    STATE(70), 1,
-      sym__comma,
-    STATE(216), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
-    STATE(298), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [4042] = 11,
+  [4144] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -6967,16 +7055,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(455), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(449), 3,
+    ACTIONS(457), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -6988,7 +7076,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(24), 9,
+    STATE(21), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -6998,7 +7086,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [4093] = 11,
+  [4195] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -7007,16 +7095,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(451), 1,
+    ACTIONS(459), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(453), 3,
+    ACTIONS(461), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -7028,7 +7116,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(162), 9,
+    STATE(161), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -7038,7 +7126,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [4144] = 21,
+  [4246] = 21,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -7053,23 +7141,23 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(455), 1,
+    ACTIONS(463), 1,
       anon_sym_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(465), 1,
       anon_sym_LF,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     STATE(70), 1,
       sym__comma,
-    STATE(218), 1,
+    STATE(204), 1,
       aux_sym_parenthesis_expression_list_repeat1,
-    STATE(304), 1,
+    STATE(306), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7080,55 +7168,15 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [4215] = 11,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(41), 1,
-      anon_sym_LPAREN,
-    ACTIONS(43), 1,
-      anon_sym_LBRACK,
-    ACTIONS(45), 1,
-      anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
-      sym_identifier,
-    ACTIONS(459), 1,
-      sym_number,
-    STATE(17), 1,
-      sym_namespace_list,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(461), 3,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-    ACTIONS(39), 7,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-    STATE(163), 9,
-      sym__expression,
-      sym_unary_op,
-      sym_binary_op,
-      sym_array_op,
-      sym_func_call,
-      sym_field_access,
-      sym_parenthesis_expression,
-      sym_array_list_expression,
-      sym_template_global,
-  [4266] = 11,
+  [4317] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(41), 1,
@@ -7137,16 +7185,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    ACTIONS(463), 1,
+    ACTIONS(467), 1,
       sym_number,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(465), 3,
+    ACTIONS(469), 3,
       sym_float,
       sym_string,
       sym_bool_array_literal,
@@ -7158,7 +7206,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-    STATE(25), 9,
+    STATE(165), 9,
       sym__expression,
       sym_unary_op,
       sym_binary_op,
@@ -7168,41 +7216,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_parenthesis_expression,
       sym_array_list_expression,
       sym_template_global,
-  [4317] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(467), 1,
-      anon_sym_COLON_COLON,
-    STATE(99), 1,
-      aux_sym_namespace_list_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(80), 6,
-      anon_sym_COLON,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(82), 16,
-      anon_sym_POUND_LPAREN,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [4357] = 20,
+  [4368] = 20,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
@@ -7219,19 +7233,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(469), 1,
+    ACTIONS(471), 1,
       anon_sym_COLON,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
-    STATE(247), 1,
+    STATE(245), 1,
       sym_block,
-    STATE(289), 1,
+    STATE(287), 1,
       sym_interface_ports,
-    STATE(321), 1,
+    STATE(311), 1,
       sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7242,32 +7256,32 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [4425] = 6,
+  [4436] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(467), 1,
+    ACTIONS(473), 1,
       anon_sym_COLON_COLON,
-    STATE(100), 1,
+    STATE(99), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(93), 6,
+    ACTIONS(86), 6,
       anon_sym_COLON,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(95), 16,
+    ACTIONS(88), 16,
       anon_sym_POUND_LPAREN,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -7284,24 +7298,24 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [4465] = 6,
+  [4476] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(471), 1,
+    ACTIONS(476), 1,
       anon_sym_COLON_COLON,
-    STATE(100), 1,
+    STATE(99), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(86), 6,
+    ACTIONS(80), 6,
       anon_sym_COLON,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(88), 16,
+    ACTIONS(82), 16,
       anon_sym_POUND_LPAREN,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -7318,105 +7332,84 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [4505] = 17,
+  [4516] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(480), 1,
-      anon_sym_PIPE,
-    ACTIONS(482), 1,
-      anon_sym_AMP,
-    ACTIONS(484), 1,
-      anon_sym_CARET,
-    ACTIONS(490), 1,
-      anon_sym_SLASH,
-    ACTIONS(492), 1,
-      anon_sym_DOT,
-    ACTIONS(494), 1,
-      anon_sym_LPAREN,
-    ACTIONS(496), 1,
-      anon_sym_LBRACK,
-    ACTIONS(498), 1,
-      anon_sym_RBRACK,
-    STATE(130), 1,
-      sym_parenthesis_expression_list,
-    STATE(131), 1,
-      sym_array_access_bracket_expression,
+    ACTIONS(476), 1,
+      anon_sym_COLON_COLON,
+    STATE(100), 1,
+      aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(476), 2,
+    ACTIONS(93), 6,
+      anon_sym_COLON,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(478), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(488), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(474), 3,
-      anon_sym_COLON,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-    ACTIONS(486), 4,
+      anon_sym_SLASH,
+    ACTIONS(95), 16,
+      anon_sym_POUND_LPAREN,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [4566] = 17,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
       anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(502), 1,
-      anon_sym_EQ,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [4556] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(478), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(130), 1,
+      sym_template_args,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
+    ACTIONS(101), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(500), 3,
-      anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+    ACTIONS(105), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [4627] = 6,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [4595] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(55), 1,
+    ACTIONS(484), 1,
       anon_sym_LF,
-    STATE(12), 1,
+    STATE(105), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(504), 8,
+    ACTIONS(480), 8,
       anon_sym_reg,
       anon_sym_initial,
       anon_sym_state,
@@ -7425,7 +7418,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_output,
       sym_identifier,
       sym_number,
-    ACTIONS(506), 13,
+    ACTIONS(482), 13,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -7439,7 +7432,7 @@ static const uint16_t ts_small_parse_table[] = {
       sym_float,
       sym_string,
       sym_bool_array_literal,
-  [4666] = 19,
+  [4634] = 17,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -7454,20 +7447,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(508), 1,
-      anon_sym_RPAREN,
-    STATE(46), 1,
+    ACTIONS(488), 1,
+      anon_sym_EQ,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
-    STATE(70), 1,
-      sym__comma,
-    STATE(258), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
@@ -7477,69 +7464,29 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [4731] = 17,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(480), 1,
-      anon_sym_PIPE,
-    ACTIONS(482), 1,
-      anon_sym_AMP,
-    ACTIONS(484), 1,
-      anon_sym_CARET,
-    ACTIONS(490), 1,
-      anon_sym_SLASH,
-    ACTIONS(492), 1,
-      anon_sym_DOT,
-    ACTIONS(494), 1,
-      anon_sym_LPAREN,
-    ACTIONS(496), 1,
-      anon_sym_LBRACK,
-    ACTIONS(510), 1,
-      anon_sym_RBRACK,
-    STATE(130), 1,
-      sym_parenthesis_expression_list,
-    STATE(131), 1,
-      sym_array_access_bracket_expression,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(476), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(478), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(488), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(474), 3,
-      anon_sym_COLON,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-    ACTIONS(486), 4,
+    ACTIONS(486), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [4792] = 6,
+  [4695] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(516), 1,
+    ACTIONS(51), 1,
       anon_sym_LF,
-    STATE(103), 1,
+    STATE(12), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(512), 8,
+    ACTIONS(490), 8,
       anon_sym_reg,
       anon_sym_initial,
       anon_sym_state,
@@ -7548,7 +7495,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_output,
       sym_identifier,
       sym_number,
-    ACTIONS(514), 13,
+    ACTIONS(492), 13,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -7562,245 +7509,66 @@ static const uint16_t ts_small_parse_table[] = {
       sym_float,
       sym_string,
       sym_bool_array_literal,
-  [4831] = 17,
+  [4734] = 14,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(117), 1,
+    ACTIONS(498), 1,
+      anon_sym_PIPE,
+    ACTIONS(500), 1,
+      anon_sym_AMP,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(121), 1,
+    ACTIONS(504), 1,
+      anon_sym_DOT,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(520), 1,
-      anon_sym_EQ,
-    STATE(46), 1,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(113), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(518), 3,
-      anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-  [4892] = 16,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
-      anon_sym_LPAREN,
-    ACTIONS(123), 1,
-      anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(217), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(215), 4,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-    ACTIONS(522), 4,
-      anon_sym_RPAREN,
-      anon_sym_RBRACK,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [4951] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(524), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(126), 1,
-      sym_template_args,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(97), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(101), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [4990] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(524), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(127), 1,
-      sym_template_args,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(107), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(109), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5029] = 4,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(103), 6,
-      anon_sym_COLON,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(105), 17,
-      anon_sym_POUND_LPAREN,
+    ACTIONS(496), 2,
       anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
       anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-      anon_sym_COLON_COLON,
-  [5064] = 9,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(492), 1,
-      anon_sym_DOT,
-    ACTIONS(494), 1,
-      anon_sym_LPAREN,
-    ACTIONS(496), 1,
-      anon_sym_LBRACK,
-    STATE(130), 1,
-      sym_parenthesis_expression_list,
-    STATE(131), 1,
-      sym_array_access_bracket_expression,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(137), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(135), 13,
+    ACTIONS(111), 9,
       anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
       anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5109] = 11,
+  [4789] = 11,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(490), 1,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(478), 2,
+    ACTIONS(496), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
     ACTIONS(113), 4,
@@ -7820,18 +7588,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5158] = 9,
+  [4838] = 9,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7856,22 +7624,22 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5203] = 13,
+  [4883] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(482), 1,
+    ACTIONS(500), 1,
       anon_sym_AMP,
-    ACTIONS(490), 1,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7879,10 +7647,10 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(113), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(476), 2,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(478), 2,
+    ACTIONS(496), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
     ACTIONS(111), 10,
@@ -7896,20 +7664,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5256] = 12,
+  [4936] = 12,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(490), 1,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7917,10 +7685,10 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(113), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(476), 2,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(478), 2,
+    ACTIONS(496), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
     ACTIONS(111), 11,
@@ -7935,24 +7703,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5307] = 14,
+  [4987] = 15,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(480), 1,
+    ACTIONS(498), 1,
       anon_sym_PIPE,
-    ACTIONS(482), 1,
+    ACTIONS(500), 1,
       anon_sym_AMP,
-    ACTIONS(490), 1,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    ACTIONS(510), 1,
+      anon_sym_CARET,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -7960,15 +7730,14 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(113), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(476), 2,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(478), 2,
+    ACTIONS(496), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(111), 9,
+    ACTIONS(111), 8,
       anon_sym_COLON,
-      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
@@ -7976,49 +7745,82 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5362] = 15,
+  [5044] = 17,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(480), 1,
+    ACTIONS(498), 1,
       anon_sym_PIPE,
-    ACTIONS(482), 1,
+    ACTIONS(500), 1,
       anon_sym_AMP,
-    ACTIONS(484), 1,
-      anon_sym_CARET,
-    ACTIONS(490), 1,
+    ACTIONS(502), 1,
       anon_sym_SLASH,
-    ACTIONS(492), 1,
+    ACTIONS(504), 1,
       anon_sym_DOT,
-    ACTIONS(494), 1,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
-    ACTIONS(496), 1,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
-    STATE(130), 1,
+    ACTIONS(510), 1,
+      anon_sym_CARET,
+    ACTIONS(518), 1,
+      anon_sym_RBRACK,
+    STATE(133), 1,
       sym_parenthesis_expression_list,
-    STATE(131), 1,
+    STATE(134), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(113), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(476), 2,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(478), 2,
+    ACTIONS(496), 2,
       anon_sym_STAR,
       anon_sym_PERCENT,
-    ACTIONS(111), 8,
+    ACTIONS(516), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(512), 3,
+      anon_sym_COLON,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+    ACTIONS(514), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [5105] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(97), 6,
       anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(99), 17,
+      anon_sym_POUND_LPAREN,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5419] = 19,
+      anon_sym_COLON_COLON,
+  [5140] = 17,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -8033,20 +7835,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(526), 1,
-      anon_sym_RPAREN,
-    STATE(46), 1,
+    ACTIONS(522), 1,
+      anon_sym_EQ,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
-    STATE(70), 1,
-      sym__comma,
-    STATE(271), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
@@ -8056,33 +7852,42 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(520), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [5484] = 6,
+  [5201] = 9,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(528), 1,
-      sym_identifier,
-    ACTIONS(534), 1,
+    ACTIONS(504), 1,
+      anon_sym_DOT,
+    ACTIONS(506), 1,
+      anon_sym_LPAREN,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
+    STATE(133), 1,
+      sym_parenthesis_expression_list,
+    STATE(134), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(532), 4,
-      anon_sym_EQ,
+    ACTIONS(137), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(530), 16,
-      anon_sym_RBRACE,
-      anon_sym_PLUS,
-      anon_sym_DASH,
+    ACTIONS(135), 13,
+      anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
@@ -8092,11 +7897,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [5522] = 16,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [5246] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -8111,11 +7915,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -8126,112 +7930,68 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(537), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [5580] = 4,
+    ACTIONS(524), 4,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [5305] = 19,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(263), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
+    ACTIONS(117), 1,
       anon_sym_SLASH,
-    ACTIONS(261), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
       anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
       anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5613] = 4,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(526), 1,
+      anon_sym_RPAREN,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    STATE(70), 1,
+      sym__comma,
+    STATE(274), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(303), 5,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(301), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5646] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(23), 1,
-      anon_sym_reg,
-    STATE(146), 1,
-      aux_sym_write_modifiers_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(539), 6,
-      anon_sym_state,
-      anon_sym_gen,
-      anon_sym_input,
-      anon_sym_output,
-      sym_identifier,
-      sym_number,
-    ACTIONS(541), 13,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_COLON_COLON,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-  [5683] = 17,
+  [5370] = 19,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
     ACTIONS(117), 1,
       anon_sym_SLASH,
     ACTIONS(121), 1,
@@ -8244,14 +8004,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    STATE(46), 1,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(528), 1,
+      anon_sym_RPAREN,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
-    STATE(330), 1,
-      sym_block,
+    STATE(70), 1,
+      sym__comma,
+    STATE(242), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
@@ -8261,85 +8027,75 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [5742] = 4,
+  [5435] = 17,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(175), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(177), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
+    ACTIONS(498), 1,
       anon_sym_PIPE,
+    ACTIONS(500), 1,
       anon_sym_AMP,
-      anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
+    ACTIONS(502), 1,
+      anon_sym_SLASH,
+    ACTIONS(504), 1,
       anon_sym_DOT,
+    ACTIONS(506), 1,
       anon_sym_LPAREN,
+    ACTIONS(508), 1,
       anon_sym_LBRACK,
+    ACTIONS(510), 1,
+      anon_sym_CARET,
+    ACTIONS(530), 1,
       anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5775] = 4,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+    STATE(133), 1,
+      sym_parenthesis_expression_list,
+    STATE(134), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(167), 5,
+    ACTIONS(494), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(496), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(516), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(169), 16,
+    ACTIONS(512), 3,
       anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+    ACTIONS(514), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5808] = 4,
+  [5496] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(478), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(129), 1,
+      sym_template_args,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(191), 5,
+    ACTIONS(107), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(193), 16,
+    ACTIONS(109), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8356,20 +8112,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5841] = 4,
+  [5535] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(532), 1,
+      sym_identifier,
+    ACTIONS(538), 1,
+      anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(325), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
+    ACTIONS(536), 4,
+      anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(323), 16,
-      anon_sym_COLON,
+    ACTIONS(534), 16,
+      anon_sym_RBRACE,
+      anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_PIPE,
       anon_sym_AMP,
@@ -8381,52 +8142,63 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PERCENT,
       anon_sym_DOT,
       anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5874] = 4,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [5573] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(239), 5,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(237), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
+    ACTIONS(541), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_LF,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [5907] = 4,
+  [5631] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(243), 5,
+    ACTIONS(253), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(241), 16,
+    ACTIONS(251), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8443,19 +8215,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5940] = 4,
+  [5664] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(139), 5,
+    ACTIONS(311), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(141), 16,
+    ACTIONS(309), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8472,96 +8244,124 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [5973] = 4,
+  [5697] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(307), 5,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(305), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
+    ACTIONS(543), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [6006] = 4,
+  [5754] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(23), 1,
+      anon_sym_reg,
+    STATE(158), 1,
+      aux_sym_write_modifiers_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(223), 5,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(221), 16,
-      anon_sym_COLON,
+    ACTIONS(545), 6,
+      anon_sym_state,
+      anon_sym_gen,
+      anon_sym_input,
+      anon_sym_output,
+      sym_identifier,
+      sym_number,
+    ACTIONS(547), 13,
+      anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_STAR,
+      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [6039] = 4,
+      anon_sym_COLON_COLON,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+  [5791] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(227), 5,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(225), 16,
-      anon_sym_COLON,
-      anon_sym_STAR,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_CARET,
+    ACTIONS(549), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PERCENT,
-      anon_sym_DOT,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      anon_sym_PLUS_COLON,
-      anon_sym_DASH_COLON,
-  [6072] = 16,
+  [5848] = 17,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
     ACTIONS(117), 1,
       anon_sym_SLASH,
     ACTIONS(121), 1,
@@ -8574,12 +8374,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
+    STATE(332), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
@@ -8589,30 +8391,27 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(543), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [6129] = 4,
+  [5907] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(231), 5,
+    ACTIONS(203), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(229), 16,
+    ACTIONS(205), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8629,19 +8428,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6162] = 4,
+  [5940] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(235), 5,
+    ACTIONS(139), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(233), 16,
+    ACTIONS(141), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8658,7 +8457,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6195] = 4,
+  [5973] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -8687,19 +8486,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6228] = 4,
+  [6006] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(183), 5,
+    ACTIONS(243), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(185), 16,
+    ACTIONS(241), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8716,19 +8515,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6261] = 4,
+  [6039] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(187), 5,
+    ACTIONS(315), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(189), 16,
+    ACTIONS(313), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8745,19 +8544,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6294] = 4,
+  [6072] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(247), 5,
+    ACTIONS(319), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(245), 16,
+    ACTIONS(317), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8774,19 +8573,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6327] = 4,
+  [6105] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(251), 5,
+    ACTIONS(175), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(249), 16,
+    ACTIONS(177), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8803,19 +8602,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6360] = 4,
+  [6138] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(255), 5,
+    ACTIONS(199), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(253), 16,
+    ACTIONS(201), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8832,19 +8631,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6393] = 4,
+  [6171] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(259), 5,
+    ACTIONS(221), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(257), 16,
+    ACTIONS(219), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8861,50 +8660,48 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6426] = 6,
+  [6204] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(547), 1,
-      anon_sym_reg,
-    STATE(146), 1,
-      aux_sym_write_modifiers_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(545), 6,
-      anon_sym_state,
-      anon_sym_gen,
-      anon_sym_input,
-      anon_sym_output,
-      sym_identifier,
-      sym_number,
-    ACTIONS(550), 13,
+    ACTIONS(225), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(223), 16,
+      anon_sym_COLON,
       anon_sym_STAR,
-      anon_sym_BANG,
       anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
       anon_sym_LPAREN,
       anon_sym_LBRACK,
-      anon_sym_COLON_COLON,
-      sym_float,
-      sym_string,
-      sym_bool_array_literal,
-  [6463] = 4,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6237] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(267), 5,
+    ACTIONS(239), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(265), 16,
+    ACTIONS(237), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8921,19 +8718,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6496] = 4,
+  [6270] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(195), 5,
+    ACTIONS(213), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(197), 16,
+    ACTIONS(211), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8950,19 +8747,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6529] = 4,
+  [6303] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(199), 5,
+    ACTIONS(163), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(201), 16,
+    ACTIONS(165), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -8979,19 +8776,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6562] = 4,
+  [6336] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(203), 5,
+    ACTIONS(167), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(205), 16,
+    ACTIONS(169), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9008,19 +8805,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6595] = 4,
+  [6369] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(287), 5,
+    ACTIONS(171), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(285), 16,
+    ACTIONS(173), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9037,19 +8834,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6628] = 4,
+  [6402] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(291), 5,
+    ACTIONS(257), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(289), 16,
+    ACTIONS(255), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9066,19 +8863,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6661] = 4,
+  [6435] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(295), 5,
+    ACTIONS(271), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(293), 16,
+    ACTIONS(269), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9095,19 +8892,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6694] = 4,
+  [6468] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(299), 5,
+    ACTIONS(217), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(297), 16,
+    ACTIONS(215), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9124,19 +8921,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6727] = 4,
+  [6501] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(207), 5,
+    ACTIONS(275), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(209), 16,
+    ACTIONS(273), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9153,19 +8950,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6760] = 4,
+  [6534] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(163), 5,
+    ACTIONS(287), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(165), 16,
+    ACTIONS(285), 16,
       anon_sym_COLON,
       anon_sym_STAR,
       anon_sym_PIPE,
@@ -9182,146 +8979,290 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
       anon_sym_PLUS_COLON,
       anon_sym_DASH_COLON,
-  [6793] = 16,
+  [6567] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(119), 1,
-      anon_sym_DOT,
-    ACTIONS(121), 1,
-      anon_sym_LPAREN,
-    ACTIONS(123), 1,
-      anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(552), 1,
-      anon_sym_DOT_DOT,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
+    ACTIONS(291), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+    ACTIONS(289), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [6849] = 16,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
       anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(554), 1,
-      anon_sym_RPAREN,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6600] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
+    ACTIONS(183), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+    ACTIONS(185), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [6905] = 16,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(117), 1,
-      anon_sym_SLASH,
-    ACTIONS(121), 1,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
       anon_sym_LPAREN,
-    ACTIONS(123), 1,
       anon_sym_LBRACK,
-    ACTIONS(129), 1,
-      anon_sym_AMP,
-    ACTIONS(131), 1,
-      anon_sym_PIPE,
-    ACTIONS(133), 1,
-      anon_sym_CARET,
-    ACTIONS(219), 1,
-      anon_sym_DOT,
-    ACTIONS(556), 1,
       anon_sym_RBRACK,
-    STATE(46), 1,
-      sym_parenthesis_expression_list,
-    STATE(47), 1,
-      sym_array_access_bracket_expression,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6633] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(115), 2,
-      anon_sym_STAR,
-      anon_sym_PERCENT,
-    ACTIONS(125), 2,
+    ACTIONS(187), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+      anon_sym_SLASH,
+    ACTIONS(189), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [6961] = 4,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6666] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(558), 7,
-      anon_sym_reg,
-      anon_sym_state,
-      anon_sym_gen,
-      anon_sym_input,
-      anon_sym_output,
-      sym_identifier,
-      sym_number,
-    ACTIONS(560), 13,
+    ACTIONS(191), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_STAR,
-      anon_sym_BANG,
-      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(193), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6699] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(299), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(297), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6732] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(303), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(301), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6765] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(307), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(305), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6798] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(295), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(293), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6831] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(195), 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(197), 16,
+      anon_sym_COLON,
+      anon_sym_STAR,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PERCENT,
+      anon_sym_DOT,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_PLUS_COLON,
+      anon_sym_DASH_COLON,
+  [6864] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(553), 1,
+      anon_sym_reg,
+    STATE(158), 1,
+      aux_sym_write_modifiers_repeat1,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(551), 6,
+      anon_sym_state,
+      anon_sym_gen,
+      anon_sym_input,
+      anon_sym_output,
+      sym_identifier,
+      sym_number,
+    ACTIONS(556), 13,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
       anon_sym_AMP,
       anon_sym_CARET,
       anon_sym_LPAREN,
@@ -9330,7 +9271,87 @@ static const uint16_t ts_small_parse_table[] = {
       sym_float,
       sym_string,
       sym_bool_array_literal,
-  [6993] = 16,
+  [6901] = 16,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    ACTIONS(558), 1,
+      anon_sym_RBRACK,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(265), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [6957] = 16,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(119), 1,
+      anon_sym_DOT,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(560), 1,
+      anon_sym_DOT_DOT,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(265), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [7013] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -9345,13 +9366,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
     ACTIONS(562), 1,
       anon_sym_RBRACK,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9362,15 +9383,43 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [7049] = 16,
+  [7069] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(564), 7,
+      anon_sym_reg,
+      anon_sym_state,
+      anon_sym_gen,
+      anon_sym_input,
+      anon_sym_output,
+      sym_identifier,
+      sym_number,
+    ACTIONS(566), 13,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_BANG,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_CARET,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COLON_COLON,
+      sym_float,
+      sym_string,
+      sym_bool_array_literal,
+  [7101] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -9385,13 +9434,53 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(564), 1,
+    ACTIONS(568), 1,
+      anon_sym_RPAREN,
+    STATE(62), 1,
+      sym_parenthesis_expression_list,
+    STATE(63), 1,
+      sym_array_access_bracket_expression,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(115), 2,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+    ACTIONS(125), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(265), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(263), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [7157] = 16,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(117), 1,
+      anon_sym_SLASH,
+    ACTIONS(121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(123), 1,
+      anon_sym_LBRACK,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_PIPE,
+    ACTIONS(133), 1,
+      anon_sym_CARET,
+    ACTIONS(267), 1,
+      anon_sym_DOT,
+    ACTIONS(570), 1,
       anon_sym_RBRACK,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9402,15 +9491,15 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [7105] = 16,
+  [7213] = 16,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(117), 1,
@@ -9425,13 +9514,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PIPE,
     ACTIONS(133), 1,
       anon_sym_CARET,
-    ACTIONS(219), 1,
+    ACTIONS(267), 1,
       anon_sym_DOT,
-    ACTIONS(566), 1,
+    ACTIONS(572), 1,
       anon_sym_RPAREN,
-    STATE(46), 1,
+    STATE(62), 1,
       sym_parenthesis_expression_list,
-    STATE(47), 1,
+    STATE(63), 1,
       sym_array_access_bracket_expression,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9442,28 +9531,28 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(125), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(217), 2,
+    ACTIONS(265), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(215), 4,
+    ACTIONS(263), 4,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [7161] = 4,
+  [7269] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(568), 6,
+    ACTIONS(574), 6,
       anon_sym_state,
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
       sym_identifier,
       sym_number,
-    ACTIONS(570), 13,
+    ACTIONS(576), 13,
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
@@ -9477,35 +9566,35 @@ static const uint16_t ts_small_parse_table[] = {
       sym_float,
       sym_string,
       sym_bool_array_literal,
-  [7192] = 15,
+  [7300] = 15,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(572), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(574), 1,
+    ACTIONS(51), 1,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(166), 1,
+    ACTIONS(578), 1,
+      anon_sym_DASH_GT,
+    STATE(12), 1,
       aux_sym__linebreak,
-    STATE(188), 1,
+    STATE(19), 1,
+      sym_namespace_list,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
     STATE(194), 1,
       sym_declaration,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(227), 1,
+    STATE(224), 1,
       sym_declaration_list,
-    STATE(276), 1,
+    STATE(296), 1,
       sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9514,35 +9603,35 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7244] = 15,
+  [7352] = 15,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(55), 1,
-      anon_sym_LF,
-    ACTIONS(572), 1,
+    ACTIONS(578), 1,
       anon_sym_DASH_GT,
-    STATE(12), 1,
-      aux_sym__linebreak,
-    STATE(17), 1,
+    ACTIONS(580), 1,
+      anon_sym_LF,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(167), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
     STATE(194), 1,
       sym_declaration,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(222), 1,
+    STATE(227), 1,
       sym_declaration_list,
-    STATE(285), 1,
+    STATE(293), 1,
       sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9551,32 +9640,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7296] = 13,
+  [7404] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(576), 1,
+    ACTIONS(582), 1,
       sym_identifier,
-    ACTIONS(578), 1,
+    ACTIONS(584), 1,
       anon_sym_RPAREN,
-    ACTIONS(580), 1,
+    ACTIONS(586), 1,
       anon_sym_LF,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(168), 1,
+    STATE(170), 1,
       aux_sym__linebreak,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(199), 2,
+    STATE(215), 2,
       sym_template_declaration_type,
       sym_declaration,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9585,32 +9674,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7343] = 13,
+  [7451] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(55), 1,
+    ACTIONS(51), 1,
       anon_sym_LF,
-    ACTIONS(576), 1,
-      sym_identifier,
     ACTIONS(582), 1,
+      sym_identifier,
+    ACTIONS(588), 1,
       anon_sym_RPAREN,
     STATE(12), 1,
       aux_sym__linebreak,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(204), 2,
+    STATE(209), 2,
       sym_template_declaration_type,
       sym_declaration,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9619,31 +9708,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7390] = 13,
+  [7498] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(55), 1,
+    ACTIONS(590), 1,
       anon_sym_LF,
-    STATE(12), 1,
-      aux_sym__linebreak,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(172), 1,
+      aux_sym__linebreak,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
     STATE(194), 1,
       sym_declaration,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(295), 1,
+    STATE(292), 1,
       sym_declaration_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9652,31 +9741,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7436] = 13,
+  [7544] = 13,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(584), 1,
+    ACTIONS(51), 1,
       anon_sym_LF,
-    STATE(17), 1,
-      sym_namespace_list,
-    STATE(169), 1,
+    STATE(12), 1,
       aux_sym__linebreak,
-    STATE(188), 1,
+    STATE(19), 1,
+      sym_namespace_list,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
     STATE(194), 1,
       sym_declaration,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(283), 1,
+    STATE(300), 1,
       sym_declaration_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9685,26 +9774,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7482] = 10,
+  [7590] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(576), 1,
+    ACTIONS(582), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(299), 2,
+    STATE(278), 2,
       sym_template_declaration_type,
       sym_declaration,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9713,25 +9802,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7520] = 10,
+  [7628] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(336), 1,
+    STATE(231), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9740,25 +9829,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7557] = 10,
+  [7665] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(17), 1,
       sym_identifier,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
-    STATE(188), 1,
+    STATE(192), 1,
       aux_sym_declaration_modifiers_repeat1,
-    STATE(202), 1,
+    STATE(208), 1,
       sym_declaration_modifiers,
-    STATE(225), 1,
+    STATE(340), 1,
       sym_declaration,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(275), 3,
+    STATE(277), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
@@ -9767,20 +9856,39 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [7594] = 10,
+  [7702] = 5,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(592), 1,
+      anon_sym_LF,
+    STATE(176), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(71), 8,
+      ts_builtin_sym_end,
+      anon_sym___builtin__,
+      anon_sym_extern,
+      anon_sym_module,
+      anon_sym_struct,
+      anon_sym_const,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+  [7726] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(13), 1,
       anon_sym_const,
-    ACTIONS(586), 1,
+    ACTIONS(595), 1,
       ts_builtin_sym_end,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
-    STATE(324), 1,
+    STATE(334), 1,
       sym_global_object,
-    STATE(337), 1,
+    STATE(343), 1,
       sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9791,20 +9899,20 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(11), 2,
       anon_sym_module,
       anon_sym_struct,
-  [7628] = 10,
+  [7760] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(13), 1,
       anon_sym_const,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    ACTIONS(590), 1,
+    ACTIONS(599), 1,
       ts_builtin_sym_end,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
-    STATE(324), 1,
+    STATE(334), 1,
       sym_global_object,
-    STATE(337), 1,
+    STATE(343), 1,
       sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9815,20 +9923,39 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(11), 2,
       anon_sym_module,
       anon_sym_struct,
-  [7662] = 10,
+  [7794] = 5,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(603), 1,
+      anon_sym_SQUOTE,
+    STATE(187), 1,
+      sym_latency_specifier,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(601), 8,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [7818] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(13), 1,
       anon_sym_const,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    ACTIONS(592), 1,
+    ACTIONS(605), 1,
       ts_builtin_sym_end,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
-    STATE(324), 1,
+    STATE(273), 1,
       sym_global_object,
-    STATE(337), 1,
+    STATE(343), 1,
       sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9839,36 +9966,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(11), 2,
       anon_sym_module,
       anon_sym_struct,
-  [7696] = 5,
+  [7852] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(594), 1,
-      anon_sym_LF,
-    STATE(177), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(71), 8,
-      ts_builtin_sym_end,
-      anon_sym___builtin__,
-      anon_sym_extern,
-      anon_sym_module,
-      anon_sym_struct,
-      anon_sym_const,
-      anon_sym_RPAREN,
-      anon_sym_RBRACK,
-  [7720] = 5,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(599), 1,
+    ACTIONS(603), 1,
       anon_sym_SQUOTE,
-    STATE(186), 1,
+    STATE(189), 1,
       sym_latency_specifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(597), 8,
+    ACTIONS(607), 8,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -9877,39 +9985,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH_GT,
       anon_sym_COMMA,
       anon_sym_LF,
-  [7744] = 5,
+  [7876] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(599), 1,
-      anon_sym_SQUOTE,
-    STATE(185), 1,
-      sym_latency_specifier,
+    ACTIONS(13), 1,
+      anon_sym_const,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(609), 1,
+      ts_builtin_sym_end,
+    STATE(176), 1,
+      aux_sym__linebreak,
+    STATE(334), 1,
+      sym_global_object,
+    STATE(343), 1,
+      sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(601), 8,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [7768] = 10,
+    ACTIONS(9), 2,
+      anon_sym___builtin__,
+      anon_sym_extern,
+    ACTIONS(11), 2,
+      anon_sym_module,
+      anon_sym_struct,
+  [7910] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(13), 1,
       anon_sym_const,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    ACTIONS(603), 1,
+    ACTIONS(611), 1,
       ts_builtin_sym_end,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
-    STATE(324), 1,
+    STATE(334), 1,
       sym_global_object,
-    STATE(337), 1,
+    STATE(343), 1,
       sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9920,20 +10033,18 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(11), 2,
       anon_sym_module,
       anon_sym_struct,
-  [7802] = 10,
+  [7944] = 9,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(13), 1,
       anon_sym_const,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    ACTIONS(605), 1,
-      ts_builtin_sym_end,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
-    STATE(255), 1,
+    STATE(334), 1,
       sym_global_object,
-    STATE(337), 1,
+    STATE(343), 1,
       sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -9944,96 +10055,59 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(11), 2,
       anon_sym_module,
       anon_sym_struct,
-  [7836] = 10,
+  [7975] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    ACTIONS(469), 1,
+    ACTIONS(471), 1,
       anon_sym_COLON,
-    ACTIONS(599), 1,
+    ACTIONS(603), 1,
       anon_sym_SQUOTE,
-    STATE(192), 1,
+    STATE(195), 1,
       sym_latency_specifier,
     STATE(226), 1,
       sym_interface_ports,
-    STATE(247), 1,
+    STATE(245), 1,
       sym_block,
-    STATE(322), 1,
+    STATE(330), 1,
       sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(607), 2,
+    ACTIONS(613), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [7869] = 10,
+  [8008] = 10,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    ACTIONS(469), 1,
+    ACTIONS(471), 1,
       anon_sym_COLON,
-    ACTIONS(599), 1,
+    ACTIONS(603), 1,
       anon_sym_SQUOTE,
-    STATE(193), 1,
+    STATE(196), 1,
       sym_latency_specifier,
-    STATE(235), 1,
+    STATE(229), 1,
       sym_interface_ports,
-    STATE(247), 1,
+    STATE(245), 1,
       sym_block,
-    STATE(316), 1,
-      sym__then_else_block,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(609), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [7902] = 9,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(13), 1,
-      anon_sym_const,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    STATE(177), 1,
-      aux_sym__linebreak,
     STATE(324), 1,
-      sym_global_object,
-    STATE(337), 1,
-      sym_const_and_type,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(9), 2,
-      anon_sym___builtin__,
-      anon_sym_extern,
-    ACTIONS(11), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [7933] = 3,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+      sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(611), 8,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
+    ACTIONS(615), 2,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_in,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [7951] = 3,
+  [8041] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(613), 8,
+    ACTIONS(617), 8,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
@@ -10042,373 +10116,336 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH_GT,
       anon_sym_COMMA,
       anon_sym_LF,
-  [7969] = 6,
+  [8059] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(84), 1,
       anon_sym_COLON_COLON,
-    STATE(16), 1,
+    STATE(14), 1,
       aux_sym_namespace_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(82), 3,
+    ACTIONS(95), 3,
       anon_sym_POUND_LPAREN,
       anon_sym_LBRACK,
       sym_identifier,
-    ACTIONS(615), 3,
+    ACTIONS(619), 3,
       anon_sym_RPAREN,
       anon_sym_COMMA,
       anon_sym_LF,
-  [7993] = 6,
+  [8083] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(617), 1,
-      sym_identifier,
-    ACTIONS(619), 1,
-      anon_sym_COLON_COLON,
-    STATE(191), 1,
-      aux_sym_declaration_modifiers_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(37), 4,
-      anon_sym_state,
-      anon_sym_gen,
-      anon_sym_input,
-      anon_sym_output,
-  [8016] = 6,
+    ACTIONS(621), 8,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_in,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [8101] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(623), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    STATE(173), 1,
+    STATE(174), 1,
       sym__comma,
-    STATE(189), 1,
+    STATE(191), 1,
       aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(621), 4,
+    ACTIONS(623), 4,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DASH_GT,
       anon_sym_LF,
-  [8039] = 6,
+  [8124] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(627), 1,
       anon_sym_COMMA,
-    STATE(173), 1,
+    STATE(174), 1,
       sym__comma,
-    STATE(189), 1,
+    STATE(191), 1,
       aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(626), 4,
+    ACTIONS(625), 4,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_DASH_GT,
       anon_sym_LF,
-  [8062] = 6,
+  [8147] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(628), 1,
+    ACTIONS(630), 1,
       sym_identifier,
-    ACTIONS(633), 1,
+    ACTIONS(632), 1,
       anon_sym_COLON_COLON,
-    STATE(191), 1,
+    STATE(193), 1,
       aux_sym_declaration_modifiers_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(630), 4,
+    ACTIONS(37), 4,
       anon_sym_state,
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-  [8085] = 8,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    ACTIONS(469), 1,
-      anon_sym_COLON,
-    STATE(220), 1,
-      sym_interface_ports,
-    STATE(247), 1,
-      sym_block,
-    STATE(318), 1,
-      sym__then_else_block,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(635), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [8112] = 8,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    ACTIONS(469), 1,
-      anon_sym_COLON,
-    STATE(223), 1,
-      sym_interface_ports,
-    STATE(247), 1,
-      sym_block,
-    STATE(309), 1,
-      sym__then_else_block,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(637), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [8139] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    STATE(173), 1,
-      sym__comma,
-    STATE(190), 1,
-      aux_sym_declaration_list_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(639), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_DASH_GT,
-      anon_sym_LF,
-  [8162] = 4,
+  [8170] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(643), 1,
+    ACTIONS(634), 1,
+      sym_identifier,
+    ACTIONS(639), 1,
       anon_sym_COLON_COLON,
+    STATE(193), 1,
+      aux_sym_declaration_modifiers_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(641), 5,
+    ACTIONS(636), 4,
       anon_sym_state,
       anon_sym_gen,
       anon_sym_input,
       anon_sym_output,
-      sym_identifier,
-  [8180] = 8,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(645), 1,
-      anon_sym_RPAREN,
-    ACTIONS(647), 1,
-      anon_sym_LF,
-    STATE(210), 1,
-      aux_sym_template_args_repeat1,
-    STATE(293), 1,
-      aux_sym__linebreak,
-    STATE(312), 1,
-      sym__comma,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [8206] = 6,
+  [8193] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(651), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    STATE(70), 1,
+    STATE(174), 1,
       sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(190), 1,
+      aux_sym_declaration_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(649), 3,
-      anon_sym_RPAREN,
-      anon_sym_RBRACK,
+    ACTIONS(641), 4,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DASH_GT,
       anon_sym_LF,
-  [8228] = 8,
+  [8216] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(654), 1,
-      anon_sym_RBRACK,
-    ACTIONS(656), 1,
-      anon_sym_LF,
-    STATE(70), 1,
-      sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
-    STATE(291), 1,
-      aux_sym__linebreak,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    ACTIONS(471), 1,
+      anon_sym_COLON,
+    STATE(230), 1,
+      sym_interface_ports,
+    STATE(245), 1,
+      sym_block,
+    STATE(333), 1,
+      sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8254] = 8,
+    ACTIONS(643), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [8243] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(658), 1,
-      anon_sym_RPAREN,
-    ACTIONS(660), 1,
-      anon_sym_LF,
-    STATE(171), 1,
-      sym__comma,
-    STATE(203), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(294), 1,
-      aux_sym__linebreak,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    ACTIONS(471), 1,
+      anon_sym_COLON,
+    STATE(225), 1,
+      sym_interface_ports,
+    STATE(245), 1,
+      sym_block,
+    STATE(317), 1,
+      sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8280] = 8,
+    ACTIONS(645), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [8270] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(662), 1,
+    ACTIONS(647), 1,
       anon_sym_RPAREN,
-    ACTIONS(664), 1,
+    ACTIONS(649), 1,
       anon_sym_LF,
-    STATE(212), 1,
+    STATE(221), 1,
       aux_sym_template_args_repeat1,
-    STATE(292), 1,
+    STATE(291), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
+    STATE(331), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8306] = 8,
+  [8296] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(666), 1,
+    ACTIONS(651), 1,
       anon_sym_RPAREN,
-    ACTIONS(668), 1,
+    ACTIONS(653), 1,
       anon_sym_LF,
-    STATE(171), 1,
+    STATE(173), 1,
       sym__comma,
-    STATE(224), 1,
+    STATE(223), 1,
       aux_sym_template_declaration_arguments_repeat1,
-    STATE(286), 1,
+    STATE(295), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8332] = 6,
+  [8322] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(302), 3,
+    STATE(238), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [8354] = 8,
+  [8344] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(670), 1,
+    ACTIONS(655), 1,
       anon_sym_RPAREN,
-    ACTIONS(672), 1,
+    ACTIONS(657), 1,
       anon_sym_LF,
-    STATE(171), 1,
-      sym__comma,
-    STATE(224), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(296), 1,
+    STATE(197), 1,
+      aux_sym_template_args_repeat1,
+    STATE(284), 1,
       aux_sym__linebreak,
+    STATE(331), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8380] = 8,
+  [8370] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(674), 1,
+    ACTIONS(659), 1,
       anon_sym_RPAREN,
-    ACTIONS(676), 1,
+    ACTIONS(661), 1,
       anon_sym_LF,
-    STATE(171), 1,
-      sym__comma,
-    STATE(201), 1,
-      aux_sym_template_declaration_arguments_repeat1,
-    STATE(280), 1,
+    STATE(221), 1,
+      aux_sym_template_args_repeat1,
+    STATE(282), 1,
       aux_sym__linebreak,
+    STATE(331), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8406] = 8,
+  [8396] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(678), 1,
-      anon_sym_RBRACK,
-    ACTIONS(680), 1,
-      anon_sym_LF,
-    STATE(70), 1,
-      sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
-    STATE(277), 1,
-      aux_sym__linebreak,
+    ACTIONS(665), 1,
+      anon_sym_COLON_COLON,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8432] = 6,
+    ACTIONS(663), 5,
+      anon_sym_state,
+      anon_sym_gen,
+      anon_sym_input,
+      anon_sym_output,
+      sym_identifier,
+  [8414] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(288), 3,
+    STATE(283), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [8454] = 6,
+  [8436] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(684), 1,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(667), 1,
+      anon_sym_RBRACK,
+    ACTIONS(669), 1,
+      anon_sym_LF,
+    STATE(70), 1,
+      sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(308), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [8462] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(673), 1,
       anon_sym_COMMA,
     STATE(11), 1,
       sym__comma,
-    STATE(207), 1,
+    STATE(205), 1,
       aux_sym_assign_left_side_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(682), 3,
+    ACTIONS(671), 3,
       anon_sym_RBRACE,
       anon_sym_EQ,
       anon_sym_LF,
-  [8476] = 6,
+  [8484] = 8,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(676), 1,
+      anon_sym_RPAREN,
+    ACTIONS(678), 1,
+      anon_sym_LF,
+    STATE(173), 1,
+      sym__comma,
+    STATE(223), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(289), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [8510] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
     STATE(11), 1,
       sym__comma,
@@ -10417,490 +10454,517 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(687), 3,
+    ACTIONS(680), 3,
       anon_sym_RBRACE,
       anon_sym_EQ,
       anon_sym_LF,
-  [8498] = 6,
+  [8532] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(45), 1,
       anon_sym_COLON_COLON,
-    ACTIONS(269), 1,
+    ACTIONS(227), 1,
       sym_identifier,
-    STATE(17), 1,
+    STATE(19), 1,
       sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    STATE(236), 3,
+    STATE(288), 3,
       sym__type,
       sym_array_type,
       sym_template_global,
-  [8520] = 8,
+  [8554] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(689), 1,
+    ACTIONS(682), 1,
       anon_sym_RPAREN,
-    ACTIONS(691), 1,
+    ACTIONS(684), 1,
       anon_sym_LF,
-    STATE(232), 1,
-      aux_sym_template_args_repeat1,
-    STATE(282), 1,
-      aux_sym__linebreak,
-    STATE(312), 1,
+    STATE(173), 1,
       sym__comma,
+    STATE(206), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(309), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8546] = 8,
+  [8580] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(693), 1,
+    ACTIONS(686), 1,
       anon_sym_RPAREN,
-    ACTIONS(695), 1,
+    ACTIONS(688), 1,
       anon_sym_LF,
-    STATE(214), 1,
+    STATE(201), 1,
       aux_sym_template_args_repeat1,
-    STATE(274), 1,
+    STATE(279), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
+    STATE(331), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8572] = 8,
+  [8606] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(697), 1,
-      anon_sym_RPAREN,
-    ACTIONS(699), 1,
+    ACTIONS(690), 1,
+      anon_sym_RBRACK,
+    ACTIONS(692), 1,
       anon_sym_LF,
-    STATE(232), 1,
-      aux_sym_template_args_repeat1,
-    STATE(307), 1,
+    STATE(70), 1,
+      sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
+    STATE(310), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [8632] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(696), 1,
+      anon_sym_COMMA,
+    STATE(70), 1,
       sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8598] = 6,
+    ACTIONS(694), 3,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_LF,
+  [8654] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
     STATE(11), 1,
       sym__comma,
-    STATE(207), 1,
+    STATE(205), 1,
       aux_sym_assign_left_side_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(701), 3,
+    ACTIONS(699), 3,
       anon_sym_RBRACE,
       anon_sym_EQ,
       anon_sym_LF,
-  [8620] = 8,
+  [8676] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(703), 1,
+    ACTIONS(701), 1,
       anon_sym_RPAREN,
-    ACTIONS(705), 1,
+    ACTIONS(703), 1,
       anon_sym_LF,
-    STATE(232), 1,
+    STATE(216), 1,
       aux_sym_template_args_repeat1,
-    STATE(300), 1,
+    STATE(301), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
+    STATE(331), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8646] = 8,
+  [8702] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(707), 1,
+    ACTIONS(705), 1,
       anon_sym_RPAREN,
+    ACTIONS(707), 1,
+      anon_sym_LF,
+    STATE(173), 1,
+      sym__comma,
+    STATE(198), 1,
+      aux_sym_template_declaration_arguments_repeat1,
+    STATE(286), 1,
+      aux_sym__linebreak,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [8728] = 8,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
     ACTIONS(709), 1,
+      anon_sym_RPAREN,
+    ACTIONS(711), 1,
       anon_sym_LF,
-    STATE(217), 1,
+    STATE(221), 1,
       aux_sym_template_args_repeat1,
-    STATE(301), 1,
+    STATE(304), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
+    STATE(331), 1,
       sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8672] = 8,
+  [8754] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(711), 1,
-      anon_sym_RBRACK,
     ACTIONS(713), 1,
+      anon_sym_RPAREN,
+    ACTIONS(715), 1,
       anon_sym_LF,
-    STATE(70), 1,
-      sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
-    STATE(303), 1,
+    STATE(220), 1,
+      aux_sym_template_args_repeat1,
+    STATE(276), 1,
       aux_sym__linebreak,
+    STATE(331), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8698] = 8,
+  [8780] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(715), 1,
-      anon_sym_RPAREN,
     ACTIONS(717), 1,
+      anon_sym_RBRACK,
+    ACTIONS(719), 1,
       anon_sym_LF,
-    STATE(232), 1,
-      aux_sym_template_args_repeat1,
+    STATE(70), 1,
+      sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     STATE(305), 1,
       aux_sym__linebreak,
-    STATE(312), 1,
-      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8724] = 8,
+  [8806] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
+    ACTIONS(379), 1,
       anon_sym_COMMA,
-    ACTIONS(719), 1,
-      anon_sym_RBRACK,
     ACTIONS(721), 1,
+      anon_sym_RBRACK,
+    ACTIONS(723), 1,
       anon_sym_LF,
     STATE(70), 1,
       sym__comma,
-    STATE(197), 1,
+    STATE(212), 1,
       aux_sym_parenthesis_expression_list_repeat1,
-    STATE(306), 1,
+    STATE(299), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8750] = 5,
+  [8832] = 8,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(725), 1,
+      anon_sym_RPAREN,
+    ACTIONS(727), 1,
+      anon_sym_LF,
+    STATE(221), 1,
+      aux_sym_template_args_repeat1,
+    STATE(307), 1,
+      aux_sym__linebreak,
+    STATE(331), 1,
+      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(723), 2,
-      anon_sym_when,
-      anon_sym_if,
-    STATE(327), 2,
-      sym_block,
-      sym_if_statement,
-  [8769] = 6,
+  [8858] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(731), 1,
+      anon_sym_COMMA,
+    STATE(221), 1,
+      aux_sym_template_args_repeat1,
+    STATE(331), 1,
+      sym__comma,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(729), 2,
+      anon_sym_RPAREN,
+      anon_sym_LF,
+  [8879] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    STATE(247), 1,
-      sym_block,
-    STATE(310), 1,
-      sym__then_else_block,
+    ACTIONS(734), 1,
+      sym_identifier,
+    ACTIONS(736), 1,
+      anon_sym_RPAREN,
+    ACTIONS(738), 1,
+      anon_sym_LF,
+    STATE(210), 1,
+      sym_template_arg,
+    STATE(232), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(725), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [8790] = 3,
+  [8902] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(742), 1,
+      anon_sym_COMMA,
+    STATE(173), 1,
+      sym__comma,
+    STATE(223), 1,
+      aux_sym_template_declaration_arguments_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(727), 5,
+    ACTIONS(740), 2,
       anon_sym_RPAREN,
-      anon_sym_LBRACK,
-      sym_identifier,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [8805] = 5,
+  [8923] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(572), 1,
+    ACTIONS(578), 1,
       anon_sym_DASH_GT,
-    STATE(297), 1,
+    STATE(303), 1,
       sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(729), 3,
+    ACTIONS(745), 3,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [8824] = 6,
+  [8942] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    STATE(247), 1,
+    STATE(245), 1,
       sym_block,
-    STATE(325), 1,
+    STATE(329), 1,
       sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(731), 2,
+    ACTIONS(747), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [8845] = 6,
+  [8963] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(735), 1,
-      anon_sym_COMMA,
-    STATE(171), 1,
-      sym__comma,
-    STATE(224), 1,
-      aux_sym_template_declaration_arguments_repeat1,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    STATE(245), 1,
+      sym_block,
+    STATE(328), 1,
+      sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(733), 2,
-      anon_sym_RPAREN,
+    ACTIONS(749), 2,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [8866] = 3,
+  [8984] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(578), 1,
+      anon_sym_DASH_GT,
+    STATE(294), 1,
+      sym__interface_ports_output,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(738), 5,
+    ACTIONS(751), 3,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_DASH_GT,
-      anon_sym_COMMA,
       anon_sym_LF,
-  [8881] = 6,
+  [9003] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    STATE(247), 1,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(753), 2,
+      anon_sym_when,
+      anon_sym_if,
+    STATE(315), 2,
       sym_block,
-    STATE(313), 1,
+      sym_if_statement,
+  [9022] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    STATE(245), 1,
+      sym_block,
+    STATE(316), 1,
       sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(740), 2,
+    ACTIONS(755), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [8902] = 5,
+  [9043] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(572), 1,
-      anon_sym_DASH_GT,
-    STATE(284), 1,
-      sym__interface_ports_output,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    STATE(245), 1,
+      sym_block,
+    STATE(319), 1,
+      sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(742), 3,
-      anon_sym_LBRACE,
+    ACTIONS(757), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [8921] = 7,
+  [9064] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(744), 1,
-      anon_sym_RBRACE,
-    ACTIONS(746), 1,
-      anon_sym_EQ,
-    ACTIONS(748), 1,
-      anon_sym_LF,
-    STATE(8), 1,
-      aux_sym__linebreak,
-    STATE(273), 1,
-      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8944] = 7,
+    ACTIONS(759), 5,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DASH_GT,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [9079] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(750), 1,
+    ACTIONS(51), 1,
+      anon_sym_LF,
+    ACTIONS(734), 1,
       sym_identifier,
-    ACTIONS(752), 1,
+    ACTIONS(761), 1,
       anon_sym_RPAREN,
-    ACTIONS(754), 1,
-      anon_sym_LF,
+    STATE(12), 1,
+      aux_sym__linebreak,
     STATE(200), 1,
       sym_template_arg,
-    STATE(237), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [8967] = 3,
+  [9102] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(756), 5,
-      anon_sym_RPAREN,
-      anon_sym_LBRACK,
+    ACTIONS(734), 1,
       sym_identifier,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [8982] = 7,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(746), 1,
-      anon_sym_EQ,
-    ACTIONS(758), 1,
-      anon_sym_RBRACE,
-    ACTIONS(760), 1,
+    ACTIONS(763), 1,
+      anon_sym_RPAREN,
+    ACTIONS(765), 1,
       anon_sym_LF,
-    STATE(4), 1,
+    STATE(214), 1,
+      sym_template_arg,
+    STATE(235), 1,
       aux_sym__linebreak,
-    STATE(260), 1,
-      aux_sym_block_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [9005] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(764), 1,
-      anon_sym_COMMA,
-    STATE(232), 1,
-      aux_sym_template_args_repeat1,
-    STATE(312), 1,
-      sym__comma,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(762), 2,
-      anon_sym_RPAREN,
-      anon_sym_LF,
-  [9026] = 7,
+  [9125] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(750), 1,
-      sym_identifier,
     ACTIONS(767), 1,
-      anon_sym_RPAREN,
+      anon_sym_RBRACE,
     ACTIONS(769), 1,
+      anon_sym_EQ,
+    ACTIONS(771), 1,
       anon_sym_LF,
-    STATE(211), 1,
-      sym_template_arg,
-    STATE(234), 1,
+    STATE(4), 1,
       aux_sym__linebreak,
+    STATE(253), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9049] = 7,
+  [9148] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(55), 1,
+    ACTIONS(51), 1,
       anon_sym_LF,
-    ACTIONS(750), 1,
+    ACTIONS(734), 1,
       sym_identifier,
-    ACTIONS(771), 1,
+    ACTIONS(773), 1,
       anon_sym_RPAREN,
     STATE(12), 1,
       aux_sym__linebreak,
-    STATE(215), 1,
+    STATE(217), 1,
       sym_template_arg,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9072] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    STATE(247), 1,
-      sym_block,
-    STATE(332), 1,
-      sym__then_else_block,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(773), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [9093] = 5,
+  [9171] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(777), 1,
-      anon_sym_LBRACK,
-    STATE(221), 1,
-      sym_array_type_bracket,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(775), 3,
+    ACTIONS(775), 5,
       anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      sym_identifier,
       anon_sym_COMMA,
       anon_sym_LF,
-  [9112] = 7,
+  [9186] = 7,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(55), 1,
-      anon_sym_LF,
-    ACTIONS(750), 1,
-      sym_identifier,
+    ACTIONS(769), 1,
+      anon_sym_EQ,
+    ACTIONS(777), 1,
+      anon_sym_RBRACE,
     ACTIONS(779), 1,
-      anon_sym_RPAREN,
-    STATE(12), 1,
+      anon_sym_LF,
+    STATE(6), 1,
       aux_sym__linebreak,
-    STATE(196), 1,
-      sym_template_arg,
+    STATE(255), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9135] = 6,
+  [9209] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(781), 1,
-      anon_sym_RBRACE,
     ACTIONS(783), 1,
-      anon_sym_LF,
-    STATE(7), 1,
-      aux_sym__linebreak,
-    STATE(241), 1,
-      aux_sym_block_repeat1,
+      anon_sym_LBRACK,
+    STATE(236), 1,
+      sym_array_type_bracket,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9155] = 3,
+    ACTIONS(781), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [9228] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(785), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
+    ACTIONS(785), 5,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      sym_identifier,
+      anon_sym_COMMA,
       anon_sym_LF,
-  [9169] = 3,
+  [9243] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -10911,659 +10975,651 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9183] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(789), 1,
-      anon_sym_RBRACE,
-    ACTIONS(791), 1,
-      anon_sym_LF,
-    STATE(10), 1,
-      aux_sym__linebreak,
-    STATE(241), 1,
-      aux_sym_block_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [9203] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(794), 1,
-      ts_builtin_sym_end,
-    ACTIONS(796), 1,
-      anon_sym_LF,
-    STATE(184), 1,
-      aux_sym__linebreak,
-    STATE(242), 1,
-      aux_sym_source_file_repeat1,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [9223] = 3,
+  [9257] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(799), 4,
+    ACTIONS(789), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9237] = 6,
+  [9271] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    ACTIONS(801), 1,
-      anon_sym_POUND_LPAREN,
-    STATE(308), 1,
-      sym_block,
-    STATE(319), 1,
-      sym_template_declaration_arguments,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(791), 1,
+      anon_sym_RPAREN,
+    STATE(70), 1,
+      sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9257] = 3,
+  [9291] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(795), 1,
+      anon_sym_COLON,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(803), 4,
-      anon_sym_RBRACE,
-      anon_sym_EQ,
+    ACTIONS(793), 3,
+      anon_sym_RPAREN,
       anon_sym_COMMA,
       anon_sym_LF,
-  [9271] = 6,
+  [9307] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(805), 1,
-      ts_builtin_sym_end,
-    ACTIONS(807), 1,
-      anon_sym_LF,
-    STATE(176), 1,
-      aux_sym__linebreak,
-    STATE(242), 1,
-      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9291] = 5,
+    ACTIONS(486), 4,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [9321] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(811), 1,
+    ACTIONS(799), 1,
       anon_sym_else,
-    STATE(315), 1,
+    STATE(318), 1,
       sym_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(809), 2,
+    ACTIONS(797), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [9309] = 5,
+  [9339] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(13), 1,
-      anon_sym_const,
-    STATE(354), 1,
-      sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(813), 2,
-      anon_sym_module,
-      anon_sym_struct,
-  [9327] = 6,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(758), 1,
+    ACTIONS(801), 4,
       anon_sym_RBRACE,
-    ACTIONS(760), 1,
+      anon_sym_EQ,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(4), 1,
-      aux_sym__linebreak,
-    STATE(268), 1,
-      aux_sym_block_repeat1,
+  [9353] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9347] = 6,
+    ACTIONS(803), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
+      anon_sym_LF,
+  [9367] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(815), 1,
+    ACTIONS(805), 1,
       ts_builtin_sym_end,
-    ACTIONS(817), 1,
+    ACTIONS(807), 1,
       anon_sym_LF,
-    STATE(175), 1,
+    STATE(184), 1,
       aux_sym__linebreak,
-    STATE(242), 1,
+    STATE(248), 1,
       aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9367] = 3,
+  [9387] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    ACTIONS(810), 1,
+      anon_sym_POUND_LPAREN,
+    STATE(312), 1,
+      sym_template_declaration_arguments,
+    STATE(320), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(819), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [9381] = 3,
+  [9407] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(785), 4,
+    ACTIONS(803), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9395] = 3,
+  [9421] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(821), 4,
+    ACTIONS(812), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9409] = 3,
+  [9435] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(799), 4,
+    ACTIONS(787), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9423] = 6,
+  [9449] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(823), 1,
-      ts_builtin_sym_end,
-    ACTIONS(825), 1,
+    ACTIONS(814), 1,
+      anon_sym_RBRACE,
+    ACTIONS(816), 1,
       anon_sym_LF,
-    STATE(174), 1,
+    STATE(5), 1,
       aux_sym__linebreak,
-    STATE(250), 1,
-      aux_sym_source_file_repeat1,
+    STATE(271), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9443] = 3,
+  [9469] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(518), 4,
+    ACTIONS(818), 1,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_COMMA,
+    ACTIONS(820), 1,
       anon_sym_LF,
-  [9457] = 3,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+    STATE(8), 1,
+      aux_sym__linebreak,
+    STATE(271), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(787), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [9471] = 6,
+  [9489] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(827), 1,
-      anon_sym_RPAREN,
-    STATE(70), 1,
-      sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+    ACTIONS(822), 1,
+      anon_sym_RBRACE,
+    ACTIONS(824), 1,
+      anon_sym_LF,
+    STATE(9), 1,
+      aux_sym__linebreak,
+    STATE(271), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9491] = 3,
+  [9509] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(826), 1,
+      ts_builtin_sym_end,
+    ACTIONS(828), 1,
+      anon_sym_LF,
+    STATE(183), 1,
+      aux_sym__linebreak,
+    STATE(248), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(829), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [9505] = 6,
+  [9529] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(831), 1,
+    ACTIONS(767), 1,
       anon_sym_RBRACE,
-    ACTIONS(833), 1,
+    ACTIONS(771), 1,
       anon_sym_LF,
-    STATE(6), 1,
+    STATE(4), 1,
       aux_sym__linebreak,
-    STATE(241), 1,
+    STATE(272), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9525] = 3,
+  [9549] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(500), 4,
+    ACTIONS(830), 4,
+      ts_builtin_sym_end,
       anon_sym_RBRACE,
-      anon_sym_EQ,
-      anon_sym_COMMA,
+      anon_sym_else,
       anon_sym_LF,
-  [9539] = 6,
+  [9563] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    ACTIONS(801), 1,
+    ACTIONS(810), 1,
       anon_sym_POUND_LPAREN,
-    STATE(320), 1,
-      sym_block,
-    STATE(331), 1,
+    STATE(326), 1,
       sym_template_declaration_arguments,
+    STATE(327), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9559] = 3,
+  [9583] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(832), 1,
+      ts_builtin_sym_end,
+    ACTIONS(834), 1,
+      anon_sym_LF,
+    STATE(182), 1,
+      aux_sym__linebreak,
+    STATE(256), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(835), 4,
-      ts_builtin_sym_end,
-      anon_sym_RBRACE,
-      anon_sym_else,
-      anon_sym_LF,
-  [9573] = 3,
+  [9603] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(837), 4,
+    ACTIONS(830), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9587] = 3,
+  [9617] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(839), 4,
+    ACTIONS(836), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9601] = 4,
+  [9631] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(843), 1,
-      anon_sym_COLON,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(841), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
+    ACTIONS(838), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-  [9617] = 3,
+  [9645] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(839), 4,
+    ACTIONS(840), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9631] = 6,
+  [9659] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(845), 1,
+    ACTIONS(777), 1,
       anon_sym_RBRACE,
-    ACTIONS(847), 1,
+    ACTIONS(779), 1,
       anon_sym_LF,
-    STATE(5), 1,
+    STATE(6), 1,
       aux_sym__linebreak,
-    STATE(241), 1,
+    STATE(254), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9651] = 3,
+  [9679] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(849), 4,
+    ACTIONS(842), 4,
       ts_builtin_sym_end,
       anon_sym_RBRACE,
       anon_sym_else,
       anon_sym_LF,
-  [9665] = 6,
+  [9693] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(744), 1,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(520), 4,
       anon_sym_RBRACE,
-    ACTIONS(748), 1,
+      anon_sym_EQ,
+      anon_sym_COMMA,
       anon_sym_LF,
-    STATE(8), 1,
+  [9707] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+    ACTIONS(844), 4,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_else,
+      anon_sym_LF,
+  [9721] = 6,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(846), 1,
+      ts_builtin_sym_end,
+    ACTIONS(848), 1,
+      anon_sym_LF,
+    STATE(177), 1,
       aux_sym__linebreak,
-    STATE(238), 1,
-      aux_sym_block_repeat1,
+    STATE(248), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9685] = 6,
+  [9741] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(371), 1,
-      anon_sym_COMMA,
-    ACTIONS(851), 1,
-      anon_sym_RPAREN,
-    STATE(70), 1,
-      sym__comma,
-    STATE(197), 1,
-      aux_sym_parenthesis_expression_list_repeat1,
+    ACTIONS(13), 1,
+      anon_sym_const,
+    STATE(349), 1,
+      sym_const_and_type,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9705] = 6,
+    ACTIONS(850), 2,
+      anon_sym_module,
+      anon_sym_struct,
+  [9759] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(853), 1,
-      ts_builtin_sym_end,
-    ACTIONS(855), 1,
+    ACTIONS(852), 1,
+      anon_sym_RBRACE,
+    ACTIONS(854), 1,
       anon_sym_LF,
-    STATE(180), 1,
+    STATE(10), 1,
       aux_sym__linebreak,
-    STATE(246), 1,
-      aux_sym_source_file_repeat1,
+    STATE(271), 1,
+      aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9725] = 6,
+  [9779] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(857), 1,
       anon_sym_RBRACE,
     ACTIONS(859), 1,
       anon_sym_LF,
-    STATE(9), 1,
+    STATE(7), 1,
       aux_sym__linebreak,
-    STATE(241), 1,
+    STATE(271), 1,
       aux_sym_block_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9745] = 5,
+  [9799] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
     ACTIONS(861), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
+      ts_builtin_sym_end,
+    ACTIONS(863), 1,
+      anon_sym_LF,
+    STATE(178), 1,
       aux_sym__linebreak,
+    STATE(269), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9762] = 5,
+  [9819] = 6,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(777), 1,
-      anon_sym_LBRACK,
-    ACTIONS(863), 1,
-      sym_identifier,
-    STATE(221), 1,
-      sym_array_type_bracket,
+    ACTIONS(379), 1,
+      anon_sym_COMMA,
+    ACTIONS(865), 1,
+      anon_sym_RPAREN,
+    STATE(70), 1,
+      sym__comma,
+    STATE(212), 1,
+      aux_sym_parenthesis_expression_list_repeat1,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9779] = 3,
+  [9839] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(865), 3,
-      anon_sym_LBRACE,
+    ACTIONS(838), 4,
+      ts_builtin_sym_end,
       anon_sym_RBRACE,
+      anon_sym_else,
       anon_sym_LF,
-  [9792] = 5,
+  [9853] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(867), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9809] = 5,
+  [9870] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
+    ACTIONS(783), 1,
+      anon_sym_LBRACK,
     ACTIONS(869), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
-      aux_sym__linebreak,
+      sym_identifier,
+    STATE(236), 1,
+      sym_array_type_bracket,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9826] = 5,
+  [9887] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(871), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9843] = 5,
+    ACTIONS(871), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_LF,
+  [9900] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(873), 1,
       anon_sym_RPAREN,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9860] = 4,
+  [9917] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(746), 1,
-      anon_sym_EQ,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(875), 2,
-      anon_sym_RBRACE,
+    ACTIONS(875), 3,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
       anon_sym_LF,
-  [9875] = 5,
+  [9930] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(877), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9892] = 3,
+  [9947] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(879), 3,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
+    ACTIONS(597), 1,
       anon_sym_LF,
-  [9905] = 3,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+    ACTIONS(879), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(881), 3,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [9918] = 3,
+  [9964] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(783), 1,
+      anon_sym_LBRACK,
+    ACTIONS(881), 1,
+      sym_identifier,
+    STATE(236), 1,
+      sym_array_type_bracket,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(883), 3,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [9931] = 5,
+  [9981] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
-    ACTIONS(885), 1,
+    ACTIONS(883), 1,
       anon_sym_RPAREN,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9948] = 3,
+  [9998] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(885), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(887), 3,
-      anon_sym_interface,
-      anon_sym_action,
-      anon_sym_trigger,
-  [9961] = 5,
+  [10015] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(777), 1,
-      anon_sym_LBRACK,
-    ACTIONS(889), 1,
-      sym_identifier,
-    STATE(221), 1,
-      sym_array_type_bracket,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(887), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9978] = 5,
+  [10032] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(19), 1,
       anon_sym_LBRACE,
-    STATE(247), 1,
+    STATE(245), 1,
       sym_block,
-    STATE(328), 1,
+    STATE(322), 1,
       sym__then_else_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [9995] = 3,
+  [10049] = 5,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(783), 1,
+      anon_sym_LBRACK,
+    ACTIONS(889), 1,
+      sym_identifier,
+    STATE(236), 1,
+      sym_array_type_bracket,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10066] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(891), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(891), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_LF,
-  [10008] = 5,
+  [10083] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(893), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10025] = 5,
+    ACTIONS(893), 3,
+      anon_sym_interface,
+      anon_sym_action,
+      anon_sym_trigger,
+  [10096] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(895), 1,
       anon_sym_RPAREN,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10042] = 5,
+  [10113] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(897), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10059] = 5,
+    ACTIONS(897), 3,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10126] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(899), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10076] = 3,
+    ACTIONS(899), 3,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10139] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11573,19 +11629,19 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10089] = 5,
+  [10152] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(903), 1,
       anon_sym_RPAREN,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10106] = 3,
+  [10169] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11595,217 +11651,206 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10119] = 5,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(907), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
-      aux_sym__linebreak,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [10136] = 3,
+  [10182] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(769), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(909), 3,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
+    ACTIONS(907), 2,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [10149] = 5,
+  [10197] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
     ACTIONS(911), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
-      aux_sym__linebreak,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10166] = 5,
+    ACTIONS(909), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10212] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(913), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10183] = 5,
+  [10229] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(777), 1,
-      anon_sym_LBRACK,
-    ACTIONS(915), 1,
-      sym_identifier,
-    STATE(221), 1,
-      sym_array_type_bracket,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10200] = 5,
+    ACTIONS(915), 3,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10242] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(917), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10217] = 5,
+  [10259] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(919), 1,
       anon_sym_RBRACK,
-    STATE(177), 1,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10234] = 5,
+  [10276] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
-      anon_sym_LF,
-    ACTIONS(921), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
-      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10251] = 5,
+    ACTIONS(921), 3,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10289] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(923), 1,
-      anon_sym_RBRACK,
-    STATE(177), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10268] = 5,
+  [10306] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(588), 1,
+    ACTIONS(597), 1,
       anon_sym_LF,
     ACTIONS(925), 1,
-      anon_sym_RPAREN,
-    STATE(177), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
       aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10285] = 3,
+  [10323] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-    ACTIONS(927), 2,
-      ts_builtin_sym_end,
+    ACTIONS(597), 1,
       anon_sym_LF,
-  [10297] = 3,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
+    ACTIONS(927), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(929), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [10309] = 3,
+  [10340] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(929), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(931), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [10321] = 3,
+  [10357] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(931), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(933), 2,
-      ts_builtin_sym_end,
-      anon_sym_LF,
-  [10333] = 4,
+  [10374] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(750), 1,
-      sym_identifier,
-    STATE(290), 1,
-      sym_template_arg,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(933), 1,
+      anon_sym_RPAREN,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10347] = 3,
+  [10391] = 5,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(597), 1,
+      anon_sym_LF,
+    ACTIONS(935), 1,
+      anon_sym_RBRACK,
+    STATE(176), 1,
+      aux_sym__linebreak,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(935), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [10359] = 3,
+  [10408] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(875), 2,
+    ACTIONS(937), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10371] = 3,
+  [10420] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    STATE(323), 1,
+      sym_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(937), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [10383] = 3,
+  [10434] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
     ACTIONS(939), 2,
-      anon_sym_RBRACE,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [10395] = 4,
+  [10446] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(143), 1,
-      sym_identifier,
-    STATE(110), 1,
-      sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10409] = 3,
+    ACTIONS(907), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10458] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11814,26 +11859,16 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(941), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10421] = 4,
-    ACTIONS(5), 1,
-      sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    STATE(311), 1,
-      sym_block,
-    ACTIONS(3), 2,
-      sym_doc_comment,
-      sym_multi_line_comment,
-  [10435] = 3,
+  [10470] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
     ACTIONS(943), 2,
-      ts_builtin_sym_end,
+      anon_sym_RBRACE,
       anon_sym_LF,
-  [10447] = 3,
+  [10482] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11842,7 +11877,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(945), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10459] = 3,
+  [10494] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11851,7 +11886,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(947), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10471] = 3,
+  [10506] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11860,7 +11895,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(949), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10483] = 3,
+  [10518] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11869,35 +11904,35 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(951), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-  [10495] = 3,
+  [10530] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
+    ACTIONS(143), 1,
+      sym_identifier,
+    STATE(102), 1,
+      sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(953), 2,
-      anon_sym_RBRACE,
-      anon_sym_LF,
-  [10507] = 4,
+  [10544] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(269), 1,
-      sym_identifier,
-    STATE(19), 1,
-      sym_namespace_list,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10521] = 3,
+    ACTIONS(953), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10556] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
     ACTIONS(955), 2,
-      anon_sym_RBRACE,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [10533] = 3,
+  [10568] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11906,7 +11941,27 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(957), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10545] = 3,
+  [10580] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(227), 1,
+      sym_identifier,
+    STATE(18), 1,
+      sym_namespace_list,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10594] = 4,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(19), 1,
+      anon_sym_LBRACE,
+    STATE(313), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10608] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11915,7 +11970,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(959), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-  [10557] = 3,
+  [10620] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
@@ -11924,106 +11979,110 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(961), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10569] = 4,
+  [10632] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(19), 1,
-      anon_sym_LBRACE,
-    STATE(329), 1,
-      sym_block,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10583] = 3,
+    ACTIONS(963), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10644] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-    ACTIONS(963), 2,
+    ACTIONS(965), 2,
       anon_sym_RBRACE,
       anon_sym_LF,
-  [10595] = 3,
+  [10656] = 4,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(965), 1,
+    ACTIONS(734), 1,
       sym_identifier,
+    STATE(280), 1,
+      sym_template_arg,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10606] = 3,
+  [10670] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(967), 1,
-      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10617] = 3,
+    ACTIONS(967), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10682] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(969), 1,
-      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10628] = 3,
+    ACTIONS(969), 2,
+      anon_sym_RBRACE,
+      anon_sym_LF,
+  [10694] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
-    ACTIONS(971), 1,
-      anon_sym_in,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10639] = 3,
+    ACTIONS(971), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+  [10706] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(973), 1,
-      sym_identifier,
+      anon_sym_RBRACK,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10650] = 3,
+  [10717] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(975), 1,
-      anon_sym_LBRACE,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10661] = 3,
+  [10728] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(977), 1,
-      sym_identifier,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10672] = 3,
+  [10739] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(979), 1,
-      sym_identifier,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10683] = 3,
+  [10750] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(981), 1,
-      sym_identifier,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10694] = 3,
+  [10761] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(983), 1,
-      anon_sym_LBRACE,
+      anon_sym_in,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10705] = 3,
+  [10772] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(985), 1,
@@ -12031,15 +12090,15 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10716] = 3,
+  [10783] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(987), 1,
-      anon_sym_RBRACK,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10727] = 3,
+  [10794] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(989), 1,
@@ -12047,7 +12106,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10738] = 3,
+  [10805] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(991), 1,
@@ -12055,31 +12114,31 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10749] = 3,
+  [10816] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(993), 1,
-      anon_sym_LBRACE,
+      ts_builtin_sym_end,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10760] = 3,
+  [10827] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(995), 1,
-      anon_sym_RBRACK,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10771] = 3,
+  [10838] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(997), 1,
-      anon_sym_LBRACE,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10782] = 3,
+  [10849] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(999), 1,
@@ -12087,15 +12146,15 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10793] = 3,
+  [10860] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(1001), 1,
-      anon_sym_LBRACE,
+      sym_identifier,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10804] = 3,
+  [10871] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(1003), 1,
@@ -12103,26 +12162,58 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10815] = 3,
+  [10882] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(1005), 1,
-      ts_builtin_sym_end,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10826] = 3,
+  [10893] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(1007), 1,
-      sym_identifier,
+      anon_sym_RBRACK,
     ACTIONS(3), 2,
       sym_doc_comment,
       sym_multi_line_comment,
-  [10837] = 3,
+  [10904] = 3,
     ACTIONS(5), 1,
       sym_single_line_comment,
     ACTIONS(1009), 1,
+      sym_identifier,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10915] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(1011), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10926] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(1013), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10937] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(1015), 1,
+      sym_identifier,
+    ACTIONS(3), 2,
+      sym_doc_comment,
+      sym_multi_line_comment,
+  [10948] = 3,
+    ACTIONS(5), 1,
+      sym_single_line_comment,
+    ACTIONS(1017), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym_doc_comment,
@@ -12137,12 +12228,12 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(15)] = 259,
   [SMALL_STATE(16)] = 307,
   [SMALL_STATE(17)] = 355,
-  [SMALL_STATE(18)] = 402,
+  [SMALL_STATE(18)] = 398,
   [SMALL_STATE(19)] = 445,
   [SMALL_STATE(20)] = 492,
   [SMALL_STATE(21)] = 548,
-  [SMALL_STATE(22)] = 608,
-  [SMALL_STATE(23)] = 670,
+  [SMALL_STATE(22)] = 600,
+  [SMALL_STATE(23)] = 662,
   [SMALL_STATE(24)] = 722,
   [SMALL_STATE(25)] = 786,
   [SMALL_STATE(26)] = 852,
@@ -12151,39 +12242,39 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(29)] = 1008,
   [SMALL_STATE(30)] = 1049,
   [SMALL_STATE(31)] = 1090,
-  [SMALL_STATE(32)] = 1153,
-  [SMALL_STATE(33)] = 1194,
-  [SMALL_STATE(34)] = 1235,
-  [SMALL_STATE(35)] = 1276,
-  [SMALL_STATE(36)] = 1317,
-  [SMALL_STATE(37)] = 1358,
-  [SMALL_STATE(38)] = 1399,
-  [SMALL_STATE(39)] = 1440,
-  [SMALL_STATE(40)] = 1481,
+  [SMALL_STATE(32)] = 1131,
+  [SMALL_STATE(33)] = 1172,
+  [SMALL_STATE(34)] = 1213,
+  [SMALL_STATE(35)] = 1254,
+  [SMALL_STATE(36)] = 1295,
+  [SMALL_STATE(37)] = 1336,
+  [SMALL_STATE(38)] = 1377,
+  [SMALL_STATE(39)] = 1418,
+  [SMALL_STATE(40)] = 1459,
   [SMALL_STATE(41)] = 1522,
-  [SMALL_STATE(42)] = 1590,
-  [SMALL_STATE(43)] = 1630,
-  [SMALL_STATE(44)] = 1670,
-  [SMALL_STATE(45)] = 1710,
-  [SMALL_STATE(46)] = 1750,
-  [SMALL_STATE(47)] = 1790,
-  [SMALL_STATE(48)] = 1830,
-  [SMALL_STATE(49)] = 1870,
-  [SMALL_STATE(50)] = 1910,
-  [SMALL_STATE(51)] = 1950,
-  [SMALL_STATE(52)] = 1990,
-  [SMALL_STATE(53)] = 2030,
-  [SMALL_STATE(54)] = 2070,
-  [SMALL_STATE(55)] = 2130,
-  [SMALL_STATE(56)] = 2190,
-  [SMALL_STATE(57)] = 2230,
-  [SMALL_STATE(58)] = 2270,
-  [SMALL_STATE(59)] = 2310,
-  [SMALL_STATE(60)] = 2350,
-  [SMALL_STATE(61)] = 2390,
-  [SMALL_STATE(62)] = 2430,
+  [SMALL_STATE(42)] = 1562,
+  [SMALL_STATE(43)] = 1602,
+  [SMALL_STATE(44)] = 1642,
+  [SMALL_STATE(45)] = 1682,
+  [SMALL_STATE(46)] = 1742,
+  [SMALL_STATE(47)] = 1782,
+  [SMALL_STATE(48)] = 1822,
+  [SMALL_STATE(49)] = 1882,
+  [SMALL_STATE(50)] = 1922,
+  [SMALL_STATE(51)] = 1962,
+  [SMALL_STATE(52)] = 2030,
+  [SMALL_STATE(53)] = 2070,
+  [SMALL_STATE(54)] = 2110,
+  [SMALL_STATE(55)] = 2170,
+  [SMALL_STATE(56)] = 2210,
+  [SMALL_STATE(57)] = 2250,
+  [SMALL_STATE(58)] = 2290,
+  [SMALL_STATE(59)] = 2330,
+  [SMALL_STATE(60)] = 2370,
+  [SMALL_STATE(61)] = 2410,
+  [SMALL_STATE(62)] = 2450,
   [SMALL_STATE(63)] = 2490,
-  [SMALL_STATE(64)] = 2550,
+  [SMALL_STATE(64)] = 2530,
   [SMALL_STATE(65)] = 2590,
   [SMALL_STATE(66)] = 2644,
   [SMALL_STATE(67)] = 2698,
@@ -12193,288 +12284,290 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(71)] = 2911,
   [SMALL_STATE(72)] = 2962,
   [SMALL_STATE(73)] = 3013,
-  [SMALL_STATE(74)] = 3084,
-  [SMALL_STATE(75)] = 3135,
+  [SMALL_STATE(74)] = 3064,
+  [SMALL_STATE(75)] = 3115,
   [SMALL_STATE(76)] = 3186,
   [SMALL_STATE(77)] = 3237,
   [SMALL_STATE(78)] = 3288,
   [SMALL_STATE(79)] = 3339,
   [SMALL_STATE(80)] = 3390,
   [SMALL_STATE(81)] = 3441,
-  [SMALL_STATE(82)] = 3512,
-  [SMALL_STATE(83)] = 3563,
+  [SMALL_STATE(82)] = 3492,
+  [SMALL_STATE(83)] = 3543,
   [SMALL_STATE(84)] = 3614,
   [SMALL_STATE(85)] = 3665,
-  [SMALL_STATE(86)] = 3716,
-  [SMALL_STATE(87)] = 3767,
-  [SMALL_STATE(88)] = 3818,
-  [SMALL_STATE(89)] = 3869,
-  [SMALL_STATE(90)] = 3920,
-  [SMALL_STATE(91)] = 3971,
+  [SMALL_STATE(86)] = 3736,
+  [SMALL_STATE(87)] = 3787,
+  [SMALL_STATE(88)] = 3838,
+  [SMALL_STATE(89)] = 3889,
+  [SMALL_STATE(90)] = 3940,
+  [SMALL_STATE(91)] = 3991,
   [SMALL_STATE(92)] = 4042,
   [SMALL_STATE(93)] = 4093,
   [SMALL_STATE(94)] = 4144,
-  [SMALL_STATE(95)] = 4215,
-  [SMALL_STATE(96)] = 4266,
+  [SMALL_STATE(95)] = 4195,
+  [SMALL_STATE(96)] = 4246,
   [SMALL_STATE(97)] = 4317,
-  [SMALL_STATE(98)] = 4357,
-  [SMALL_STATE(99)] = 4425,
-  [SMALL_STATE(100)] = 4465,
-  [SMALL_STATE(101)] = 4505,
-  [SMALL_STATE(102)] = 4566,
-  [SMALL_STATE(103)] = 4627,
-  [SMALL_STATE(104)] = 4666,
-  [SMALL_STATE(105)] = 4731,
-  [SMALL_STATE(106)] = 4792,
-  [SMALL_STATE(107)] = 4831,
-  [SMALL_STATE(108)] = 4892,
-  [SMALL_STATE(109)] = 4951,
-  [SMALL_STATE(110)] = 4990,
-  [SMALL_STATE(111)] = 5029,
-  [SMALL_STATE(112)] = 5064,
-  [SMALL_STATE(113)] = 5109,
-  [SMALL_STATE(114)] = 5158,
-  [SMALL_STATE(115)] = 5203,
-  [SMALL_STATE(116)] = 5256,
-  [SMALL_STATE(117)] = 5307,
-  [SMALL_STATE(118)] = 5362,
-  [SMALL_STATE(119)] = 5419,
-  [SMALL_STATE(120)] = 5484,
-  [SMALL_STATE(121)] = 5522,
-  [SMALL_STATE(122)] = 5580,
-  [SMALL_STATE(123)] = 5613,
-  [SMALL_STATE(124)] = 5646,
-  [SMALL_STATE(125)] = 5683,
-  [SMALL_STATE(126)] = 5742,
-  [SMALL_STATE(127)] = 5775,
-  [SMALL_STATE(128)] = 5808,
-  [SMALL_STATE(129)] = 5841,
-  [SMALL_STATE(130)] = 5874,
-  [SMALL_STATE(131)] = 5907,
-  [SMALL_STATE(132)] = 5940,
-  [SMALL_STATE(133)] = 5973,
-  [SMALL_STATE(134)] = 6006,
-  [SMALL_STATE(135)] = 6039,
-  [SMALL_STATE(136)] = 6072,
-  [SMALL_STATE(137)] = 6129,
-  [SMALL_STATE(138)] = 6162,
-  [SMALL_STATE(139)] = 6195,
-  [SMALL_STATE(140)] = 6228,
-  [SMALL_STATE(141)] = 6261,
-  [SMALL_STATE(142)] = 6294,
-  [SMALL_STATE(143)] = 6327,
-  [SMALL_STATE(144)] = 6360,
-  [SMALL_STATE(145)] = 6393,
-  [SMALL_STATE(146)] = 6426,
-  [SMALL_STATE(147)] = 6463,
-  [SMALL_STATE(148)] = 6496,
-  [SMALL_STATE(149)] = 6529,
-  [SMALL_STATE(150)] = 6562,
-  [SMALL_STATE(151)] = 6595,
-  [SMALL_STATE(152)] = 6628,
-  [SMALL_STATE(153)] = 6661,
-  [SMALL_STATE(154)] = 6694,
-  [SMALL_STATE(155)] = 6727,
-  [SMALL_STATE(156)] = 6760,
-  [SMALL_STATE(157)] = 6793,
-  [SMALL_STATE(158)] = 6849,
-  [SMALL_STATE(159)] = 6905,
-  [SMALL_STATE(160)] = 6961,
-  [SMALL_STATE(161)] = 6993,
-  [SMALL_STATE(162)] = 7049,
-  [SMALL_STATE(163)] = 7105,
-  [SMALL_STATE(164)] = 7161,
-  [SMALL_STATE(165)] = 7192,
-  [SMALL_STATE(166)] = 7244,
-  [SMALL_STATE(167)] = 7296,
-  [SMALL_STATE(168)] = 7343,
-  [SMALL_STATE(169)] = 7390,
-  [SMALL_STATE(170)] = 7436,
-  [SMALL_STATE(171)] = 7482,
-  [SMALL_STATE(172)] = 7520,
-  [SMALL_STATE(173)] = 7557,
-  [SMALL_STATE(174)] = 7594,
-  [SMALL_STATE(175)] = 7628,
-  [SMALL_STATE(176)] = 7662,
-  [SMALL_STATE(177)] = 7696,
-  [SMALL_STATE(178)] = 7720,
-  [SMALL_STATE(179)] = 7744,
-  [SMALL_STATE(180)] = 7768,
-  [SMALL_STATE(181)] = 7802,
-  [SMALL_STATE(182)] = 7836,
-  [SMALL_STATE(183)] = 7869,
-  [SMALL_STATE(184)] = 7902,
-  [SMALL_STATE(185)] = 7933,
-  [SMALL_STATE(186)] = 7951,
-  [SMALL_STATE(187)] = 7969,
-  [SMALL_STATE(188)] = 7993,
-  [SMALL_STATE(189)] = 8016,
-  [SMALL_STATE(190)] = 8039,
-  [SMALL_STATE(191)] = 8062,
-  [SMALL_STATE(192)] = 8085,
-  [SMALL_STATE(193)] = 8112,
-  [SMALL_STATE(194)] = 8139,
-  [SMALL_STATE(195)] = 8162,
-  [SMALL_STATE(196)] = 8180,
-  [SMALL_STATE(197)] = 8206,
-  [SMALL_STATE(198)] = 8228,
-  [SMALL_STATE(199)] = 8254,
-  [SMALL_STATE(200)] = 8280,
-  [SMALL_STATE(201)] = 8306,
-  [SMALL_STATE(202)] = 8332,
-  [SMALL_STATE(203)] = 8354,
-  [SMALL_STATE(204)] = 8380,
-  [SMALL_STATE(205)] = 8406,
-  [SMALL_STATE(206)] = 8432,
-  [SMALL_STATE(207)] = 8454,
-  [SMALL_STATE(208)] = 8476,
-  [SMALL_STATE(209)] = 8498,
-  [SMALL_STATE(210)] = 8520,
-  [SMALL_STATE(211)] = 8546,
-  [SMALL_STATE(212)] = 8572,
-  [SMALL_STATE(213)] = 8598,
-  [SMALL_STATE(214)] = 8620,
-  [SMALL_STATE(215)] = 8646,
-  [SMALL_STATE(216)] = 8672,
-  [SMALL_STATE(217)] = 8698,
-  [SMALL_STATE(218)] = 8724,
-  [SMALL_STATE(219)] = 8750,
-  [SMALL_STATE(220)] = 8769,
-  [SMALL_STATE(221)] = 8790,
-  [SMALL_STATE(222)] = 8805,
-  [SMALL_STATE(223)] = 8824,
-  [SMALL_STATE(224)] = 8845,
-  [SMALL_STATE(225)] = 8866,
-  [SMALL_STATE(226)] = 8881,
-  [SMALL_STATE(227)] = 8902,
-  [SMALL_STATE(228)] = 8921,
-  [SMALL_STATE(229)] = 8944,
-  [SMALL_STATE(230)] = 8967,
-  [SMALL_STATE(231)] = 8982,
-  [SMALL_STATE(232)] = 9005,
-  [SMALL_STATE(233)] = 9026,
-  [SMALL_STATE(234)] = 9049,
-  [SMALL_STATE(235)] = 9072,
-  [SMALL_STATE(236)] = 9093,
-  [SMALL_STATE(237)] = 9112,
-  [SMALL_STATE(238)] = 9135,
-  [SMALL_STATE(239)] = 9155,
-  [SMALL_STATE(240)] = 9169,
-  [SMALL_STATE(241)] = 9183,
-  [SMALL_STATE(242)] = 9203,
-  [SMALL_STATE(243)] = 9223,
-  [SMALL_STATE(244)] = 9237,
-  [SMALL_STATE(245)] = 9257,
-  [SMALL_STATE(246)] = 9271,
-  [SMALL_STATE(247)] = 9291,
-  [SMALL_STATE(248)] = 9309,
-  [SMALL_STATE(249)] = 9327,
-  [SMALL_STATE(250)] = 9347,
-  [SMALL_STATE(251)] = 9367,
-  [SMALL_STATE(252)] = 9381,
-  [SMALL_STATE(253)] = 9395,
-  [SMALL_STATE(254)] = 9409,
-  [SMALL_STATE(255)] = 9423,
-  [SMALL_STATE(256)] = 9443,
-  [SMALL_STATE(257)] = 9457,
-  [SMALL_STATE(258)] = 9471,
-  [SMALL_STATE(259)] = 9491,
-  [SMALL_STATE(260)] = 9505,
-  [SMALL_STATE(261)] = 9525,
-  [SMALL_STATE(262)] = 9539,
-  [SMALL_STATE(263)] = 9559,
-  [SMALL_STATE(264)] = 9573,
-  [SMALL_STATE(265)] = 9587,
-  [SMALL_STATE(266)] = 9601,
-  [SMALL_STATE(267)] = 9617,
-  [SMALL_STATE(268)] = 9631,
-  [SMALL_STATE(269)] = 9651,
-  [SMALL_STATE(270)] = 9665,
-  [SMALL_STATE(271)] = 9685,
-  [SMALL_STATE(272)] = 9705,
-  [SMALL_STATE(273)] = 9725,
-  [SMALL_STATE(274)] = 9745,
-  [SMALL_STATE(275)] = 9762,
-  [SMALL_STATE(276)] = 9779,
-  [SMALL_STATE(277)] = 9792,
-  [SMALL_STATE(278)] = 9809,
-  [SMALL_STATE(279)] = 9826,
-  [SMALL_STATE(280)] = 9843,
-  [SMALL_STATE(281)] = 9860,
-  [SMALL_STATE(282)] = 9875,
-  [SMALL_STATE(283)] = 9892,
-  [SMALL_STATE(284)] = 9905,
-  [SMALL_STATE(285)] = 9918,
-  [SMALL_STATE(286)] = 9931,
-  [SMALL_STATE(287)] = 9948,
-  [SMALL_STATE(288)] = 9961,
-  [SMALL_STATE(289)] = 9978,
-  [SMALL_STATE(290)] = 9995,
-  [SMALL_STATE(291)] = 10008,
-  [SMALL_STATE(292)] = 10025,
-  [SMALL_STATE(293)] = 10042,
-  [SMALL_STATE(294)] = 10059,
-  [SMALL_STATE(295)] = 10076,
-  [SMALL_STATE(296)] = 10089,
-  [SMALL_STATE(297)] = 10106,
-  [SMALL_STATE(298)] = 10119,
-  [SMALL_STATE(299)] = 10136,
-  [SMALL_STATE(300)] = 10149,
-  [SMALL_STATE(301)] = 10166,
-  [SMALL_STATE(302)] = 10183,
-  [SMALL_STATE(303)] = 10200,
-  [SMALL_STATE(304)] = 10217,
-  [SMALL_STATE(305)] = 10234,
-  [SMALL_STATE(306)] = 10251,
-  [SMALL_STATE(307)] = 10268,
-  [SMALL_STATE(308)] = 10285,
-  [SMALL_STATE(309)] = 10297,
-  [SMALL_STATE(310)] = 10309,
-  [SMALL_STATE(311)] = 10321,
-  [SMALL_STATE(312)] = 10333,
-  [SMALL_STATE(313)] = 10347,
-  [SMALL_STATE(314)] = 10359,
-  [SMALL_STATE(315)] = 10371,
-  [SMALL_STATE(316)] = 10383,
-  [SMALL_STATE(317)] = 10395,
-  [SMALL_STATE(318)] = 10409,
-  [SMALL_STATE(319)] = 10421,
-  [SMALL_STATE(320)] = 10435,
-  [SMALL_STATE(321)] = 10447,
-  [SMALL_STATE(322)] = 10459,
-  [SMALL_STATE(323)] = 10471,
-  [SMALL_STATE(324)] = 10483,
-  [SMALL_STATE(325)] = 10495,
-  [SMALL_STATE(326)] = 10507,
-  [SMALL_STATE(327)] = 10521,
-  [SMALL_STATE(328)] = 10533,
-  [SMALL_STATE(329)] = 10545,
-  [SMALL_STATE(330)] = 10557,
-  [SMALL_STATE(331)] = 10569,
-  [SMALL_STATE(332)] = 10583,
-  [SMALL_STATE(333)] = 10595,
-  [SMALL_STATE(334)] = 10606,
-  [SMALL_STATE(335)] = 10617,
-  [SMALL_STATE(336)] = 10628,
-  [SMALL_STATE(337)] = 10639,
-  [SMALL_STATE(338)] = 10650,
-  [SMALL_STATE(339)] = 10661,
-  [SMALL_STATE(340)] = 10672,
-  [SMALL_STATE(341)] = 10683,
-  [SMALL_STATE(342)] = 10694,
-  [SMALL_STATE(343)] = 10705,
-  [SMALL_STATE(344)] = 10716,
-  [SMALL_STATE(345)] = 10727,
-  [SMALL_STATE(346)] = 10738,
-  [SMALL_STATE(347)] = 10749,
-  [SMALL_STATE(348)] = 10760,
-  [SMALL_STATE(349)] = 10771,
-  [SMALL_STATE(350)] = 10782,
-  [SMALL_STATE(351)] = 10793,
-  [SMALL_STATE(352)] = 10804,
-  [SMALL_STATE(353)] = 10815,
-  [SMALL_STATE(354)] = 10826,
-  [SMALL_STATE(355)] = 10837,
+  [SMALL_STATE(98)] = 4368,
+  [SMALL_STATE(99)] = 4436,
+  [SMALL_STATE(100)] = 4476,
+  [SMALL_STATE(101)] = 4516,
+  [SMALL_STATE(102)] = 4556,
+  [SMALL_STATE(103)] = 4595,
+  [SMALL_STATE(104)] = 4634,
+  [SMALL_STATE(105)] = 4695,
+  [SMALL_STATE(106)] = 4734,
+  [SMALL_STATE(107)] = 4789,
+  [SMALL_STATE(108)] = 4838,
+  [SMALL_STATE(109)] = 4883,
+  [SMALL_STATE(110)] = 4936,
+  [SMALL_STATE(111)] = 4987,
+  [SMALL_STATE(112)] = 5044,
+  [SMALL_STATE(113)] = 5105,
+  [SMALL_STATE(114)] = 5140,
+  [SMALL_STATE(115)] = 5201,
+  [SMALL_STATE(116)] = 5246,
+  [SMALL_STATE(117)] = 5305,
+  [SMALL_STATE(118)] = 5370,
+  [SMALL_STATE(119)] = 5435,
+  [SMALL_STATE(120)] = 5496,
+  [SMALL_STATE(121)] = 5535,
+  [SMALL_STATE(122)] = 5573,
+  [SMALL_STATE(123)] = 5631,
+  [SMALL_STATE(124)] = 5664,
+  [SMALL_STATE(125)] = 5697,
+  [SMALL_STATE(126)] = 5754,
+  [SMALL_STATE(127)] = 5791,
+  [SMALL_STATE(128)] = 5848,
+  [SMALL_STATE(129)] = 5907,
+  [SMALL_STATE(130)] = 5940,
+  [SMALL_STATE(131)] = 5973,
+  [SMALL_STATE(132)] = 6006,
+  [SMALL_STATE(133)] = 6039,
+  [SMALL_STATE(134)] = 6072,
+  [SMALL_STATE(135)] = 6105,
+  [SMALL_STATE(136)] = 6138,
+  [SMALL_STATE(137)] = 6171,
+  [SMALL_STATE(138)] = 6204,
+  [SMALL_STATE(139)] = 6237,
+  [SMALL_STATE(140)] = 6270,
+  [SMALL_STATE(141)] = 6303,
+  [SMALL_STATE(142)] = 6336,
+  [SMALL_STATE(143)] = 6369,
+  [SMALL_STATE(144)] = 6402,
+  [SMALL_STATE(145)] = 6435,
+  [SMALL_STATE(146)] = 6468,
+  [SMALL_STATE(147)] = 6501,
+  [SMALL_STATE(148)] = 6534,
+  [SMALL_STATE(149)] = 6567,
+  [SMALL_STATE(150)] = 6600,
+  [SMALL_STATE(151)] = 6633,
+  [SMALL_STATE(152)] = 6666,
+  [SMALL_STATE(153)] = 6699,
+  [SMALL_STATE(154)] = 6732,
+  [SMALL_STATE(155)] = 6765,
+  [SMALL_STATE(156)] = 6798,
+  [SMALL_STATE(157)] = 6831,
+  [SMALL_STATE(158)] = 6864,
+  [SMALL_STATE(159)] = 6901,
+  [SMALL_STATE(160)] = 6957,
+  [SMALL_STATE(161)] = 7013,
+  [SMALL_STATE(162)] = 7069,
+  [SMALL_STATE(163)] = 7101,
+  [SMALL_STATE(164)] = 7157,
+  [SMALL_STATE(165)] = 7213,
+  [SMALL_STATE(166)] = 7269,
+  [SMALL_STATE(167)] = 7300,
+  [SMALL_STATE(168)] = 7352,
+  [SMALL_STATE(169)] = 7404,
+  [SMALL_STATE(170)] = 7451,
+  [SMALL_STATE(171)] = 7498,
+  [SMALL_STATE(172)] = 7544,
+  [SMALL_STATE(173)] = 7590,
+  [SMALL_STATE(174)] = 7628,
+  [SMALL_STATE(175)] = 7665,
+  [SMALL_STATE(176)] = 7702,
+  [SMALL_STATE(177)] = 7726,
+  [SMALL_STATE(178)] = 7760,
+  [SMALL_STATE(179)] = 7794,
+  [SMALL_STATE(180)] = 7818,
+  [SMALL_STATE(181)] = 7852,
+  [SMALL_STATE(182)] = 7876,
+  [SMALL_STATE(183)] = 7910,
+  [SMALL_STATE(184)] = 7944,
+  [SMALL_STATE(185)] = 7975,
+  [SMALL_STATE(186)] = 8008,
+  [SMALL_STATE(187)] = 8041,
+  [SMALL_STATE(188)] = 8059,
+  [SMALL_STATE(189)] = 8083,
+  [SMALL_STATE(190)] = 8101,
+  [SMALL_STATE(191)] = 8124,
+  [SMALL_STATE(192)] = 8147,
+  [SMALL_STATE(193)] = 8170,
+  [SMALL_STATE(194)] = 8193,
+  [SMALL_STATE(195)] = 8216,
+  [SMALL_STATE(196)] = 8243,
+  [SMALL_STATE(197)] = 8270,
+  [SMALL_STATE(198)] = 8296,
+  [SMALL_STATE(199)] = 8322,
+  [SMALL_STATE(200)] = 8344,
+  [SMALL_STATE(201)] = 8370,
+  [SMALL_STATE(202)] = 8396,
+  [SMALL_STATE(203)] = 8414,
+  [SMALL_STATE(204)] = 8436,
+  [SMALL_STATE(205)] = 8462,
+  [SMALL_STATE(206)] = 8484,
+  [SMALL_STATE(207)] = 8510,
+  [SMALL_STATE(208)] = 8532,
+  [SMALL_STATE(209)] = 8554,
+  [SMALL_STATE(210)] = 8580,
+  [SMALL_STATE(211)] = 8606,
+  [SMALL_STATE(212)] = 8632,
+  [SMALL_STATE(213)] = 8654,
+  [SMALL_STATE(214)] = 8676,
+  [SMALL_STATE(215)] = 8702,
+  [SMALL_STATE(216)] = 8728,
+  [SMALL_STATE(217)] = 8754,
+  [SMALL_STATE(218)] = 8780,
+  [SMALL_STATE(219)] = 8806,
+  [SMALL_STATE(220)] = 8832,
+  [SMALL_STATE(221)] = 8858,
+  [SMALL_STATE(222)] = 8879,
+  [SMALL_STATE(223)] = 8902,
+  [SMALL_STATE(224)] = 8923,
+  [SMALL_STATE(225)] = 8942,
+  [SMALL_STATE(226)] = 8963,
+  [SMALL_STATE(227)] = 8984,
+  [SMALL_STATE(228)] = 9003,
+  [SMALL_STATE(229)] = 9022,
+  [SMALL_STATE(230)] = 9043,
+  [SMALL_STATE(231)] = 9064,
+  [SMALL_STATE(232)] = 9079,
+  [SMALL_STATE(233)] = 9102,
+  [SMALL_STATE(234)] = 9125,
+  [SMALL_STATE(235)] = 9148,
+  [SMALL_STATE(236)] = 9171,
+  [SMALL_STATE(237)] = 9186,
+  [SMALL_STATE(238)] = 9209,
+  [SMALL_STATE(239)] = 9228,
+  [SMALL_STATE(240)] = 9243,
+  [SMALL_STATE(241)] = 9257,
+  [SMALL_STATE(242)] = 9271,
+  [SMALL_STATE(243)] = 9291,
+  [SMALL_STATE(244)] = 9307,
+  [SMALL_STATE(245)] = 9321,
+  [SMALL_STATE(246)] = 9339,
+  [SMALL_STATE(247)] = 9353,
+  [SMALL_STATE(248)] = 9367,
+  [SMALL_STATE(249)] = 9387,
+  [SMALL_STATE(250)] = 9407,
+  [SMALL_STATE(251)] = 9421,
+  [SMALL_STATE(252)] = 9435,
+  [SMALL_STATE(253)] = 9449,
+  [SMALL_STATE(254)] = 9469,
+  [SMALL_STATE(255)] = 9489,
+  [SMALL_STATE(256)] = 9509,
+  [SMALL_STATE(257)] = 9529,
+  [SMALL_STATE(258)] = 9549,
+  [SMALL_STATE(259)] = 9563,
+  [SMALL_STATE(260)] = 9583,
+  [SMALL_STATE(261)] = 9603,
+  [SMALL_STATE(262)] = 9617,
+  [SMALL_STATE(263)] = 9631,
+  [SMALL_STATE(264)] = 9645,
+  [SMALL_STATE(265)] = 9659,
+  [SMALL_STATE(266)] = 9679,
+  [SMALL_STATE(267)] = 9693,
+  [SMALL_STATE(268)] = 9707,
+  [SMALL_STATE(269)] = 9721,
+  [SMALL_STATE(270)] = 9741,
+  [SMALL_STATE(271)] = 9759,
+  [SMALL_STATE(272)] = 9779,
+  [SMALL_STATE(273)] = 9799,
+  [SMALL_STATE(274)] = 9819,
+  [SMALL_STATE(275)] = 9839,
+  [SMALL_STATE(276)] = 9853,
+  [SMALL_STATE(277)] = 9870,
+  [SMALL_STATE(278)] = 9887,
+  [SMALL_STATE(279)] = 9900,
+  [SMALL_STATE(280)] = 9917,
+  [SMALL_STATE(281)] = 9930,
+  [SMALL_STATE(282)] = 9947,
+  [SMALL_STATE(283)] = 9964,
+  [SMALL_STATE(284)] = 9981,
+  [SMALL_STATE(285)] = 9998,
+  [SMALL_STATE(286)] = 10015,
+  [SMALL_STATE(287)] = 10032,
+  [SMALL_STATE(288)] = 10049,
+  [SMALL_STATE(289)] = 10066,
+  [SMALL_STATE(290)] = 10083,
+  [SMALL_STATE(291)] = 10096,
+  [SMALL_STATE(292)] = 10113,
+  [SMALL_STATE(293)] = 10126,
+  [SMALL_STATE(294)] = 10139,
+  [SMALL_STATE(295)] = 10152,
+  [SMALL_STATE(296)] = 10169,
+  [SMALL_STATE(297)] = 10182,
+  [SMALL_STATE(298)] = 10197,
+  [SMALL_STATE(299)] = 10212,
+  [SMALL_STATE(300)] = 10229,
+  [SMALL_STATE(301)] = 10242,
+  [SMALL_STATE(302)] = 10259,
+  [SMALL_STATE(303)] = 10276,
+  [SMALL_STATE(304)] = 10289,
+  [SMALL_STATE(305)] = 10306,
+  [SMALL_STATE(306)] = 10323,
+  [SMALL_STATE(307)] = 10340,
+  [SMALL_STATE(308)] = 10357,
+  [SMALL_STATE(309)] = 10374,
+  [SMALL_STATE(310)] = 10391,
+  [SMALL_STATE(311)] = 10408,
+  [SMALL_STATE(312)] = 10420,
+  [SMALL_STATE(313)] = 10434,
+  [SMALL_STATE(314)] = 10446,
+  [SMALL_STATE(315)] = 10458,
+  [SMALL_STATE(316)] = 10470,
+  [SMALL_STATE(317)] = 10482,
+  [SMALL_STATE(318)] = 10494,
+  [SMALL_STATE(319)] = 10506,
+  [SMALL_STATE(320)] = 10518,
+  [SMALL_STATE(321)] = 10530,
+  [SMALL_STATE(322)] = 10544,
+  [SMALL_STATE(323)] = 10556,
+  [SMALL_STATE(324)] = 10568,
+  [SMALL_STATE(325)] = 10580,
+  [SMALL_STATE(326)] = 10594,
+  [SMALL_STATE(327)] = 10608,
+  [SMALL_STATE(328)] = 10620,
+  [SMALL_STATE(329)] = 10632,
+  [SMALL_STATE(330)] = 10644,
+  [SMALL_STATE(331)] = 10656,
+  [SMALL_STATE(332)] = 10670,
+  [SMALL_STATE(333)] = 10682,
+  [SMALL_STATE(334)] = 10694,
+  [SMALL_STATE(335)] = 10706,
+  [SMALL_STATE(336)] = 10717,
+  [SMALL_STATE(337)] = 10728,
+  [SMALL_STATE(338)] = 10739,
+  [SMALL_STATE(339)] = 10750,
+  [SMALL_STATE(340)] = 10761,
+  [SMALL_STATE(341)] = 10772,
+  [SMALL_STATE(342)] = 10783,
+  [SMALL_STATE(343)] = 10794,
+  [SMALL_STATE(344)] = 10805,
+  [SMALL_STATE(345)] = 10816,
+  [SMALL_STATE(346)] = 10827,
+  [SMALL_STATE(347)] = 10838,
+  [SMALL_STATE(348)] = 10849,
+  [SMALL_STATE(349)] = 10860,
+  [SMALL_STATE(350)] = 10871,
+  [SMALL_STATE(351)] = 10882,
+  [SMALL_STATE(352)] = 10893,
+  [SMALL_STATE(353)] = 10904,
+  [SMALL_STATE(354)] = 10915,
+  [SMALL_STATE(355)] = 10926,
+  [SMALL_STATE(356)] = 10937,
+  [SMALL_STATE(357)] = 10948,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -12483,500 +12576,504 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
   [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(160),
-  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(339),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(287),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(345),
-  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
-  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(162),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(166),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(175),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(290),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(341),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
   [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
   [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0),
   [73] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(12),
-  [76] = {.entry = {.count = 1, .reusable = false}}, SHIFT(102),
-  [78] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 1, 0, 1),
-  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 1, 0, 1),
-  [84] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [76] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
+  [78] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 2, 0, 4),
+  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 2, 0, 4),
+  [84] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
   [86] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10),
   [88] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10),
-  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10), SHIFT_REPEAT(341),
-  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 2, 0, 4),
-  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 2, 0, 4),
-  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 1, 0, 3),
-  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 1, 0, 3),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
-  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 7),
-  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 7),
+  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10), SHIFT_REPEAT(353),
+  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_list, 1, 0, 1),
+  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_list, 1, 0, 1),
+  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 5),
+  [101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 7),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 7),
+  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 1, 0, 3),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 1, 0, 3),
   [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_op, 3, 0, 36),
   [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_op, 3, 0, 36),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
-  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(333),
+  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(94),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
   [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
-  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
   [135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_op, 2, 0, 22),
   [137] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_op, 2, 0, 22),
-  [139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 0),
-  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 0),
-  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
-  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
-  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
-  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
-  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [163] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 5),
-  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 5),
-  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 3, 0, 17),
-  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 3, 0, 17),
-  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
-  [173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 9),
-  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 9),
-  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 5),
-  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 5),
-  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 11),
-  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 11),
-  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 28),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 28),
-  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 2, 0, 0),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 2, 0, 0),
-  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 11),
-  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 11),
-  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 28),
-  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 28),
-  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 41),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 41),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 6, 0, 41),
-  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 6, 0, 41),
-  [211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_specifier, 2, 0, 25),
-  [213] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_latency_specifier, 2, 0, 25),
-  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
-  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
-  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 3, 0, 5),
-  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 3, 0, 5),
-  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 3, 0, 0),
-  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 3, 0, 0),
-  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 37),
-  [231] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 37),
-  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
-  [235] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
-  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_call, 2, 0, 24),
-  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_call, 2, 0, 24),
-  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_op, 2, 0, 8),
-  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_op, 2, 0, 8),
-  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 5),
-  [247] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 5),
-  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 11),
-  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 11),
-  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 28),
-  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 28),
-  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
-  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
-  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 54),
-  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 54),
-  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 55),
-  [267] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 55),
-  [269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [273] = {.entry = {.count = 1, .reusable = false}}, SHIFT(73),
-  [275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [279] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
-  [281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 11),
-  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 11),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 28),
-  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 28),
-  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 41),
-  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 41),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
-  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 6, 0, 41),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 6, 0, 41),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression, 3, 0, 25),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression, 3, 0, 25),
-  [309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [311] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
-  [313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [319] = {.entry = {.count = 1, .reusable = false}}, SHIFT(94),
-  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 2, 0, 0),
-  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 2, 0, 0),
-  [327] = {.entry = {.count = 1, .reusable = false}}, SHIFT(209),
-  [329] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
-  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 53),
-  [335] = {.entry = {.count = 1, .reusable = false}}, SHIFT(161),
-  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [341] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
-  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 1, 0, 38),
-  [347] = {.entry = {.count = 1, .reusable = false}}, SHIFT(159),
-  [349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [351] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [353] = {.entry = {.count = 1, .reusable = false}}, SHIFT(119),
-  [355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [357] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
-  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [361] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
-  [363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [365] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
-  [367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
-  [375] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
-  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [379] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
-  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [383] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
-  [385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [387] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
-  [389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [391] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
-  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [395] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
-  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [399] = {.entry = {.count = 1, .reusable = false}}, SHIFT(157),
-  [401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [407] = {.entry = {.count = 1, .reusable = false}}, SHIFT(112),
-  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
-  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
-  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [419] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
-  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [423] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
-  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(116),
-  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(117),
-  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [435] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
-  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
-  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
-  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
-  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [451] = {.entry = {.count = 1, .reusable = false}}, SHIFT(162),
-  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
-  [459] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
-  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [463] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
-  [465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [467] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 3, 0, 17),
+  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 3, 0, 17),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
+  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(112),
+  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [163] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 5),
+  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 5),
+  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 11),
+  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 11),
+  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 4, 0, 28),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 4, 0, 28),
+  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 5),
+  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 5),
+  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 2, 0, 0),
+  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 2, 0, 0),
+  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 11),
+  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 11),
+  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 28),
+  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 28),
+  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 5, 0, 41),
+  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 5, 0, 41),
+  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 6, 0, 41),
+  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 6, 0, 41),
+  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_args, 3, 0, 0),
+  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_args, 3, 0, 0),
+  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_template_global, 2, 0, 9),
+  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_global, 2, 0, 9),
+  [207] = {.entry = {.count = 1, .reusable = false}}, SHIFT(119),
+  [209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 37),
+  [213] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 37),
+  [215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 28),
+  [217] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 28),
+  [219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression, 3, 0, 25),
+  [221] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression, 3, 0, 25),
+  [223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 3, 0, 5),
+  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 3, 0, 5),
+  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 3, 0, 0),
+  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 3, 0, 0),
+  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 2, 0, 0),
+  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 2, 0, 0),
+  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [247] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
+  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 2, 0, 0),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 5),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 5),
+  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_specifier, 2, 0, 25),
+  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_latency_specifier, 2, 0, 25),
+  [263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [265] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 4, 0, 11),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 4, 0, 11),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 3, 0, 5),
+  [277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [279] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 55),
+  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 55),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 56),
+  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_access_bracket_expression, 3, 0, 56),
+  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
+  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesis_expression_list, 4, 0, 11),
+  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 11),
+  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 11),
+  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 28),
+  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 28),
+  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 5, 0, 41),
+  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 5, 0, 41),
+  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_list_expression, 6, 0, 41),
+  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_list_expression, 6, 0, 41),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_call, 2, 0, 24),
+  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_call, 2, 0, 24),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_op, 2, 0, 8),
+  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_op, 2, 0, 8),
+  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [323] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
+  [325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 1, 0, 38),
+  [329] = {.entry = {.count = 1, .reusable = false}}, SHIFT(159),
+  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [335] = {.entry = {.count = 1, .reusable = false}}, SHIFT(117),
+  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [341] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [345] = {.entry = {.count = 1, .reusable = false}}, SHIFT(199),
+  [347] = {.entry = {.count = 1, .reusable = false}}, SHIFT(122),
+  [349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 54),
+  [353] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
+  [355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [357] = {.entry = {.count = 1, .reusable = false}}, SHIFT(116),
+  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [361] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
+  [363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [365] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [369] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [373] = {.entry = {.count = 1, .reusable = false}}, SHIFT(160),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [383] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [387] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [391] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [395] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [399] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
+  [401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [403] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
+  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [407] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
+  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [423] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(110),
+  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [435] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
+  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [443] = {.entry = {.count = 1, .reusable = false}}, SHIFT(109),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [451] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [455] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [459] = {.entry = {.count = 1, .reusable = false}}, SHIFT(161),
+  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [467] = {.entry = {.count = 1, .reusable = false}}, SHIFT(165),
   [469] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [471] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10), SHIFT_REPEAT(343),
-  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [476] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
-  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [488] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [490] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 2, 0, 23),
-  [502] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 2, 0, 23),
-  [504] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 2, 0, 0),
-  [506] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 2, 0, 0),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [512] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 1, 0, 0),
-  [514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 1, 0, 0),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [518] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 1, 0, 15),
-  [520] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 1, 0, 15),
-  [522] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 5),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 1, 0, 0),
-  [530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
-  [532] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
-  [534] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__type, 1, 0, 0), REDUCE(sym__expression, 1, 0, 0),
-  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 3, 0, 39),
-  [539] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 14),
-  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 14),
+  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [473] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_namespace_list_repeat1, 2, 0, 10), SHIFT_REPEAT(342),
+  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [480] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 1, 0, 0),
+  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 1, 0, 0),
+  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [486] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 2, 0, 23),
+  [488] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 2, 0, 23),
+  [490] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__comma, 2, 0, 0),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__comma, 2, 0, 0),
+  [494] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [502] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [516] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
+  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_to, 1, 0, 15),
+  [522] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign_to, 1, 0, 15),
+  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 5),
+  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 1, 0, 0),
+  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [536] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
+  [538] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__type, 1, 0, 0), REDUCE(sym__expression, 1, 0, 0),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 3, 0, 39),
   [543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decl_assign_statement, 3, 0, 35),
-  [545] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
-  [547] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10), SHIFT_REPEAT(160),
-  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
-  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 52),
-  [558] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
-  [560] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
-  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 3, 0, 65),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [568] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 1),
-  [570] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 1),
-  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
-  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [576] = {.entry = {.count = 1, .reusable = false}}, SHIFT(187),
-  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
-  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 5),
-  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 11),
-  [592] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 4),
-  [594] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(177),
-  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 2, 0, 19),
-  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 26),
-  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 1),
+  [545] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 14),
+  [547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 14),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_domain_statement, 4, 0, 46),
+  [551] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
+  [553] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10), SHIFT_REPEAT(162),
+  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 2, 0, 10),
+  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 53),
+  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [564] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
+  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_write_modifiers_repeat1, 1, 0, 1),
+  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [570] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 3, 0, 66),
+  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_write_modifiers, 1, 0, 1),
+  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_write_modifiers, 1, 0, 1),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [582] = {.entry = {.count = 1, .reusable = false}}, SHIFT(188),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [592] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__linebreak, 2, 0, 0), SHIFT_REPEAT(176),
+  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 11),
+  [597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 5),
+  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 2, 0, 19),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
   [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 2, 0, 21),
-  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 31),
-  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 40),
-  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 27),
-  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_type, 1, 0, 13),
-  [617] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration_modifiers, 1, 0, 14),
-  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_modifiers, 1, 0, 14),
-  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10),
-  [623] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
-  [626] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 2, 0, 4),
-  [628] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10),
-  [630] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10), SHIFT_REPEAT(195),
-  [633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10),
-  [635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 34),
-  [637] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 48),
-  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 1, 0, 1),
-  [641] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 1, 0, 1),
-  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 1, 0, 1),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
-  [649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10),
-  [651] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
-  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
-  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
-  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
-  [662] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
-  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
-  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
-  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
-  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
-  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
-  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
-  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
-  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10),
-  [684] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
-  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 1, 0, 1),
-  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
-  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
-  [701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 2, 0, 4),
-  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
-  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
-  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
-  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
-  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
-  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [725] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 51),
-  [727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 2, 0, 8),
-  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 60),
-  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 63),
-  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10),
-  [735] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
-  [738] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 5),
-  [740] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 33),
-  [742] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 43),
-  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
-  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
-  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
-  [756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type_bracket, 3, 0, 25),
-  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
-  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [762] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10),
-  [764] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [773] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 47),
-  [775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 4, 0, 56),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [785] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 11),
-  [787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 6, 0, 41),
-  [789] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10),
-  [791] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10), SHIFT_REPEAT(10),
-  [794] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10),
-  [796] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(184),
-  [799] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 11),
-  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [803] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 5),
-  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 4),
-  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__then_else_block, 1, 0, 29),
-  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
-  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 11),
-  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [819] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 28),
-  [821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 5),
-  [823] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 5),
-  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
-  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 5),
-  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 28),
-  [839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 41),
-  [841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 1, 0, 13),
-  [843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [845] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [847] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
-  [851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 1),
-  [855] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
-  [859] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [861] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 26),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 1),
+  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 4),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 2, 0, 21),
+  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 31),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 3, 0, 27),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_type, 1, 0, 13),
+  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 40),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 2, 0, 4),
+  [625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10),
+  [627] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 10), SHIFT_REPEAT(103),
+  [630] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration_modifiers, 1, 0, 14),
+  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_modifiers, 1, 0, 14),
+  [634] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10),
+  [636] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10), SHIFT_REPEAT(202),
+  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 2, 0, 10),
+  [641] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration_list, 1, 0, 1),
+  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 34),
+  [645] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 49),
+  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [663] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 1, 0, 1),
+  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_modifiers_repeat1, 1, 0, 1),
+  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10),
+  [673] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 10), SHIFT_REPEAT(103),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 1, 0, 1),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [694] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10),
+  [696] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parenthesis_expression_list_repeat1, 2, 0, 10), SHIFT_REPEAT(103),
+  [699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign_left_side, 2, 0, 4),
+  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10),
+  [731] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 10), SHIFT_REPEAT(103),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [740] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10),
+  [742] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 10), SHIFT_REPEAT(103),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 61),
+  [747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 64),
+  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 33),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 43),
+  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 48),
+  [757] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 52),
+  [759] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_list_repeat1, 2, 0, 5),
+  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 2, 0, 8),
+  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [781] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 4, 0, 57),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [785] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type_bracket, 3, 0, 25),
+  [787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 41),
+  [789] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 28),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [793] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_arg, 1, 0, 13),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [797] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__then_else_block, 1, 0, 29),
+  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [801] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_assign_left_side_repeat1, 2, 0, 5),
+  [803] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 6, 0, 41),
+  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10),
+  [807] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(184),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [812] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 28),
+  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [816] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [826] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 4),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [830] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 11),
+  [832] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 1),
+  [834] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [836] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 5),
+  [838] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 5, 0, 11),
+  [840] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
+  [842] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 5),
+  [844] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [846] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 11),
+  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10),
+  [854] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 10), SHIFT_REPEAT(10),
+  [857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [859] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 5),
   [863] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [865] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 42),
-  [867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [871] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
-  [875] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 5),
-  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [879] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 2, 0, 57),
-  [881] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 58),
-  [883] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 59),
-  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
-  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
-  [889] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_const_and_type, 2, 0, 2),
-  [891] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 5),
-  [893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
-  [901] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 3, 0, 66),
-  [903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
-  [905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 4, 0, 67),
-  [907] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [909] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 5),
-  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
-  [913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [925] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [927] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 3, 0, 6),
-  [929] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 62),
-  [931] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 64),
-  [933] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 16),
-  [935] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 49),
-  [937] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__then_else_block, 2, 0, 44),
-  [939] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 46),
-  [941] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 50),
-  [943] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 12),
-  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 30),
-  [947] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 32),
-  [949] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_domain_statement, 2, 0, 20),
-  [951] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5),
-  [953] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 6, 0, 68),
-  [955] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 25),
-  [957] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 45),
-  [959] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 5, 0, 18),
-  [961] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 7, 0, 69),
-  [963] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 61),
-  [965] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [967] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [969] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 6, 0, 41),
-  [971] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [973] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [975] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 11),
-  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
-  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [983] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 11),
-  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [987] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [871] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_declaration_arguments_repeat1, 2, 0, 5),
+  [873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [875] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_args_repeat1, 2, 0, 5),
+  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [881] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_const_and_type, 2, 0, 2),
+  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [897] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 2, 0, 58),
+  [899] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 2, 0, 42),
+  [901] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 59),
+  [903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 3, 0, 60),
+  [907] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 5),
+  [909] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_domain_statement, 2, 0, 20),
+  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [915] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__interface_ports_output, 3, 0, 67),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [921] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_ports, 4, 0, 68),
+  [923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [925] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [927] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [933] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [935] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [937] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 30),
+  [939] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 5, 0, 18),
+  [941] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 25),
+  [943] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 62),
+  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 63),
+  [947] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__then_else_block, 2, 0, 44),
+  [949] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 5, 0, 65),
+  [951] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 3, 0, 6),
+  [953] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 45),
+  [955] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 16),
+  [957] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 47),
+  [959] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_object, 4, 0, 12),
+  [961] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 50),
+  [963] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 6, 0, 69),
+  [965] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 3, 0, 32),
+  [967] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 7, 0, 70),
+  [969] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_statement, 4, 0, 51),
+  [971] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5),
+  [973] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [975] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [977] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 5),
+  [979] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 41),
+  [981] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 28),
+  [983] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [987] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
   [991] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 28),
-  [993] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 5),
-  [995] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [997] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 28),
-  [999] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 41),
-  [1001] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 5),
+  [993] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [995] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [997] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [999] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 4, 0, 11),
+  [1001] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
   [1003] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 2, 0, 0),
-  [1005] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [1007] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [1009] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 0),
+  [1005] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 5),
+  [1007] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [1009] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 3, 0, 0),
+  [1013] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 5, 0, 11),
+  [1015] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [1017] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_declaration_arguments, 6, 0, 41),
 };
 
 #ifdef __cplusplus